@@ -3,10 +3,13 @@ use std::{iter::repeat, str::FromStr};
 use authorization::{schema::WebOwnerSubject, AuthorizationApi, NoAuthorization};
 use criterion::{BatchSize::SmallInput, Bencher, BenchmarkId, Criterion, SamplingMode};
 use criterion_macro::criterion;
+use futures::lock::Mutex;
 use graph::{
     store::{
         account::{InsertAccountIdParams, InsertWebIdParams},
-        knowledge::{CreateEntityParams, GetEntitySubgraphParams},
+        knowledge::{
+            CreateEntityParams, EntityTraversalFilters, GetEntitySubgraphParams, SubgraphBudget,
+        },
         query::Filter,
         AccountStore, EntityQuerySorting, EntityStore,
     },
@@ -193,11 +196,14 @@ async fn seed_db<A: AuthorizationApi>(
 pub fn bench_get_entity_by_id<A: AuthorizationApi>(
     b: &mut Bencher,
     runtime: &Runtime,
-    store: &Store<A>,
+    store: &mut Store<A>,
     actor_id: AccountId,
     entity_metadata_list: &[EntityMetadata],
     graph_resolve_depths: GraphResolveDepths,
 ) {
+    // `get_entity_subgraph` requires mutable access to the store, so it's shared between the
+    // iterations behind a lock.
+    let store = &Mutex::new(store);
     b.to_async(runtime).iter_batched(
         || {
             // Each iteration, *before timing*, pick a random entity from the sample to
@@ -210,11 +216,15 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
         },
         |entity_record_id| async move {
             store
+                .lock()
+                .await
                 .get_entity_subgraph(
                     actor_id,
                     GetEntitySubgraphParams {
                         filter: Filter::for_entity_by_entity_id(entity_record_id.entity_id),
                         graph_resolve_depths,
+                        traversal_filters: EntityTraversalFilters::default(),
+                        budget: SubgraphBudget::default(),
                         temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                             pinned: PinnedTemporalAxisUnresolved::new(None),
                             variable: VariableTemporalAxisUnresolved::new(
@@ -224,6 +234,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                         },
                         sorting: EntityQuerySorting {
                             paths: Vec::new(),
+                            relevance: None,
                             cursor: None,
                         },
                         limit: None,
@@ -259,7 +270,7 @@ fn bench_scaling_read_entity_zero_depths(c: &mut Criterion) {
             entity_metadata_list,
             ..
         } = runtime.block_on(seed_db(account_id, &mut store_wrapper, size));
-        let store = &store_wrapper.store;
+        let store = &mut store_wrapper.store;
 
         group.bench_with_input(
             BenchmarkId::new(
@@ -311,7 +322,7 @@ fn bench_scaling_read_entity_one_depth(c: &mut Criterion) {
             entity_metadata_list,
             ..
         } = runtime.block_on(seed_db(account_id, &mut store_wrapper, size));
-        let store = &store_wrapper.store;
+        let store = &mut store_wrapper.store;
 
         group.bench_with_input(
             BenchmarkId::new(
@@ -166,6 +166,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                         },
                         sorting: EntityQuerySorting {
                             paths: Vec::new(),
+                            relevance: None,
                             cursor: None,
                         },
                         limit: None,
@@ -2,10 +2,13 @@ use std::borrow::Cow;
 
 use authorization::AuthorizationApi;
 use criterion::{BatchSize::SmallInput, Bencher};
+use futures::lock::Mutex;
 use graph::{
     knowledge::EntityQueryPath,
     store::{
-        knowledge::{GetEntitiesParams, GetEntitySubgraphParams},
+        knowledge::{
+            EntityTraversalFilters, GetEntitiesParams, GetEntitySubgraphParams, SubgraphBudget,
+        },
         query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
         EntityQuerySorting, EntityStore,
     },
@@ -56,6 +59,7 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
                         },
                         sorting: EntityQuerySorting {
                             paths: Vec::new(),
+                            relevance: None,
                             cursor: None,
                         },
                         limit: None,
@@ -74,10 +78,13 @@ pub fn bench_get_entity_by_id<A: AuthorizationApi>(
 pub fn bench_get_entities_by_property<A: AuthorizationApi>(
     b: &mut Bencher,
     runtime: &Runtime,
-    store: &Store<A>,
+    store: &mut Store<A>,
     actor_id: AccountId,
     graph_resolve_depths: GraphResolveDepths,
 ) {
+    // `get_entity_subgraph` requires mutable access to the store, so it's shared between the
+    // iterations behind a lock.
+    let store = &Mutex::new(store);
     b.to_async(runtime).iter(|| async move {
         let mut filter = Filter::Equal(
             Some(FilterExpression::Path(EntityQueryPath::Properties(Some(
@@ -93,11 +100,15 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
             .convert_parameters()
             .expect("failed to convert parameters");
         let response = store
+            .lock()
+            .await
             .get_entity_subgraph(
                 actor_id,
                 GetEntitySubgraphParams {
                     filter,
                     graph_resolve_depths,
+                    traversal_filters: EntityTraversalFilters::default(),
+                    budget: SubgraphBudget::default(),
                     temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                         pinned: PinnedTemporalAxisUnresolved::new(None),
                         variable: VariableTemporalAxisUnresolved::new(
@@ -107,6 +118,7 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
                     },
                     sorting: EntityQuerySorting {
                         paths: Vec::new(),
+                        relevance: None,
                         cursor: None,
                     },
                     limit: None,
@@ -123,10 +135,11 @@ pub fn bench_get_entities_by_property<A: AuthorizationApi>(
 pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
     b: &mut Bencher,
     runtime: &Runtime,
-    store: &Store<A>,
+    store: &mut Store<A>,
     actor_id: AccountId,
     graph_resolve_depths: GraphResolveDepths,
 ) {
+    let store = &Mutex::new(store);
     b.to_async(runtime).iter(|| async move {
         let mut filter = Filter::Equal(
             Some(FilterExpression::Path(EntityQueryPath::EntityEdge {
@@ -146,11 +159,15 @@ pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
             .convert_parameters()
             .expect("failed to convert parameters");
         let response = store
+            .lock()
+            .await
             .get_entity_subgraph(
                 actor_id,
                 GetEntitySubgraphParams {
                     filter,
                     graph_resolve_depths,
+                    traversal_filters: EntityTraversalFilters::default(),
+                    budget: SubgraphBudget::default(),
                     temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
                         pinned: PinnedTemporalAxisUnresolved::new(None),
                         variable: VariableTemporalAxisUnresolved::new(
@@ -160,6 +177,7 @@ pub fn bench_get_link_by_target_by_property<A: AuthorizationApi>(
                     },
                     sorting: EntityQuerySorting {
                         paths: Vec::new(),
+                        relevance: None,
                         cursor: None,
                     },
                     limit: None,
@@ -102,7 +102,7 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
     );
 
     let mut group = c.benchmark_group("representative_read_multiple_entities");
-    let (runtime, store_wrapper) = setup(DB_NAME, false, false, account_id, NoAuthorization);
+    let (runtime, mut store_wrapper) = setup(DB_NAME, false, false, account_id, NoAuthorization);
     group.sample_size(10);
     group.sampling_mode(SamplingMode::Flat);
 
@@ -325,7 +325,7 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
                 knowledge::entity::bench_get_entities_by_property(
                     b,
                     &runtime,
-                    &store_wrapper.store,
+                    &mut store_wrapper.store,
                     store_wrapper.account_id,
                     *graph_resolve_depth,
                 );
@@ -378,7 +378,7 @@ fn bench_representative_read_multiple_entities(c: &mut Criterion) {
                 knowledge::entity::bench_get_link_by_target_by_property(
                     b,
                     &runtime,
-                    &store_wrapper.store,
+                    &mut store_wrapper.store,
                     store_wrapper.account_id,
                     *graph_resolve_depth,
                 );
@@ -15,7 +15,10 @@ use clap::Parser;
 use error_stack::{Report, Result, ResultExt};
 use graph::{
     ontology::domain_validator::DomainValidator,
-    store::{DatabaseConnectionInfo, FetchingPool, PostgresStorePool, StorePool},
+    store::{
+        webhook::{WebhookDispatcher, WebhookDispatcherConfig, WebhookSecretKey},
+        DatabaseConnectionInfo, FetchingPool, PostgresStorePool, StorePool,
+    },
 };
 use graph_api::rest::{rest_api_router, OpenApiDocumentation, RestRouterDependencies};
 use regex::Regex;
@@ -58,6 +61,10 @@ impl TryFrom<ApiAddress> for SocketAddr {
 }
 
 #[derive(Debug, Parser)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "The arguments are flags of the command line interface"
+)]
 pub struct ServerArgs {
     #[clap(flatten)]
     pub db_info: DatabaseConnectionInfo,
@@ -123,8 +130,32 @@ pub struct ServerArgs {
     /// The URL of the Temporal server.
     #[clap(long, env = "HASH_TEMPORAL_SERVER_PORT", default_value_t = 7233)]
     pub temporal_port: u16,
+
+    /// The interval in seconds in which pending webhook deliveries are sent.
+    #[clap(
+        long,
+        env = "HASH_GRAPH_WEBHOOK_DISPATCH_INTERVAL",
+        default_value_t = 5
+    )]
+    pub webhook_dispatch_interval: u64,
+
+    /// The hex-encoded 256-bit key used to encrypt the secrets of webhooks.
+    ///
+    /// If not set, webhooks can neither be created nor dispatched.
+    #[clap(long, env = "HASH_GRAPH_WEBHOOK_SECRET_KEY")]
+    pub webhook_secret_key: Option<WebhookSecretKey>,
+
+    /// Allows webhooks to send requests to addresses which are not publicly reachable, e.g. the
+    /// loopback interface or private networks.
+    #[clap(
+        long,
+        env = "HASH_GRAPH_WEBHOOK_ALLOW_PRIVATE_DESTINATIONS",
+        default_value_t = false
+    )]
+    pub webhook_allow_private_destinations: bool,
 }
 
+#[expect(clippy::too_many_lines)]
 pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
     if args.healthcheck {
         return healthcheck(args.api_address)
@@ -158,13 +189,16 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
         return Ok(());
     }
 
-    let pool = PostgresStorePool::new(&args.db_info, NoTls)
+    let mut pool = PostgresStorePool::new(&args.db_info, NoTls)
         .await
         .change_context(GraphError)
         .map_err(|report| {
             tracing::error!(error = ?report, "Failed to connect to database");
             report
         })?;
+    if let Some(webhook_secret_key) = args.webhook_secret_key {
+        pool = pool.with_webhook_secret_key(webhook_secret_key);
+    }
     _ = pool
         .acquire(NoAuthorization, None)
         .await
@@ -184,6 +218,19 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
         )
     };
 
+    let pool = Arc::new(pool);
+    tokio::spawn(
+        WebhookDispatcher::new(WebhookDispatcherConfig {
+            allow_private_destinations: args.webhook_allow_private_destinations,
+            ..WebhookDispatcherConfig::default()
+        })
+        .change_context(GraphError)?
+        .run(
+            Arc::clone(&pool),
+            Duration::from_secs(args.webhook_dispatch_interval),
+        ),
+    );
+
     let mut spicedb_client = SpiceDbOpenApi::new(
         format!("{}:{}", args.spicedb_host, args.spicedb_http_port),
         args.spicedb_grpc_preshared_key.as_deref(),
@@ -200,7 +247,7 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
     zanzibar_client.seed().await.change_context(GraphError)?;
 
     let router = rest_api_router(RestRouterDependencies {
-        store: Arc::new(pool),
+        store: pool,
         authorization_api: Arc::new(zanzibar_client),
         domain_regex: DomainValidator::new(args.allowed_url_domain),
         temporal_client: if let Some(host) = args.temporal_host {
@@ -0,0 +1,184 @@
+//! Web routes for reading the change feed.
+
+#![expect(clippy::str_to_string)]
+
+use std::{error::Error, sync::Arc};
+
+use authorization::AuthorizationApiPool;
+use axum::{
+    body::Body,
+    http::header,
+    response::{IntoResponse, Response},
+    routing::post,
+    Extension, Router,
+};
+use bytes::{Bytes, BytesMut};
+use codec::bytes::JsonLinesEncoder;
+use error_stack::Report;
+use futures::{stream, StreamExt, TryStreamExt};
+use graph::store::{
+    change::{
+        Change, ChangeCursor, ChangeEvent, ChangeStore, EntityChangeOperation, ReadChangesParams,
+    },
+    QueryError, StorePool,
+};
+use serde::{Deserialize, Serialize};
+use temporal_client::TemporalClient;
+use tokio_util::codec::Encoder;
+use utoipa::{OpenApi, ToSchema};
+
+use super::api_resource::RoutedResource;
+use crate::rest::{json::Json, status::report_to_response, AuthenticatedUserHeader};
+
+/// The number of changes read from the store at once while streaming the change feed.
+const CHANGE_FEED_PAGE_SIZE: usize = 1_000;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        stream_changes,
+    ),
+    components(
+        schemas(
+            StreamChangesRequest,
+            ChangeFeedLine,
+            ChangeCursor,
+            ChangeEvent,
+            Change,
+            EntityChangeOperation,
+        ),
+    ),
+    tags(
+        (name = "Change", description = "Change feed API")
+    )
+)]
+pub(crate) struct ChangeResource;
+
+impl RoutedResource for ChangeResource {
+    /// Create routes for reading the change feed.
+    fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().route("/changes", post(stream_changes::<S, A>))
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StreamChangesRequest {
+    /// Only changes recorded after this cursor are streamed. To resume the feed, pass the cursor
+    /// of the last line received.
+    #[serde(default)]
+    after: Option<ChangeCursor>,
+}
+
+/// A line of the streamed change feed.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+enum ChangeFeedLine {
+    Change(ChangeEvent),
+    /// The last line of the feed with the cursor to resume the feed from.
+    Cursor {
+        cursor: ChangeCursor,
+    },
+}
+
+#[utoipa::path(
+    post,
+    path = "/changes",
+    request_body = StreamChangesRequest,
+    tag = "Change",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/x-ndjson", description = "Every change recorded after the cursor, one JSON object per line. The last line contains the cursor to resume the feed from", body = ChangeFeedLine),
+
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn stream_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<StreamChangesRequest>,
+) -> Result<Response, Response>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    let authorization_api = authorization_api_pool
+        .acquire_owned()
+        .await
+        .map_err(report_to_response)?;
+
+    // The store has to outlive this handler as the response body is streamed.
+    let store = store_pool
+        .acquire_owned(authorization_api, temporal_client.0.clone())
+        .await
+        .map_err(report_to_response)?;
+
+    let changes = stream::try_unfold(Some((store, request.after)), move |state| async move {
+        let Some((store, after)) = state else {
+            return Ok::<_, Report<QueryError>>(None);
+        };
+
+        let response = store
+            .read_changes(
+                actor_id,
+                ReadChangesParams {
+                    after,
+                    limit: CHANGE_FEED_PAGE_SIZE,
+                },
+            )
+            .await?;
+
+        let mut lines = response
+            .changes
+            .into_iter()
+            .map(ChangeFeedLine::Change)
+            .collect::<Vec<_>>();
+        let next_state = if response.has_more {
+            Some((store, response.cursor))
+        } else {
+            // The changes the actor is not allowed to view are omitted, so the cursor of the last
+            // change sent might lag behind the position the feed was read to.
+            lines.extend(
+                response
+                    .cursor
+                    .map(|cursor| ChangeFeedLine::Cursor { cursor }),
+            );
+            None
+        };
+
+        Ok(Some((
+            stream::iter(lines.into_iter().map(Ok::<_, Report<QueryError>>)),
+            next_state,
+        )))
+    })
+    .try_flatten();
+
+    let mut encoder = JsonLinesEncoder::default();
+    let mut buffer = BytesMut::new();
+    let body = changes
+        .map(
+            move |change| -> Result<Bytes, Box<dyn Error + Send + Sync>> {
+                encoder.encode(change?, &mut buffer)?;
+                Ok(buffer.split().freeze())
+            },
+        )
+        .inspect_err(|error| tracing::error!(?error, "Could not stream changes"));
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(body),
+    )
+        .into_response())
+}
@@ -2,8 +2,9 @@
 
 #![expect(clippy::str_to_string)]
 
-use std::sync::Arc;
+use std::{borrow::Cow, io, pin::pin, str::FromStr, sync::Arc};
 
+use async_trait::async_trait;
 use authorization::{
     backend::{ModifyRelationshipOperation, PermissionAssertion},
     schema::{
@@ -15,27 +16,49 @@ use authorization::{
     AuthorizationApi, AuthorizationApiPool,
 };
 use axum::{
-    extract::Path,
-    http::StatusCode,
-    response::Response,
+    body::Body,
+    extract::{FromRequestParts, Path, Query},
+    http::{
+        header::{self, IF_MATCH},
+        request::Parts,
+        StatusCode,
+    },
+    response::{IntoResponse, Response},
     routing::{get, post},
     Extension, Router,
 };
+use bytes::Bytes;
 use error_stack::{Report, ResultExt};
+use futures::{channel::mpsc, future, stream, SinkExt, StreamExt, TryStreamExt};
 use graph::{
     knowledge::{EntityQueryPath, EntityQuerySortingToken, EntityQueryToken},
     store::{
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        error::{EntityDoesNotExist, EntityEditionMismatch, RaceConditionOnUpdate},
         knowledge::{
-            CountEntitiesParams, CreateEntityRequest, DiffEntityParams, DiffEntityResult,
-            GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, PatchEntityParams,
-            UpdateEntityEmbeddingsParams, ValidateEntityParams,
+            AggregateEntitiesParams, CorrectEntityParams, CountEntitiesParams, CreateEntityRequest,
+            DiffEntityParams, DiffEntityResult, DiffWebParams, DiffWebResult, DiscardDraftsParams,
+            EntityAggregation, EntityAggregationGroup, EntityConfidenceDiff, EntityHistoryEdition,
+            EntityMigrationFailure, EntityRevertTarget, EntityTraversalFilters, EntityTypeIdDiff,
+            EraseEntitiesParams, EraseEntitiesResponse, FindEntityPathsParams, GetEntitiesParams,
+            GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+            GetEntitySubgraphParams, LinkDataDiff, LinkErasureBehavior, ListDraftsParams,
+            MergeEntitiesParams, MigrateEntitiesParams, MigrateEntitiesResponse, ModifiedEntity,
+            PatchEntityParams, PropertyMergeStrategy, PropertyMigration, PublishDraftsParams,
+            RevertEntityParams, StreamEntitiesParams, SubgraphBudget,
+            SubgraphBudgetExceededBehavior, UpdateEntityEmbeddingsParams, ValidateEntityParams,
         },
         query::Filter,
-        AccountStore, EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
-        EntityValidationType, NullOrdering, Ordering, StorePool,
+        AccountStore, AggregationFunction, EntityQueryCursor, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityStore, EntityValidationType, NullOrdering, Ordering,
+        StorePool,
+    },
+    subgraph::{
+        edges::GraphResolveDepths,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableAxis,
+            VariableTemporalAxisUnresolved,
+        },
     },
-    subgraph::{edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
 };
 use graph_types::{
     knowledge::{
@@ -46,15 +69,18 @@ use graph_types::{
             SourceProvenance, SourceType,
         },
         link::LinkData,
-        Confidence, Property, PropertyDiff, PropertyMetadata, PropertyMetadataMap, PropertyObject,
-        PropertyPatchOperation, PropertyPath, PropertyPathElement, PropertyProvenance,
+        Confidence, Property, PropertyDiff, PropertyMetadata, PropertyMetadataDiff,
+        PropertyMetadataMap, PropertyObject, PropertyPatchOperation, PropertyPath,
+        PropertyPathElement, PropertyProvenance,
     },
     owned_by_id::OwnedById,
     Embedding,
 };
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
+use temporal_versioning::{TemporalBound, TimeAxis, Timestamp};
 use utoipa::{OpenApi, ToSchema};
+use uuid::Uuid;
 use validation::ValidateEntityComponents;
 
 use crate::rest::{
@@ -70,11 +96,25 @@ use crate::rest::{
         validate_entity,
         check_entity_permission,
         get_entities,
+        stream_entities,
         get_entity_subgraph,
         count_entities,
+        aggregate_entities,
+        find_entity_paths,
         patch_entity,
+        patch_entities,
+        revert_entity,
+        correct_entity,
+        list_drafts,
+        publish_drafts,
+        discard_drafts,
+        erase_entities,
+        merge_entities,
+        migrate_entities,
         update_entity_embeddings,
         diff_entity,
+        diff_web,
+        get_entity_history,
 
         get_entity_authorization_relationships,
         modify_entity_authorization_relationships,
@@ -89,6 +129,10 @@ use crate::rest::{
             CreateEntityRequest,
             ValidateEntityParams,
             CountEntitiesParams,
+            AggregateEntitiesParams,
+            EntityAggregation,
+            EntityAggregationGroup,
+            AggregationFunction,
             EntityValidationType,
             ValidateEntityComponents,
             Embedding,
@@ -97,8 +141,26 @@ use crate::rest::{
             EntityQueryToken,
 
             PatchEntityParams,
+            EntityRevertTarget,
+            RevertEntityParams,
+            CorrectEntityParams,
+            ListDraftsParams,
+            PublishDraftsParams,
+            DiscardDraftsParams,
             PropertyPatchOperation,
 
+            EraseEntitiesParams,
+            EraseEntitiesResponse,
+            LinkErasureBehavior,
+
+            MergeEntitiesParams,
+            PropertyMergeStrategy,
+
+            MigrateEntitiesParams,
+            MigrateEntitiesResponse,
+            PropertyMigration,
+            EntityMigrationFailure,
+
             EntityRelationAndSubject,
             EntityPermission,
             EntitySettingSubject,
@@ -111,7 +173,9 @@ use crate::rest::{
             EntitySetting,
 
             GetEntitiesRequest,
+            StreamEntitiesRequest,
             GetEntitySubgraphRequest,
+            EntityTraversalFilters,
             EntityQueryCursor,
             Ordering,
             NullOrdering,
@@ -119,6 +183,10 @@ use crate::rest::{
             EntityQuerySortingToken,
             GetEntitiesResponse,
             GetEntitySubgraphResponse,
+            SubgraphBudget,
+            SubgraphBudgetExceededBehavior,
+            FindEntityPathsParams,
+            FindEntityPathsResponse,
 
             Entity,
             Property,
@@ -146,10 +214,21 @@ use crate::rest::{
 
             DiffEntityParams,
             DiffEntityResult,
+            DiffWebParams,
+            DiffWebResult,
+            ModifiedEntity,
             PropertyDiff,
+            PropertyMetadataDiff,
+            EntityTypeIdDiff,
+            EntityConfidenceDiff,
+            LinkDataDiff,
             PropertyPath,
             PropertyPathElement,
             Confidence,
+
+            GetEntityHistoryResponse,
+            EntityHistoryEdition,
+            TimeAxis,
         )
     ),
     tags(
@@ -169,18 +248,38 @@ impl RoutedResource for EntityResource {
         Router::new().nest(
             "/entities",
             Router::new()
-                .route("/", post(create_entity::<S, A>).patch(patch_entity::<S, A>))
-                .route("/bulk", post(create_entities::<S, A>))
+                .route(
+                    "/",
+                    post(create_entity::<S, A>)
+                        .patch(patch_entity::<S, A>)
+                        .delete(erase_entities::<S, A>),
+                )
+                .route(
+                    "/bulk",
+                    post(create_entities::<S, A>).patch(patch_entities::<S, A>),
+                )
                 .route(
                     "/relationships",
                     post(modify_entity_authorization_relationships::<A>),
                 )
+                .route("/revert", post(revert_entity::<S, A>))
+                .route("/correct", post(correct_entity::<S, A>))
                 .route("/diff", post(diff_entity::<S, A>))
+                .route("/diff/web", post(diff_web::<S, A>))
+                .nest(
+                    "/drafts",
+                    Router::new()
+                        .route("/publish", post(publish_drafts::<S, A>))
+                        .route("/discard", post(discard_drafts::<S, A>)),
+                )
+                .route("/merge", post(merge_entities::<S, A>))
+                .route("/migrate", post(migrate_entities::<S, A>))
                 .route("/validate", post(validate_entity::<S, A>))
                 .route("/embeddings", post(update_entity_embeddings::<S, A>))
                 .nest(
                     "/:entity_id",
                     Router::new()
+                        .route("/history", get(get_entity_history::<S, A>))
                         .route(
                             "/relationships",
                             get(get_entity_authorization_relationships::<A>),
@@ -203,8 +302,12 @@ impl RoutedResource for EntityResource {
                     "/query",
                     Router::new()
                         .route("/", post(get_entities::<S, A>))
+                        .route("/stream", post(stream_entities::<S, A>))
                         .route("/subgraph", post(get_entity_subgraph::<S, A>))
-                        .route("/count", post(count_entities::<S, A>)),
+                        .route("/count", post(count_entities::<S, A>))
+                        .route("/aggregate", post(aggregate_entities::<S, A>))
+                        .route("/paths", post(find_entity_paths::<S, A>))
+                        .route("/drafts", post(list_drafts::<S, A>)),
                 ),
         )
     }
@@ -220,6 +323,7 @@ impl RoutedResource for EntityResource {
     ),
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the created entity", body = EntityMetadata),
+        (status = 409, content_type = "text/plain", description = "A property constrained to be unique is already used by another entity in the web"),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Entity Type URL was not found"),
@@ -270,6 +374,7 @@ where
     ),
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the created entity", body = [EntityMetadata]),
+        (status = 409, content_type = "text/plain", description = "A property constrained to be unique is already used by another entity in the web"),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Entity Type URL was not found"),
@@ -402,6 +507,7 @@ where
 
 fn generate_sorting_paths(
     paths: Option<Vec<EntityQuerySortingRecord<'_>>>,
+    relevance: Option<Cow<'_, str>>,
     limit: Option<usize>,
     cursor: Option<EntityQueryCursor<'_>>,
     temporal_axes: &QueryTemporalAxesUnresolved,
@@ -461,6 +567,7 @@ fn generate_sorting_paths(
 
     EntityQuerySorting {
         paths: sorting,
+        relevance: relevance.map(|query| Cow::Owned(query.into_owned())),
         cursor: cursor.map(EntityQueryCursor::into_owned),
     }
 }
@@ -475,6 +582,8 @@ struct GetEntitiesRequest<'q, 's, 'p> {
     limit: Option<usize>,
     #[serde(borrow)]
     sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
+    #[serde(borrow, default)]
+    relevance: Option<Cow<'p, str>>,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'s>>,
     #[serde(default)]
@@ -537,6 +646,7 @@ where
                 filter: request.filter,
                 sorting: generate_sorting_paths(
                     request.sorting_paths,
+                    request.relevance,
                     request.limit,
                     request.cursor,
                     &request.temporal_axes,
@@ -558,21 +668,153 @@ where
         .map_err(report_to_response)
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StreamEntitiesRequest<'q, 's, 'p> {
+    #[serde(borrow)]
+    filter: Filter<'q, Entity>,
+    temporal_axes: QueryTemporalAxesUnresolved,
+    include_drafts: bool,
+    #[serde(borrow)]
+    sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
+    #[serde(borrow, default)]
+    relevance: Option<Cow<'p, str>>,
+    #[serde(borrow)]
+    cursor: Option<EntityQueryCursor<'s>>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/stream",
+    request_body = StreamEntitiesRequest,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/x-ndjson",
+            body = Entity,
+            description = "The entities that satisfy the given query, one JSON object per line.",
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
+async fn stream_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Response, Response>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    // The number of serialized entities which are buffered before the client reads them.
+    const BUFFER_SIZE: usize = 100;
+
+    // The entities borrow from the store, so the store has to be owned by the future which reads
+    // them. The lines are passed to the response body through a channel.
+    let (mut sender, receiver) = mpsc::channel(BUFFER_SIZE);
+    let producer = async move {
+        let mut request =
+            StreamEntitiesRequest::deserialize(&request).map_err(report_to_response)?;
+        request
+            .filter
+            .convert_parameters()
+            .map_err(report_to_response)?;
+
+        let authorization_api = authorization_api_pool
+            .acquire_owned()
+            .await
+            .map_err(report_to_response)?;
+
+        let store = store_pool
+            .acquire_owned(authorization_api, temporal_client.0)
+            .await
+            .map_err(report_to_response)?;
+
+        let entities = store
+            .stream_entities(
+                actor_id,
+                StreamEntitiesParams {
+                    filter: request.filter,
+                    sorting: generate_sorting_paths(
+                        request.sorting_paths,
+                        request.relevance,
+                        None,
+                        request.cursor,
+                        &request.temporal_axes,
+                    ),
+                    include_drafts: request.include_drafts,
+                    temporal_axes: request.temporal_axes,
+                },
+            )
+            .await
+            .map_err(report_to_response)?;
+
+        let mut entities = pin!(entities);
+        while let Some(entity) = entities.try_next().await.map_err(report_to_response)? {
+            let mut line = serde_json::to_vec(&entity).map_err(report_to_response)?;
+            line.push(b'\n');
+            if sender.send(Bytes::from(line)).await.is_err() {
+                // The response body was dropped, so nobody is interested in the remaining
+                // entities.
+                break;
+            }
+        }
+
+        Ok(())
+    };
+
+    let mut lines = Box::pin(stream::select(
+        receiver.map(Ok),
+        stream::once(producer).filter_map(|result| future::ready(result.err().map(Err))),
+    ));
+
+    // Errors which occur before the first entity is read, e.g. an invalid query, are returned as
+    // regular error responses. Later errors can only abort the response body.
+    let first = match lines.next().await {
+        Some(Err(response)) => return Err(response),
+        first => first,
+    };
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(
+            stream::iter(first)
+                .chain(lines)
+                .map_err(|_: Response| io::Error::other("Could not stream entities")),
+        ),
+    )
+        .into_response())
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct GetEntitySubgraphRequest<'q, 's, 'p> {
     #[serde(borrow)]
     filter: Filter<'q, Entity>,
     graph_resolve_depths: GraphResolveDepths,
+    #[serde(borrow, default)]
+    traversal_filters: EntityTraversalFilters<'q>,
     temporal_axes: QueryTemporalAxesUnresolved,
     include_drafts: bool,
     limit: Option<usize>,
     #[serde(borrow)]
     sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
+    #[serde(borrow, default)]
+    relevance: Option<Cow<'p, str>>,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'s>>,
     #[serde(default)]
     include_count: bool,
+    #[serde(default)]
+    budget: SubgraphBudget,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -582,6 +824,7 @@ struct GetEntitySubgraphResponse<'r> {
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'r>>,
     count: Option<usize>,
+    incomplete: bool,
 }
 
 #[utoipa::path(
@@ -602,7 +845,9 @@ struct GetEntitySubgraphResponse<'r> {
             description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth.",
         ),
         (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 429, content_type = "text/plain", description = "The subgraph exceeded the requested budget"),
         (status = 500, description = "Store error occurred"),
+        (status = 504, content_type = "text/plain", description = "A statement exceeded the requested statement timeout"),
     )
 )]
 #[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
@@ -622,7 +867,7 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let store = store_pool
+    let mut store = store_pool
         .acquire(authorization_api, temporal_client.0)
         .await
         .map_err(report_to_response)?;
@@ -633,6 +878,15 @@ where
         .filter
         .convert_parameters()
         .map_err(report_to_response)?;
+    for filter in [
+        &mut request.traversal_filters.link_entities,
+        &mut request.traversal_filters.target_entities,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        filter.convert_parameters().map_err(report_to_response)?;
+    }
 
     store
         .get_entity_subgraph(
@@ -641,15 +895,18 @@ where
                 filter: request.filter,
                 sorting: generate_sorting_paths(
                     request.sorting_paths,
+                    request.relevance,
                     request.limit,
                     request.cursor,
                     &request.temporal_axes,
                 ),
                 limit: request.limit,
                 graph_resolve_depths: request.graph_resolve_depths,
+                traversal_filters: request.traversal_filters,
                 include_drafts: request.include_drafts,
                 include_count: request.include_count,
                 temporal_axes: request.temporal_axes,
+                budget: request.budget,
             },
         )
         .await
@@ -658,6 +915,7 @@ where
                 subgraph: response.subgraph.into(),
                 cursor: response.cursor.map(EntityQueryCursor::into_owned),
                 count: response.count,
+                incomplete: response.incomplete,
             })
         })
         .map_err(report_to_response)
@@ -717,15 +975,134 @@ where
         .map_err(report_to_response)
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/query/aggregate",
+    request_body = AggregateEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            description = "The aggregated values of each group of entities",
+            body = [EntityAggregationGroup],
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
+async fn aggregate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<Vec<EntityAggregationGroup>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let mut query = AggregateEntitiesParams::deserialize(&request).map_err(report_to_response)?;
+    query
+        .filter
+        .convert_parameters()
+        .map_err(report_to_response)?;
+
+    store
+        .aggregate_entities(actor_id, query)
+        .await
+        .map(Json)
+        .map_err(report_to_response)
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct FindEntityPathsResponse {
+    subgraph: Subgraph,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/paths",
+    request_body = FindEntityPathsParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            description = "A subgraph containing the shortest paths between the two entities",
+            body = FindEntityPathsResponse,
+        ),
+        (status = 404, description = "The source entity was not found"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool, request))]
+async fn find_entity_paths<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<FindEntityPathsResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let params = FindEntityPathsParams::deserialize(&request).map_err(report_to_response)?;
+
+    store
+        .find_entity_paths(actor_id, params)
+        .await
+        .map(|response| {
+            Json(FindEntityPathsResponse {
+                subgraph: response.subgraph.into(),
+            })
+        })
+        .map_err(report_to_response)
+}
+
 #[utoipa::path(
     patch,
     path = "/entities",
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("If-Match" = Option<String>, Header, description = "`*` or a comma-separated list of the editions the patch may be based on. The patch is rejected if the entity does not exist or was updated in the meantime"),
     ),
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the updated entity", body = EntityMetadata),
+        (status = 400, content_type = "text/plain", description = "The `If-Match` header is malformed or does not match the expected editions in the request body"),
+        (status = 409, content_type = "application/json", description = "The entity was updated since the expected editions or a property constrained to be unique is already used by another entity in the web"),
+        (status = 412, content_type = "text/plain", description = "The entity does not exist or its latest edition does not match the `If-Match` header"),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
 
@@ -740,15 +1117,45 @@ where
 )]
 async fn patch_entity<S, A>(
     AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    IfMatchHeader(if_match): IfMatchHeader,
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(params): Json<PatchEntityParams>,
+    Json(mut params): Json<PatchEntityParams>,
 ) -> Result<Json<EntityMetadata>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
+    match &if_match {
+        Some(IfMatch::Editions(edition_ids)) if edition_ids.is_empty() => {
+            return Err((
+                StatusCode::PRECONDITION_FAILED,
+                "Weak entity tags in `If-Match` never match",
+            )
+                .into_response());
+        }
+        Some(IfMatch::Editions(edition_ids)) if params.expected_edition_ids.is_empty() => {
+            params.expected_edition_ids.clone_from(edition_ids);
+        }
+        Some(IfMatch::Editions(edition_ids))
+            if !params
+                .expected_edition_ids
+                .iter()
+                .all(|expected_edition_id| edition_ids.contains(expected_edition_id))
+                || !edition_ids
+                    .iter()
+                    .all(|edition_id| params.expected_edition_ids.contains(edition_id)) =>
+        {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "`If-Match` header does not match `expectedEditionIds`",
+            )
+                .into_response());
+        }
+        _ => {}
+    }
+
     let authorization_api = authorization_api_pool
         .acquire()
         .await
@@ -763,51 +1170,116 @@ where
         .patch_entity(actor_id, params)
         .await
         .map_err(|report| {
-            if report.contains::<EntityDoesNotExist>() {
-                report.attach(hash_status::StatusCode::NotFound)
+            if if_match.is_some()
+                && (report.contains::<EntityDoesNotExist>()
+                    || report.contains::<EntityEditionMismatch>())
+            {
+                (StatusCode::PRECONDITION_FAILED, format!("{report:?}")).into_response()
+            } else if report.contains::<EntityDoesNotExist>() {
+                report_to_response(report.attach(hash_status::StatusCode::NotFound))
             } else if report.contains::<RaceConditionOnUpdate>() {
-                report.attach(hash_status::StatusCode::Cancelled)
+                report_to_response(report.attach(hash_status::StatusCode::Cancelled))
             } else {
-                report
+                report_to_response(report)
             }
         })
-        .map_err(report_to_response)
         .map(Json)
 }
 
+/// The precondition passed in the `If-Match` header as specified in [RFC 9110].
+///
+/// Entity tags are the [`EntityEditionId`]s the request is based on and may be quoted or unquoted.
+/// If the header is missing, no edition is expected.
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-if-match
+struct IfMatchHeader(Option<IfMatch>);
+
+#[derive(Debug)]
+enum IfMatch {
+    /// `*`: The entity has to exist.
+    Any,
+    /// The latest edition of the entity has to be one of the listed editions.
+    ///
+    /// Weak entity tags are skipped as `If-Match` uses the strong comparison, so this may be
+    /// empty.
+    Editions(Vec<EntityEditionId>),
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatchHeader {
+    type Rejection = (StatusCode, Cow<'static, str>);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let mut header_values = parts.headers.get_all(IF_MATCH).iter().peekable();
+        if header_values.peek().is_none() {
+            return Ok(Self(None));
+        }
+
+        let mut any = false;
+        let mut edition_ids = Vec::new();
+        for header_value in header_values {
+            let header_string = header_value
+                .to_str()
+                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?;
+            for entity_tag in header_string.split(',').map(str::trim) {
+                if entity_tag == "*" {
+                    any = true;
+                } else if !entity_tag.starts_with("W/") {
+                    let uuid = Uuid::from_str(entity_tag.trim_matches('"')).map_err(|error| {
+                        (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string()))
+                    })?;
+                    edition_ids.push(EntityEditionId::new(uuid));
+                }
+            }
+        }
+
+        if any {
+            if !edition_ids.is_empty() {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Cow::Borrowed("`*` cannot be combined with other entity tags in `If-Match`"),
+                ));
+            }
+            Ok(Self(Some(IfMatch::Any)))
+        } else {
+            Ok(Self(Some(IfMatch::Editions(edition_ids))))
+        }
+    }
+}
+
 #[utoipa::path(
-    post,
-    path = "/entities/embeddings",
+    patch,
+    path = "/entities/bulk",
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
     ),
     responses(
-        (status = 204, content_type = "application/json", description = "The embeddings were created"),
+        (status = 200, content_type = "application/json", description = "The metadata of the updated entities", body = [EntityMetadata]),
+        (status = 409, content_type = "text/plain", description = "A property constrained to be unique is already used by another entity in the web"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "An entity that should be updated was unexpectedly updated at the same time"),
 
-        (status = 403, description = "Insufficient permissions to update the entity"),
+        (status = 404, description = "Entity ID or Entity Type URL was not found"),
         (status = 500, description = "Store error occurred"),
     ),
-    request_body = UpdateEntityEmbeddingsParams,
+    request_body = [PatchEntityParams],
 )]
-#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
-async fn update_entity_embeddings<S, A>(
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn patch_entities<S, A>(
     AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(body): Json<serde_json::Value>,
-) -> Result<(), Response>
+    Json(params): Json<Vec<PatchEntityParams>>,
+) -> Result<Json<Vec<EntityMetadata>>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
-    // Manually deserialize the request from a JSON value to allow borrowed deserialization and
-    // better error reporting.
-    let params = UpdateEntityEmbeddingsParams::deserialize(body)
-        .attach(hash_status::StatusCode::InvalidArgument)
-        .map_err(report_to_response)?;
-
     let authorization_api = authorization_api_pool
         .acquire()
         .await
@@ -819,35 +1291,50 @@ where
         .map_err(report_to_response)?;
 
     store
-        .update_entity_embeddings(actor_id, params)
+        .patch_entities(actor_id, params)
         .await
+        .map_err(|report| {
+            if report.contains::<EntityDoesNotExist>() {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
         .map_err(report_to_response)
+        .map(Json)
 }
 
 #[utoipa::path(
-    post,
-    path = "/entities/diff",
+    delete,
+    path = "/entities",
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The difference between the two entities", body = DiffEntityResult),
+        (status = 200, content_type = "application/json", description = "The IDs of the erased entities", body = EraseEntitiesResponse),
+        (status = 400, content_type = "application/json", description = "An entity is still referenced by a link entity which would not be erased"),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
+        (status = 403, description = "Insufficient permissions to erase the entities"),
         (status = 404, description = "Entity ID was not found"),
         (status = 500, description = "Store error occurred"),
     ),
-    request_body = DiffEntityParams,
+    request_body = EraseEntitiesParams,
 )]
-#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
-async fn diff_entity<S, A>(
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn erase_entities<S, A>(
     AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(params): Json<DiffEntityParams>,
-) -> Result<Json<DiffEntityResult<'static>>, Response>
+    Json(params): Json<EraseEntitiesParams>,
+) -> Result<Json<EraseEntitiesResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -857,21 +1344,605 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let store = store_pool
+    let mut store = store_pool
         .acquire(authorization_api, temporal_client.0)
         .await
         .map_err(report_to_response)?;
 
     store
-        .diff_entity(actor_id, params)
+        .erase_entities(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/revert",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the edition created by the revert", body = EntityMetadata),
+        (status = 400, content_type = "text/plain", description = "The reverted-to contents are not valid anymore"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be reverted was unexpectedly updated at the same time"),
+
+        (status = 404, description = "The entity or the edition to revert to does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = RevertEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn revert_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<RevertEntityParams>,
+) -> Result<Json<EntityMetadata>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .revert_entity(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/correct",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the corrected editions", body = [EntityMetadata]),
+        (status = 400, content_type = "text/plain", description = "The decision time interval is empty"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be corrected was unexpectedly updated at the same time"),
+
+        (status = 404, description = "The entity does not exist within the decision time interval"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = CorrectEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn correct_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<CorrectEntityParams>,
+) -> Result<Json<Vec<EntityMetadata>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .correct_entity(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/drafts",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The drafts the actor is working on", body = [Entity]),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = ListDraftsParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn list_drafts<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<ListDraftsParams>,
+) -> Result<Json<Vec<Entity>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .list_drafts(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/drafts/publish",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the published entities", body = [EntityMetadata]),
+        (status = 400, content_type = "text/plain", description = "A draft could not be published"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "A draft that should be published was unexpectedly updated at the same time"),
+
+        (status = 404, description = "A draft does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = PublishDraftsParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn publish_drafts<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<PublishDraftsParams>,
+) -> Result<Json<Vec<EntityMetadata>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .publish_drafts(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/drafts/discard",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, content_type = "application/json", description = "The drafts were discarded"),
+        (status = 400, content_type = "text/plain", description = "An ID does not refer to a draft or a removed entity is still linked by another entity"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to discard a draft"),
+        (status = 404, description = "A draft does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = DiscardDraftsParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn discard_drafts<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<DiscardDraftsParams>,
+) -> Result<(), Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .discard_drafts(actor_id, params)
+        .await
+        .map_err(report_to_response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/merge",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the merged entity", body = EntityMetadata),
+        (status = 400, content_type = "application/json", description = "The merged entities are not distinct or the merged entity is invalid"),
+        (status = 409, content_type = "application/json", description = "One of the entities was updated while merging"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the entities"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MergeEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn merge_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MergeEntitiesParams>,
+) -> Result<Json<EntityMetadata>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .merge_entities(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/migrate",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The migrated entities and the entities which could not be migrated", body = MigrateEntitiesResponse),
+        (status = 400, content_type = "application/json", description = "The target type is not a different version of the source type"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MigrateEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn migrate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MigrateEntitiesParams>,
+) -> Result<Json<MigrateEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .migrate_entities(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/embeddings",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, content_type = "application/json", description = "The embeddings were created"),
+
+        (status = 403, description = "Insufficient permissions to update the entity"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = UpdateEntityEmbeddingsParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn update_entity_embeddings<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<(), Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    // Manually deserialize the request from a JSON value to allow borrowed deserialization and
+    // better error reporting.
+    let params = UpdateEntityEmbeddingsParams::deserialize(body)
+        .attach(hash_status::StatusCode::InvalidArgument)
+        .map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .update_entity_embeddings(actor_id, params)
+        .await
+        .map_err(report_to_response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/diff",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The difference between the two entities", body = DiffEntityResult),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = DiffEntityParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn diff_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<DiffEntityParams>,
+) -> Result<Json<DiffEntityResult<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .diff_entity(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityDoesNotExist>() {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/diff/web",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The changes to the entities of the web between the two points in time", body = DiffWebResult),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = DiffWebParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn diff_web<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<DiffWebParams>,
+) -> Result<Json<DiffWebResult<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .diff_web(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetEntityHistoryQuery {
+    axis: Option<TimeAxis>,
+    after: Option<Timestamp<VariableAxis>>,
+    limit: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/history",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The ID of the entity to read the history of"),
+        ("axis" = Option<TimeAxis>, Query, description = "The temporal axis to read the history along, defaults to the transaction time"),
+        ("after" = Option<String>, Query, description = "The cursor to start reading from"),
+        ("limit" = Option<usize>, Query, description = "The maximum number of editions to read"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The editions of the entity", body = GetEntityHistoryResponse),
+
+        (status = 403, description = "The actor is not permitted to view the entity"),
+        (status = 404, description = "The entity does not exist"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn get_entity_history<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    Query(query): Query<GetEntityHistoryQuery>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+) -> Result<Json<GetEntityHistoryResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let temporal_axes = match query.axis.unwrap_or(TimeAxis::TransactionTime) {
+        TimeAxis::DecisionTime => QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(Some(TemporalBound::Unbounded), None),
+        },
+        TimeAxis::TransactionTime => QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(Some(TemporalBound::Unbounded), None),
+        },
+    };
+
+    store
+        .get_entity_history(
+            actor_id,
+            GetEntityHistoryParams {
+                entity_id,
+                temporal_axes,
+                after: query.after,
+                limit: query.limit,
+            },
+        )
         .await
-        .map_err(|report| {
-            if report.contains::<EntityDoesNotExist>() {
-                report.attach(hash_status::StatusCode::NotFound)
-            } else {
-                report
-            }
-        })
         .map_err(report_to_response)
         .map(Json)
 }
@@ -30,9 +30,11 @@ use graph::{
     store::{
         error::{BaseUrlAlreadyExists, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
         ontology::{
-            ArchiveEntityTypeParams, CreateEntityTypeParams, GetEntityTypeSubgraphParams,
-            GetEntityTypesParams, GetEntityTypesResponse, UnarchiveEntityTypeParams,
-            UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            ArchiveEntityTypeParams, CreateEntityTypeParams, EntityTypeUniquenessConstraint,
+            GetEntityTypeSubgraphParams, GetEntityTypeUniquenessConstraintsParams,
+            GetEntityTypeUniquenessConstraintsResponse, GetEntityTypesParams,
+            GetEntityTypesResponse, UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams,
+            UpdateEntityTypesParams,
         },
         query::Filter,
         ConflictBehavior, EntityTypeStore, StorePool,
@@ -86,6 +88,9 @@ use crate::{
         update_entity_type_embeddings,
         archive_entity_type,
         unarchive_entity_type,
+        add_entity_type_uniqueness_constraint,
+        get_entity_type_uniqueness_constraints,
+        remove_entity_type_uniqueness_constraint,
     ),
     components(
         schemas(
@@ -113,6 +118,9 @@ use crate::{
             GetEntityTypeSubgraphResponse,
             ArchiveEntityTypeParams,
             UnarchiveEntityTypeParams,
+            EntityTypeUniquenessConstraint,
+            GetEntityTypeUniquenessConstraintsParams,
+            GetEntityTypeUniquenessConstraintsResponse,
         )
     ),
     tags(
@@ -162,7 +170,20 @@ impl RoutedResource for EntityTypeResource {
                 .route("/load", post(load_external_entity_type::<S, A>))
                 .route("/archive", put(archive_entity_type::<S, A>))
                 .route("/unarchive", put(unarchive_entity_type::<S, A>))
-                .route("/embeddings", post(update_entity_type_embeddings::<S, A>)),
+                .route("/embeddings", post(update_entity_type_embeddings::<S, A>))
+                .nest(
+                    "/uniqueness-constraints",
+                    Router::new()
+                        .route(
+                            "/",
+                            post(add_entity_type_uniqueness_constraint::<S, A>)
+                                .delete(remove_entity_type_uniqueness_constraint::<S, A>),
+                        )
+                        .route(
+                            "/query",
+                            post(get_entity_type_uniqueness_constraints::<S, A>),
+                        ),
+                ),
         )
     }
 }
@@ -1145,3 +1166,142 @@ where
         })
         .map(Json)
 }
+
+#[utoipa::path(
+    post,
+    path = "/entity-types/uniqueness-constraints",
+    tag = "EntityType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, description = "The uniqueness constraint was added"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the entity type"),
+        (status = 409, description = "The constraint already exists or entities in a web already share a value at the property path"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = EntityTypeUniquenessConstraint,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn add_entity_type_uniqueness_constraint<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<EntityTypeUniquenessConstraint>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .add_entity_type_uniqueness_constraint(actor_id, params)
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entity-types/uniqueness-constraints/query",
+    tag = "EntityType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The property paths constrained to be unique", body = GetEntityTypeUniquenessConstraintsResponse),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to view the entity type"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = GetEntityTypeUniquenessConstraintsParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn get_entity_type_uniqueness_constraints<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<GetEntityTypeUniquenessConstraintsParams>,
+) -> Result<Json<GetEntityTypeUniquenessConstraintsResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_entity_type_uniqueness_constraints(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/entity-types/uniqueness-constraints",
+    tag = "EntityType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, description = "The uniqueness constraint was removed"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the entity type"),
+        (status = 404, description = "The uniqueness constraint was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = EntityTypeUniquenessConstraint,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn remove_entity_type_uniqueness_constraint<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<EntityTypeUniquenessConstraint>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .remove_entity_type_uniqueness_constraint(actor_id, params)
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
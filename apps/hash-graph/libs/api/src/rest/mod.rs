@@ -12,11 +12,13 @@ mod status;
 mod utoipa_typedef;
 
 mod account;
+mod change;
 mod data_type;
 mod entity;
 mod entity_type;
 mod property_type;
 mod web;
+mod webhook;
 
 use std::{borrow::Cow, fs, io, str::FromStr, sync::Arc};
 
@@ -237,22 +239,26 @@ where
 {
     vec![
         account::AccountResource::routes::<S, A>(),
+        change::ChangeResource::routes::<S, A>(),
         data_type::DataTypeResource::routes::<S, A>(),
         property_type::PropertyTypeResource::routes::<S, A>(),
         entity_type::EntityTypeResource::routes::<S, A>(),
         entity::EntityResource::routes::<S, A>(),
         web::WebResource::routes::<S, A>(),
+        webhook::WebhookResource::routes::<S, A>(),
     ]
 }
 
 fn api_documentation() -> Vec<openapi::OpenApi> {
     vec![
         account::AccountResource::documentation(),
+        change::ChangeResource::documentation(),
         data_type::DataTypeResource::documentation(),
         property_type::PropertyTypeResource::documentation(),
         entity_type::EntityTypeResource::documentation(),
         entity::EntityResource::documentation(),
         web::WebResource::documentation(),
+        webhook::WebhookResource::documentation(),
     ]
 }
 
@@ -754,6 +760,108 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("StartsWithCaseInsensitiveFilter"))
+                                .property(
+                                    "startsWithCaseInsensitive",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("startsWithCaseInsensitive"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("EndsWithCaseInsensitiveFilter"))
+                                .property(
+                                    "endsWithCaseInsensitive",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("endsWithCaseInsensitive"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ContainsSegmentCaseInsensitiveFilter"))
+                                .property(
+                                    "containsSegmentCaseInsensitive",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("containsSegmentCaseInsensitive"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("RegexFilter"))
+                                .property(
+                                    "regex",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("regex"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("RegexCaseSensitiveFilter"))
+                                .property(
+                                    "regexCaseSensitive",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("regexCaseSensitive"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("MatchesFilter"))
+                                .property(
+                                    "matches",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("matches"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("InFilter"))
+                                .property(
+                                    "in",
+                                    ArrayBuilder::new()
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(
+                                                    ArrayBuilder::new().items(
+                                                        OneOfBuilder::new()
+                                                            .item(
+                                                                ObjectBuilder::new().schema_type(
+                                                                    SchemaType::String,
+                                                                ),
+                                                            )
+                                                            .item(
+                                                                ObjectBuilder::new().schema_type(
+                                                                    SchemaType::Number,
+                                                                ),
+                                                            ),
+                                                    ),
+                                                ),
+                                        )
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("in"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -796,6 +904,21 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "EntityQueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(Ref::from_schema_name("Selector"))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
             components.schemas.insert(
                 "EntityQuerySortingPath".to_owned(),
                 schema::Schema::Array(
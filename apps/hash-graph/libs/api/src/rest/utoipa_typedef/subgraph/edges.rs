@@ -197,6 +197,16 @@ impl From<graph::subgraph::edges::Edges> for Edges {
                             .property_type_to_data_type
                             .into_flattened::<OntologyOutwardEdge>(),
                     )
+                    .chain(
+                        edges
+                            .property_type_to_entity_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
+                    .chain(
+                        edges
+                            .data_type_to_property_type
+                            .into_flattened::<OntologyOutwardEdge>(),
+                    )
                     .fold(HashMap::new(), collect_merge),
             ),
             knowledge_graph: KnowledgeGraphRootedEdges(
@@ -0,0 +1,276 @@
+//! Web routes for managing webhooks.
+
+#![expect(clippy::str_to_string)]
+
+use std::sync::Arc;
+
+use authorization::AuthorizationApiPool;
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::Response,
+    routing::{delete, get, post},
+    Extension, Router,
+};
+use graph::store::{
+    change::EntityChangeOperation,
+    webhook::{
+        CreateWebhookParams, Webhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryId,
+        WebhookId, WebhookStore,
+    },
+    StorePool,
+};
+use graph_types::owned_by_id::OwnedById;
+use serde::Deserialize;
+use temporal_client::TemporalClient;
+use utoipa::{OpenApi, ToSchema};
+
+use super::api_resource::RoutedResource;
+use crate::rest::{json::Json, status::report_to_response, AuthenticatedUserHeader};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        create_webhook,
+        get_webhooks,
+        delete_webhook,
+        get_webhook_deliveries,
+    ),
+    components(
+        schemas(
+            WebhookId,
+            WebhookDeliveryId,
+            Webhook,
+            WebhookDelivery,
+            WebhookDeliveryAttempt,
+            EntityChangeOperation,
+
+            CreateWebhookParams,
+            GetWebhooksParams,
+        ),
+    ),
+    tags(
+        (name = "Webhook", description = "Webhook management API")
+    )
+)]
+pub(crate) struct WebhookResource;
+
+impl RoutedResource for WebhookResource {
+    /// Create routes for managing webhooks.
+    fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().nest(
+            "/webhooks",
+            Router::new()
+                .route("/", post(create_webhook::<S, A>))
+                .route("/query", post(get_webhooks::<S, A>))
+                .nest(
+                    "/:webhook_id",
+                    Router::new()
+                        .route("/", delete(delete_webhook::<S, A>))
+                        .route("/deliveries", get(get_webhook_deliveries::<S, A>)),
+                ),
+        )
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhookParams,
+    tag = "Webhook",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The created webhook", body = Webhook),
+
+        (status = 400, description = "The URL of the webhook is not valid"),
+        (status = 403, description = "The actor is not permitted to manage the web"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, params)
+)]
+async fn create_webhook<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<CreateWebhookParams>,
+) -> Result<Json<Webhook>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .create_webhook(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetWebhooksParams {
+    owned_by_id: OwnedById,
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhooks/query",
+    request_body = GetWebhooksParams,
+    tag = "Webhook",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The webhooks of the web", body = [Webhook]),
+
+        (status = 403, description = "The actor is not permitted to manage the web"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_webhooks<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<GetWebhooksParams>,
+) -> Result<Json<Vec<Webhook>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_webhooks(actor_id, params.owned_by_id)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{webhook_id}",
+    tag = "Webhook",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("webhook_id" = WebhookId, Path, description = "The ID of the webhook to remove"),
+    ),
+    responses(
+        (status = 204, description = "The webhook was removed"),
+
+        (status = 403, description = "The actor is not permitted to manage the web of the webhook"),
+        (status = 404, description = "The webhook does not exist"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn delete_webhook<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(webhook_id): Path<WebhookId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .delete_webhook(actor_id, webhook_id)
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhooks/{webhook_id}/deliveries",
+    tag = "Webhook",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("webhook_id" = WebhookId, Path, description = "The ID of the webhook to read the delivery log of"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The deliveries of the webhook, starting with the most recent one", body = [WebhookDelivery]),
+
+        (status = 403, description = "The actor is not permitted to manage the web of the webhook"),
+        (status = 404, description = "The webhook does not exist"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_webhook_deliveries<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(webhook_id): Path<WebhookId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+) -> Result<Json<Vec<WebhookDelivery>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_webhook_deliveries(actor_id, webhook_id)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fmt, str::FromStr};
 
-use graph_types::knowledge::entity::Entity;
+use graph_types::knowledge::{entity::Entity, PropertyPath, PropertyPathElement};
 use serde::{
     de::{self, SeqAccess, Visitor},
     Deserialize, Deserializer,
@@ -365,6 +365,43 @@ pub enum EntityQueryPath<'p> {
     ///
     /// It's currently not possible to query for the list of property provenance values directly.
     PropertyProvenance(Option<JsonPath<'p>>),
+    /// The confidence value of a single property of the [`Entity`].
+    ///
+    /// Deserializes from `["propertyConfidence", ...]` where `...` is the path to the property.
+    /// Properties without a confidence value are treated as `null`.
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::knowledge::EntityQueryPath;
+    /// let path = EntityQueryPath::deserialize(json!([
+    ///     "propertyConfidence",
+    ///     "https://blockprotocol.org/@alice/types/property-type/name/"
+    /// ]))?;
+    /// assert_eq!(
+    ///     path.to_string(),
+    ///     r#"propertyConfidence.$."/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1""#
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    PropertyConfidence(JsonPath<'p>),
+    /// Corresponds to the sources in the provenance data of the properties of the [`Entity`].
+    ///
+    /// Deserializes from `["propertySource", ...]` where `...` is a path to a field of a
+    /// [`SourceProvenance`]. A filter on this path matches an [`Entity`] if any source of any of
+    /// its properties matches.
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use graph::knowledge::EntityQueryPath;
+    /// let path = EntityQueryPath::deserialize(json!(["propertySource", "location", "uri"]))?;
+    /// assert_eq!(path.to_string(), r#"propertySource.$."location"."uri""#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// [`SourceProvenance`]: graph_types::knowledge::entity::SourceProvenance
+    PropertySource(Option<JsonPath<'p>>),
     /// The embedding for the whole entity blob.
     ///
     /// Deserializes from `["embedding"]`:
@@ -400,6 +437,9 @@ impl fmt::Display for EntityQueryPath<'_> {
             Self::EditionProvenance(None) => fmt.write_str("editionProvenance"),
             Self::PropertyProvenance(Some(path)) => write!(fmt, "propertyProvenance.{path}"),
             Self::PropertyProvenance(None) => fmt.write_str("propertyProvenance"),
+            Self::PropertyConfidence(path) => write!(fmt, "propertyConfidence.{path}"),
+            Self::PropertySource(Some(path)) => write!(fmt, "propertySource.{path}"),
+            Self::PropertySource(None) => fmt.write_str("propertySource"),
             Self::Embedding => fmt.write_str("embedding"),
             Self::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
@@ -455,6 +495,8 @@ impl QueryPath for EntityQueryPath<'_> {
             | Self::Provenance(_)
             | Self::EditionProvenance(_)
             | Self::PropertyProvenance(_)
+            | Self::PropertyConfidence(_)
+            | Self::PropertySource(_)
             | Self::LeftEntityProvenance
             | Self::RightEntityProvenance => ParameterType::Any,
             Self::EntityConfidence | Self::LeftEntityConfidence | Self::RightEntityConfidence => {
@@ -469,6 +511,14 @@ impl QueryPath for EntityQueryPath<'_> {
             Self::EntityEdge { path, .. } => path.expected_type(),
         }
     }
+
+    fn accepts_entity_ids(&self) -> bool {
+        match self {
+            Self::Uuid => true,
+            Self::EntityEdge { path, .. } => path.accepts_entity_ids(),
+            _ => false,
+        }
+    }
 }
 
 /// A single token in an [`EntityQueryPath`].
@@ -486,6 +536,8 @@ pub enum EntityQueryToken {
     Properties,
     Provenance,
     EditionProvenance,
+    PropertyConfidence,
+    PropertySource,
     Embedding,
     IncomingLinks,
     OutgoingLinks,
@@ -500,10 +552,10 @@ pub struct EntityQueryPathVisitor {
 }
 
 impl EntityQueryPathVisitor {
-    pub const EXPECTING: &'static str = "one of `uuid`, `editionId`, `draftId`, `archived`, \
-                                         `ownedById`, `type`, `properties`, `provenance`, \
-                                         `editionProvenance`, `embedding`, `incomingLinks`, \
-                                         `outgoingLinks`, `leftEntity`, `rightEntity`";
+    pub const EXPECTING: &'static str =
+        "one of `uuid`, `editionId`, `draftId`, `archived`, `ownedById`, `type`, `properties`, \
+         `provenance`, `editionProvenance`, `propertyConfidence`, `propertySource`, `embedding`, \
+         `incomingLinks`, `outgoingLinks`, `leftEntity`, `rightEntity`";
 
     #[must_use]
     pub const fn new(position: usize) -> Self {
@@ -518,6 +570,7 @@ impl<'de> Visitor<'de> for EntityQueryPathVisitor {
         formatter.write_str(Self::EXPECTING)
     }
 
+    #[expect(clippy::too_many_lines)]
     fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
@@ -585,6 +638,22 @@ impl<'de> Visitor<'de> for EntityQueryPathVisitor {
                     )))
                 }
             }
+            EntityQueryToken::PropertyConfidence => {
+                visit_property_confidence(&mut seq, &mut self.position)?
+            }
+            EntityQueryToken::PropertySource => {
+                let mut path_tokens = Vec::new();
+                while let Some(field) = seq.next_element::<PathToken<'de>>()? {
+                    path_tokens.push(field);
+                    self.position += 1;
+                }
+
+                if path_tokens.is_empty() {
+                    EntityQueryPath::PropertySource(None)
+                } else {
+                    EntityQueryPath::PropertySource(Some(JsonPath::from_path_tokens(path_tokens)))
+                }
+            }
             EntityQueryToken::LeftEntity => EntityQueryPath::EntityEdge {
                 edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
                 path: Box::new(Self::new(self.position).visit_seq(seq)?),
@@ -618,6 +687,31 @@ impl<'de> Visitor<'de> for EntityQueryPathVisitor {
     }
 }
 
+/// Deserializes the remaining elements of `seq` as the path to a property and returns the
+/// [`EntityQueryPath::PropertyConfidence`] for it.
+///
+/// Property metadata is keyed by the JSON pointer of the property path, so the pointer is used as
+/// the only field of the resulting [`JsonPath`].
+fn visit_property_confidence<'de, A>(
+    seq: &mut A,
+    position: &mut usize,
+) -> Result<EntityQueryPath<'de>, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let mut property_path = PropertyPath::default();
+    while let Some(element) = seq.next_element::<PropertyPathElement<'de>>()? {
+        property_path.push(element);
+        *position += 1;
+    }
+
+    Ok(EntityQueryPath::PropertyConfidence(
+        JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Owned(
+            property_path.to_json_pointer(),
+        ))]),
+    ))
+}
+
 impl<'de: 'p, 'p> Deserialize<'de> for EntityQueryPath<'p> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -637,6 +731,7 @@ pub enum EntityQuerySortingToken {
     RecordCreatedAtDecisionTime,
     CreatedAtTransactionTime,
     CreatedAtDecisionTime,
+    PropertyConfidence,
 }
 
 /// Deserializes an [`EntityQueryPath`] from a string sequence.
@@ -648,7 +743,8 @@ pub struct EntityQuerySortingVisitor {
 impl EntityQuerySortingVisitor {
     pub const EXPECTING: &'static str =
         "one of `archived`, `properties`, `recordCreatedAtTransactionTime`, \
-         `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`";
+         `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`, \
+         `propertyConfidence`";
 
     #[must_use]
     pub const fn new(position: usize) -> Self {
@@ -701,6 +797,9 @@ impl<'de> Visitor<'de> for EntityQuerySortingVisitor {
                     EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(path_tokens)))
                 }
             }
+            EntityQuerySortingToken::PropertyConfidence => {
+                visit_property_confidence(&mut seq, &mut self.position)?
+            }
         })
     }
 }
@@ -764,6 +863,12 @@ impl<'de: 'p, 'p> EntityQueryPath<'p> {
             Self::PropertyProvenance(path) => {
                 EntityQueryPath::PropertyProvenance(path.map(JsonPath::into_owned))
             }
+            Self::PropertyConfidence(path) => {
+                EntityQueryPath::PropertyConfidence(path.into_owned())
+            }
+            Self::PropertySource(path) => {
+                EntityQueryPath::PropertySource(path.map(JsonPath::into_owned))
+            }
         }
     }
 }
@@ -855,6 +960,17 @@ mod tests {
                 Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/name/")
             )])))
         );
+        assert_eq!(
+            deserialize([
+                "propertyConfidence",
+                "https://blockprotocol.org/@alice/types/property-type/name/"
+            ]),
+            EntityQueryPath::PropertyConfidence(JsonPath::from_path_tokens(vec![
+                PathToken::Field(Cow::Borrowed(
+                    "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1"
+                ))
+            ]))
+        );
         assert_eq!(
             deserialize(["leftEntity", "uuid"]),
             EntityQueryPath::EntityEdge {
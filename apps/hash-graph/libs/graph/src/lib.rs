@@ -12,6 +12,7 @@
 #![feature(let_chains)]
 #![feature(never_type)]
 #![feature(extend_one)]
+#![feature(ip)]
 #![feature(doc_auto_cfg)]
 #![cfg_attr(not(miri), doc(test(attr(deny(warnings, clippy::all)))))]
 #![expect(
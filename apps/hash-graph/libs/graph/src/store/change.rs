@@ -0,0 +1,116 @@
+use error_stack::Report;
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{EntityEditionId, EntityId},
+};
+use serde::{Deserialize, Serialize};
+use temporal_versioning::{Timestamp, TransactionTime};
+use type_system::url::VersionedUrl;
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::store::QueryError;
+
+/// Position in the change feed.
+///
+/// Changes are ordered by the ID of the database transaction they were written in, followed by
+/// the transaction time they were recorded at and the ID of the record they refer to, i.e. the
+/// entity edition ID or the ontology ID.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChangeCursor {
+    pub transaction_id: i64,
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub record_id: Uuid,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityChangeOperation {
+    /// The first edition of the entity was created.
+    Create,
+    /// A new edition of the entity was created.
+    Update,
+    /// The entity was archived.
+    Archive,
+    /// The entity was unarchived.
+    Unarchive,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Change {
+    #[serde(rename_all = "camelCase")]
+    Entity {
+        operation: EntityChangeOperation,
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+        entity_type_ids: Vec<VersionedUrl>,
+    },
+    #[serde(rename_all = "camelCase")]
+    DataType { data_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    PropertyType { property_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    EntityType { entity_type_id: VersionedUrl },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeEvent {
+    pub cursor: ChangeCursor,
+    pub change: Change,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReadChangesParams {
+    /// Only changes recorded after this cursor are returned.
+    #[serde(default)]
+    pub after: Option<ChangeCursor>,
+    /// The maximum number of changes to scan.
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ReadChangesResponse {
+    pub changes: Vec<ChangeEvent>,
+    /// The cursor to continue reading from.
+    ///
+    /// This is the cursor of the last scanned change, which might be a change the actor is not
+    /// allowed to view. If no change was scanned, the cursor passed in the request is returned.
+    pub cursor: Option<ChangeCursor>,
+    /// Whether more changes might be recorded after `cursor` already.
+    ///
+    /// If this is `false`, all changes recorded so far have been read and later changes can be
+    /// read by passing `cursor` again.
+    pub has_more: bool,
+}
+
+/// Describes the API of a store implementation for the change feed.
+pub trait ChangeStore {
+    /// Reads the changes recorded after the cursor specified in `params`.
+    ///
+    /// Every new entity edition, including archiving and unarchiving an entity, and the creation of
+    /// every ontology type is reported in the order the writing transactions were started. Changes
+    /// are only reported once no transaction which might write an earlier change is running
+    /// anymore, so a change is never reported behind a cursor which was already returned. Changes
+    /// to records the actor is not allowed to view are omitted.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the changes from the store failed
+    fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> impl Future<Output = Result<ReadChangesResponse, Report<QueryError>>> + Send;
+}
@@ -1,6 +1,8 @@
 use std::fmt;
 
 use error_stack::Context;
+use graph_types::knowledge::{entity::EntityId, PropertyPath};
+use type_system::url::BaseUrl;
 
 #[derive(Debug)]
 pub struct StoreError;
@@ -97,6 +99,18 @@ impl fmt::Display for RaceConditionOnUpdate {
 
 impl Context for RaceConditionOnUpdate {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct EntityEditionMismatch;
+
+impl fmt::Display for EntityEditionMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The latest edition of the entity does not match the expected edition")
+    }
+}
+
+impl Context for EntityEditionMismatch {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct VersionedUrlAlreadyExists;
@@ -143,3 +157,80 @@ impl fmt::Display for MigrationError {
         fmt.write_str("The store encountered a migration error")
     }
 }
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityIsLinked;
+
+impl fmt::Display for EntityIsLinked {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity is referenced by a link entity which is not removed")
+    }
+}
+
+impl Context for EntityIsLinked {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct LinkedEntityIsDraft;
+
+impl fmt::Display for LinkedEntityIsDraft {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("A non-draft link entity must not link to an entity which is only a draft")
+    }
+}
+
+impl Context for LinkedEntityIsDraft {}
+
+#[derive(Debug)]
+#[must_use]
+pub enum SubgraphBudgetExceeded {
+    Vertices,
+    Edges,
+    TraversalTime,
+    StatementTimeout,
+}
+
+impl fmt::Display for SubgraphBudgetExceeded {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vertices => fmt.write_str("the subgraph exceeded the maximum number of vertices"),
+            Self::Edges => fmt.write_str("the subgraph exceeded the maximum number of edges"),
+            Self::TraversalTime => {
+                fmt.write_str("resolving the subgraph exceeded the maximum traversal time")
+            }
+            Self::StatementTimeout => {
+                fmt.write_str("a statement exceeded the maximum statement timeout")
+            }
+        }
+    }
+}
+
+impl Context for SubgraphBudgetExceeded {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct PropertyIsNotUnique {
+    pub entity_type_base_url: BaseUrl,
+    pub property_path: PropertyPath<'static>,
+    pub conflicting_entity_id: EntityId,
+}
+
+impl fmt::Display for PropertyIsNotUnique {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "The value at `{}` has to be unique for entities of `{}` in a web but is already used \
+             by entity `{}`",
+            self.property_path.to_json_pointer(),
+            self.entity_type_base_url,
+            self.conflicting_entity_id
+        )
+    }
+}
+
+impl Context for PropertyIsNotUnique {}
+
+/// Attached to the error of a batch operation to identify the item which failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BatchIndex(pub usize);
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, mem, sync::Arc};
+use std::{collections::HashSet, mem, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use authorization::{
@@ -11,6 +11,7 @@ use authorization::{
     AuthorizationApi,
 };
 use error_stack::{Report, Result, ResultExt};
+use futures::Stream;
 use graph_types::{
     account::AccountId,
     knowledge::entity::{Entity, EntityId, EntityMetadata},
@@ -25,6 +26,7 @@ use graph_types::{
 use tarpc::context;
 use temporal_client::TemporalClient;
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use time::OffsetDateTime;
 use tokio::net::ToSocketAddrs;
 use tokio_serde::formats::Json;
 use type_fetcher::fetcher::{FetchedOntologyType, FetcherClient};
@@ -34,24 +36,38 @@ use crate::{
     ontology::domain_validator::DomainValidator,
     store::{
         account::{InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams},
+        change::{ChangeStore, ReadChangesParams, ReadChangesResponse},
         crud::{QueryResult, Read, ReadPaginated, Sorting},
+        error::DeletionError,
         knowledge::{
-            CountEntitiesParams, CreateEntityParams, GetEntitiesParams, GetEntitiesResponse,
-            GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-            UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
+            AggregateEntitiesParams, CorrectEntityParams, CountEntitiesParams, CreateEntityParams,
+            DiffWebParams, DiffWebResult, DiscardDraftsParams, EntityAggregationGroup,
+            EraseEntitiesParams, EraseEntitiesResponse, FindEntityPathsParams,
+            FindEntityPathsResponse, GetEntitiesParams, GetEntitiesResponse,
+            GetEntityHistoryParams, GetEntityHistoryResponse, GetEntitySubgraphParams,
+            GetEntitySubgraphResponse, MergeEntitiesParams, MigrateEntitiesParams,
+            MigrateEntitiesResponse, PatchEntityParams, PublishDraftsParams, RevertEntityParams,
+            StreamEntitiesParams, UpdateEntityEmbeddingsParams, ValidateEntityError,
+            ValidateEntityParams,
         },
         ontology::{
             ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
             CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
-            GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
-            GetDataTypesResponse, GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
-            GetEntityTypesParams, GetEntityTypesResponse, GetPropertyTypeSubgraphParams,
-            GetPropertyTypeSubgraphResponse, GetPropertyTypesParams, GetPropertyTypesResponse,
-            UnarchiveDataTypeParams, UnarchiveEntityTypeParams, UnarchivePropertyTypeParams,
-            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams,
-            UpdateEntityTypesParams, UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            EntityTypeUniquenessConstraint, GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse,
+            GetDataTypesParams, GetDataTypesResponse, GetEntityTypeSubgraphParams,
+            GetEntityTypeSubgraphResponse, GetEntityTypeUniquenessConstraintsParams,
+            GetEntityTypeUniquenessConstraintsResponse, GetEntityTypesParams,
+            GetEntityTypesResponse, GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse,
+            GetPropertyTypesParams, GetPropertyTypesResponse, UnarchiveDataTypeParams,
+            UnarchiveEntityTypeParams, UnarchivePropertyTypeParams, UpdateDataTypeEmbeddingParams,
+            UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
         },
         query::Filter,
+        webhook::{
+            CreateWebhookParams, PendingWebhookDelivery, Webhook, WebhookDelivery,
+            WebhookDeliveryAttempt, WebhookDeliveryId, WebhookId, WebhookStore,
+        },
         AccountStore, ConflictBehavior, DataTypeStore, EntityStore, EntityTypeStore,
         InsertionError, PropertyTypeStore, QueryError, QueryRecord, StoreError, StorePool,
         UpdateError,
@@ -798,6 +814,79 @@ where
     }
 }
 
+impl<S, A> ChangeStore for FetchingStore<S, A>
+where
+    S: ChangeStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
+impl<S, A> WebhookStore for FetchingStore<S, A>
+where
+    S: WebhookStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn create_webhook(
+        &mut self,
+        actor_id: AccountId,
+        params: CreateWebhookParams,
+    ) -> Result<Webhook, InsertionError> {
+        self.store.create_webhook(actor_id, params).await
+    }
+
+    async fn get_webhooks(
+        &self,
+        actor_id: AccountId,
+        owned_by_id: OwnedById,
+    ) -> Result<Vec<Webhook>, QueryError> {
+        self.store.get_webhooks(actor_id, owned_by_id).await
+    }
+
+    async fn delete_webhook(
+        &mut self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<(), DeletionError> {
+        self.store.delete_webhook(actor_id, webhook_id).await
+    }
+
+    async fn get_webhook_deliveries(
+        &self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<Vec<WebhookDelivery>, QueryError> {
+        self.store
+            .get_webhook_deliveries(actor_id, webhook_id)
+            .await
+    }
+
+    async fn claim_webhook_deliveries(
+        &mut self,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<PendingWebhookDelivery>, QueryError> {
+        self.store.claim_webhook_deliveries(limit, lease).await
+    }
+
+    async fn record_webhook_delivery_attempt(
+        &mut self,
+        delivery_id: WebhookDeliveryId,
+        attempt: WebhookDeliveryAttempt,
+        next_attempt_at: Option<OffsetDateTime>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .record_webhook_delivery_attempt(delivery_id, attempt, next_attempt_at)
+            .await
+    }
+}
+
 impl<S, A> DataTypeStore for FetchingStore<S, A>
 where
     S: DataTypeStore + PropertyTypeStore + EntityTypeStore + Send + Sync,
@@ -1119,6 +1208,36 @@ where
             .update_entity_type_embeddings(actor_id, params)
             .await
     }
+
+    async fn add_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), InsertionError> {
+        self.store
+            .add_entity_type_uniqueness_constraint(actor_id, params)
+            .await
+    }
+
+    async fn get_entity_type_uniqueness_constraints(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityTypeUniquenessConstraintsParams,
+    ) -> Result<GetEntityTypeUniquenessConstraintsResponse, QueryError> {
+        self.store
+            .get_entity_type_uniqueness_constraints(actor_id, params)
+            .await
+    }
+
+    async fn remove_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), DeletionError> {
+        self.store
+            .remove_entity_type_uniqueness_constraint(actor_id, params)
+            .await
+    }
 }
 
 impl<S, A> EntityStore for FetchingStore<S, A>
@@ -1173,9 +1292,17 @@ where
         self.store.get_entities(actor_id, params).await
     }
 
-    async fn get_entity_subgraph(
+    async fn stream_entities(
         &self,
         actor_id: AccountId,
+        params: StreamEntitiesParams<'_>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send, QueryError> {
+        self.store.stream_entities(actor_id, params).await
+    }
+
+    async fn get_entity_subgraph(
+        &mut self,
+        actor_id: AccountId,
         params: GetEntitySubgraphParams<'_>,
     ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
         self.store.get_entity_subgraph(actor_id, params).await
@@ -1193,6 +1320,22 @@ where
             .await
     }
 
+    async fn diff_web(
+        &self,
+        actor_id: AccountId,
+        params: DiffWebParams,
+    ) -> Result<DiffWebResult<'static>, QueryError> {
+        self.store.diff_web(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, QueryError> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
     async fn count_entities(
         &self,
         actor_id: AccountId,
@@ -1201,12 +1344,33 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
-    async fn patch_entity(
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<Vec<EntityAggregationGroup>, QueryError> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
+    async fn patch_entities(
         &mut self,
         actor_id: AccountId,
-        params: PatchEntityParams,
-    ) -> Result<EntityMetadata, UpdateError> {
-        for entity_type_id in &params.entity_type_ids {
+        params: Vec<PatchEntityParams>,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        let type_ids = params
+            .iter()
+            .flat_map(|params| &params.entity_type_ids)
+            .collect::<HashSet<_>>();
+
+        for entity_type_id in type_ids {
             self.insert_external_types_by_reference(
                 actor_id,
                 OntologyTypeReference::EntityTypeReference(&EntityTypeReference::new(
@@ -1220,7 +1384,39 @@ where
             .change_context(UpdateError)?;
         }
 
-        self.store.patch_entity(actor_id, params).await
+        self.store.patch_entities(actor_id, params).await
+    }
+
+    async fn revert_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: RevertEntityParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store.revert_entity(actor_id, params).await
+    }
+
+    async fn correct_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store.correct_entity(actor_id, params).await
+    }
+
+    async fn publish_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: PublishDraftsParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store.publish_drafts(actor_id, params).await
+    }
+
+    async fn discard_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: DiscardDraftsParams,
+    ) -> Result<(), DeletionError> {
+        self.store.discard_drafts(actor_id, params).await
     }
 
     async fn update_entity_embeddings(
@@ -1230,4 +1426,40 @@ where
     ) -> Result<(), UpdateError> {
         self.store.update_entity_embeddings(actor_id, params).await
     }
+
+    async fn erase_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: EraseEntitiesParams,
+    ) -> Result<EraseEntitiesResponse, DeletionError> {
+        self.store.erase_entities(actor_id, params).await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn migrate_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntitiesParams,
+    ) -> Result<MigrateEntitiesResponse, UpdateError> {
+        self.insert_external_types_by_reference(
+            actor_id,
+            OntologyTypeReference::EntityTypeReference(&EntityTypeReference::new(
+                params.target_entity_type_id.clone(),
+            )),
+            ConflictBehavior::Skip,
+            FetchBehavior::ExcludeProvidedReferences,
+            &HashSet::new(),
+        )
+        .await
+        .change_context(UpdateError)?;
+
+        self.store.migrate_entities(actor_id, params).await
+    }
 }
@@ -1,24 +1,28 @@
-use std::{borrow::Cow, error::Error, fmt};
+use std::{borrow::Cow, error::Error, fmt, iter::once};
 
 use authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
 use error_stack::Report;
-use futures::TryFutureExt;
+use futures::{Stream, TryFutureExt};
 use graph_types::{
-    account::AccountId,
+    account::{AccountId, EditionCreatedById},
     knowledge::{
         entity::{
-            Entity, EntityEmbedding, EntityId, EntityMetadata, EntityUuid,
-            ProvidedEntityEditionProvenance,
+            Entity, EntityEditionId, EntityEditionProvenance, EntityEmbedding, EntityId,
+            EntityMetadata, EntityTemporalMetadata, EntityUuid, ProvidedEntityEditionProvenance,
         },
         link::LinkData,
-        Confidence, PropertyDiff, PropertyMetadataMap, PropertyObject, PropertyPatchOperation,
-        PropertyPath,
+        Confidence, Property, PropertyDiff, PropertyMetadataDiff, PropertyMetadataMap,
+        PropertyObject, PropertyPatchOperation, PropertyPath, PropertyPathElement,
+        PropertyProvenance,
     },
     owned_by_id::OwnedById,
 };
 use serde::{Deserialize, Serialize};
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
-use type_system::{url::VersionedUrl, ClosedEntityType, EntityType};
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    ClosedEntityType, EntityType,
+};
 #[cfg(feature = "utoipa")]
 use utoipa::{
     openapi,
@@ -30,10 +34,20 @@ use validation::ValidateEntityComponents;
 use crate::{
     knowledge::EntityQueryPath,
     store::{
-        crud::Sorting, postgres::CursorField, query::Filter, InsertionError, NullOrdering,
-        Ordering, QueryError, UpdateError,
+        crud::Sorting,
+        error::DeletionError,
+        postgres::CursorField,
+        query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+        AggregationFunction, InsertionError, NullOrdering, Ordering, QueryError, UpdateError,
+    },
+    subgraph::{
+        edges::{EdgeDirection, GraphResolveDepths},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableAxis,
+            VariableTemporalAxisUnresolved,
+        },
+        Subgraph,
     },
-    subgraph::{edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved, Subgraph},
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -119,6 +133,13 @@ impl EntityQuerySortingRecord<'_> {
 pub struct EntityQuerySorting<'s> {
     #[serde(borrow)]
     pub paths: Vec<EntityQuerySortingRecord<'s>>,
+    /// Orders the entities by how well their properties match this full-text search query before
+    /// applying the sorting `paths`.
+    ///
+    /// The most relevant entities are returned first. If a cursor is provided, its first value is
+    /// the relevance of the last returned entity.
+    #[serde(borrow, default)]
+    pub relevance: Option<Cow<'s, str>>,
     #[serde(borrow)]
     pub cursor: Option<EntityQueryCursor<'s>>,
 }
@@ -243,6 +264,84 @@ pub struct GetEntitiesResponse<'r> {
     pub count: Option<usize>,
 }
 
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StreamEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    #[serde(borrow)]
+    pub sorting: EntityQuerySorting<'static>,
+    pub include_drafts: bool,
+}
+
+/// Restricts which entities are traversed when resolving a [`Subgraph`].
+///
+/// The filters only apply to entities which are reached by following `hasLeftEntity` and
+/// `hasRightEntity` edges, the root entities are not affected.
+#[derive(Debug, Default, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTraversalFilters<'a> {
+    /// Only link entities matching this filter are followed from their left or right entity.
+    #[serde(borrow, default)]
+    pub link_entities: Option<Filter<'a, Entity>>,
+    /// Only entities matching this filter are followed from a link entity pointing to them.
+    #[serde(borrow, default)]
+    pub target_entities: Option<Filter<'a, Entity>>,
+}
+
+impl<'a> EntityTraversalFilters<'a> {
+    /// Returns the filter for entities reached by following an edge in the given direction.
+    #[must_use]
+    pub const fn for_direction(&self, direction: EdgeDirection) -> Option<&Filter<'a, Entity>> {
+        match direction {
+            EdgeDirection::Incoming => self.link_entities.as_ref(),
+            EdgeDirection::Outgoing => self.target_entities.as_ref(),
+        }
+    }
+}
+
+/// Determines what happens if resolving a [`Subgraph`] exceeds its [`SubgraphBudget`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SubgraphBudgetExceededBehavior {
+    /// The request fails.
+    #[default]
+    Error,
+    /// The traversal stops and the subgraph resolved so far is returned, flagged as incomplete.
+    Truncate,
+}
+
+/// Limits the resources used to resolve a [`Subgraph`].
+///
+/// The vertex, edge, and time budgets are checked between traversal steps, so the subgraph may
+/// exceed them by the size of a single step.
+#[derive(Debug, Default, Copy, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SubgraphBudget {
+    /// The maximum number of vertices in the subgraph.
+    #[serde(default)]
+    pub max_vertices: Option<usize>,
+    /// The maximum number of edges in the subgraph.
+    #[serde(default)]
+    pub max_edges: Option<usize>,
+    /// The maximum time in milliseconds spent on traversing the graph.
+    #[serde(default)]
+    pub max_traversal_time_ms: Option<u64>,
+    /// The maximum time in milliseconds a single database statement may take.
+    ///
+    /// The statement is cancelled by the database, so exceeding this budget always results in an
+    /// error.
+    #[serde(default)]
+    pub statement_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub on_exceeded: SubgraphBudgetExceededBehavior,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -251,6 +350,10 @@ pub struct GetEntitySubgraphParams<'a> {
     pub filter: Filter<'a, Entity>,
     pub temporal_axes: QueryTemporalAxesUnresolved,
     pub graph_resolve_depths: GraphResolveDepths,
+    #[serde(borrow, default)]
+    pub traversal_filters: EntityTraversalFilters<'a>,
+    #[serde(default)]
+    pub budget: SubgraphBudget,
     #[serde(borrow)]
     pub sorting: EntityQuerySorting<'static>,
     pub limit: Option<usize>,
@@ -264,6 +367,8 @@ pub struct GetEntitySubgraphResponse<'r> {
     pub subgraph: Subgraph,
     pub cursor: Option<EntityQueryCursor<'r>>,
     pub count: Option<usize>,
+    /// Whether the traversal was stopped early because the [`SubgraphBudget`] was exceeded.
+    pub incomplete: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -276,11 +381,89 @@ pub struct CountEntitiesParams<'a> {
     pub include_drafts: bool,
 }
 
+/// An aggregate computed over each group of entities in [`EntityStore::aggregate_entities`].
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityAggregation<'p> {
+    pub function: AggregationFunction,
+    /// The path of the values to aggregate.
+    ///
+    /// This is only optional for [`AggregationFunction::Count`].
+    #[serde(borrow, default)]
+    pub path: Option<EntityQueryPath<'p>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesParams<'p> {
+    #[serde(borrow)]
+    pub filter: Filter<'p, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    /// The paths to group the entities by.
+    ///
+    /// If no path is specified, all entities are aggregated into a single group.
+    #[serde(borrow, default)]
+    pub group_by: Vec<EntityQueryPath<'p>>,
+    #[serde(borrow)]
+    pub aggregations: Vec<EntityAggregation<'p>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAggregationGroup {
+    /// The values of the `groupBy` paths shared by the entities in this group.
+    pub keys: Vec<serde_json::Value>,
+    /// The results of the `aggregations` in the order they were requested.
+    pub values: Vec<serde_json::Value>,
+}
+
+/// Finds the shortest paths between two entities in [`EntityStore::find_entity_paths`].
+///
+/// Link entities are followed regardless of their direction, so a path may go from a link
+/// entity's right entity to its left entity.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FindEntityPathsParams {
+    pub source_entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    /// The maximum number of link entities on a path.
+    pub max_depth: u8,
+    /// Only link entities of one of these types are followed.
+    ///
+    /// If this is not specified, all link entities are followed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub link_entity_type_ids: Option<Vec<VersionedUrl>>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+}
+
+#[derive(Debug)]
+pub struct FindEntityPathsResponse {
+    /// The entities and edges on the shortest paths.
+    ///
+    /// The source entity is the root of the subgraph. If no path was found within the maximum
+    /// depth, the subgraph only contains the source entity.
+    pub subgraph: Subgraph,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PatchEntityParams {
     pub entity_id: EntityId,
+    /// The editions the patch may be based on.
+    ///
+    /// If not empty, the patch is only applied if the latest edition of the entity is one of these
+    /// editions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_ids: Vec<EntityEditionId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub decision_time: Option<Timestamp<DecisionTime>>,
@@ -314,6 +497,73 @@ pub struct UpdateEntityEmbeddingsParams<'e> {
     pub reset: bool,
 }
 
+/// The edition an entity is reverted to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum EntityRevertTarget {
+    /// A specific edition of the entity.
+    EditionId(EntityEditionId),
+    /// The edition of the entity which was current at the given transaction time.
+    TransactionTime(Timestamp<TransactionTime>),
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RevertEntityParams {
+    pub entity_id: EntityId,
+    pub target: EntityRevertTarget,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+/// Corrects the properties of an entity for a bounded interval in decision time.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CorrectEntityParams {
+    pub entity_id: EntityId,
+    /// The start of the decision time interval to correct, inclusive.
+    pub decision_time_start: Timestamp<DecisionTime>,
+    /// The end of the decision time interval to correct, exclusive.
+    pub decision_time_end: Timestamp<DecisionTime>,
+    pub properties: Vec<PropertyPatchOperation>,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+/// Lists the drafts an actor is working on.
+#[derive(Debug, Default, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ListDraftsParams {
+    /// Only drafts of entities in this web are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub owned_by_id: Option<OwnedById>,
+}
+
+/// Publishes a set of drafts in a single transaction.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PublishDraftsParams {
+    /// The drafts to publish. Every ID has to contain a draft ID.
+    pub entity_ids: Vec<EntityId>,
+    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+/// Discards a set of drafts in a single transaction.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DiscardDraftsParams {
+    /// The drafts to discard. Every ID has to contain a draft ID.
+    pub entity_ids: Vec<EntityId>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -339,6 +589,342 @@ pub struct DiffEntityParams {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct DiffEntityResult<'e> {
     pub properties: Vec<PropertyDiff<'e>>,
+    /// Changes to the confidence and provenance of individual properties.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub property_metadata: Vec<PropertyMetadataDiff<'e>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entity_type_ids: Vec<EntityTypeIdDiff<'e>>,
+    /// The archived state of the second entity if it differs from the first entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub archived: Option<bool>,
+    /// The draft state of the second entity if it differs from the first entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub confidence: Option<EntityConfidenceDiff>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub link_data: Option<LinkDataDiff<'e>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum EntityTypeIdDiff<'e> {
+    Added { added: Cow<'e, VersionedUrl> },
+    Removed { removed: Cow<'e, VersionedUrl> },
+}
+
+/// The change of the confidence of an entity.
+///
+/// An absent value means that the entity does not have a confidence in the respective edition.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityConfidenceDiff {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub old: Option<Confidence>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub new: Option<Confidence>,
+}
+
+/// The change of the link data of an entity, including its endpoints and their metadata.
+///
+/// An absent value means that the entity is not a link in the respective edition.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LinkDataDiff<'e> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub old: Option<Cow<'e, LinkData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub new: Option<Cow<'e, LinkData>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DiffWebParams {
+    pub owned_by_id: OwnedById,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(required = true))]
+    pub first_decision_time: Option<Timestamp<DecisionTime>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(required = true))]
+    pub first_transaction_time: Option<Timestamp<TransactionTime>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(required = true))]
+    pub second_decision_time: Option<Timestamp<DecisionTime>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(required = true))]
+    pub second_transaction_time: Option<Timestamp<TransactionTime>>,
+    /// Only entities which are of one of these types at either point in time are compared.
+    ///
+    /// If not specified, entities of any type are compared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub entity_type_ids: Option<Vec<VersionedUrl>>,
+    /// Only entities with a UUID after this cursor are compared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub after: Option<EntityUuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub limit: Option<usize>,
+}
+
+/// An entity which exists at both points in time but changed in between.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ModifiedEntity<'e> {
+    pub entity_id: EntityId,
+    pub first_edition_id: EntityEditionId,
+    pub second_edition_id: EntityEditionId,
+    pub properties: Vec<PropertyDiff<'e>>,
+}
+
+/// The changes to the entities of a web between two points in time.
+///
+/// An entity is considered to exist at a point in time if it has an edition there which is not
+/// archived. Entities which were created and archived between the two points in time are not
+/// reported.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DiffWebResult<'e> {
+    /// Entities which exist at the second point in time but not at the first.
+    pub created: Vec<EntityId>,
+    /// Entities which exist at the first point in time but not at the second.
+    pub archived: Vec<EntityId>,
+    pub modified: Vec<ModifiedEntity<'e>>,
+    /// The cursor to pass as `after` to read the next page, if there are more entities.
+    ///
+    /// As entities the actor is not permitted to view are skipped, this might be set even if the
+    /// page is empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub cursor: Option<EntityUuid>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEntityHistoryParams {
+    pub entity_id: EntityId,
+    /// The temporal axes to read the history along.
+    ///
+    /// The editions are returned in the order of the variable axis, the pinned axis determines
+    /// which editions are visible.
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    /// Only editions starting after this timestamp on the variable axis are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub after: Option<Timestamp<VariableAxis>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub limit: Option<usize>,
+}
+
+/// A single edition in the history of an entity.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityHistoryEdition {
+    pub edition_id: EntityEditionId,
+    pub temporal_versioning: EntityTemporalMetadata,
+    pub provenance: EntityEditionProvenance,
+    /// The actor who created this edition.
+    pub actor_id: EditionCreatedById,
+    /// The changes to the properties compared to the previous edition.
+    ///
+    /// For the first edition of the entity every property is reported as added.
+    pub property_diff: Vec<PropertyDiff<'static>>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityHistoryResponse {
+    pub editions: Vec<EntityHistoryEdition>,
+    /// The cursor to pass as `after` to read the next page, if there are more editions.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<Timestamp<VariableAxis>>,
+}
+
+/// Determines how link entities are handled, which point at an entity that is being erased.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum LinkErasureBehavior {
+    /// Link entities pointing at an erased entity are erased as well.
+    Cascade,
+    /// The erasure fails if a link entity, which is not erased itself, points at an erased
+    /// entity.
+    #[default]
+    Restrict,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EraseEntitiesParams {
+    pub entity_ids: Vec<EntityId>,
+    #[serde(default)]
+    pub link_behavior: LinkErasureBehavior,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EraseEntitiesResponse {
+    pub erased_entity_ids: Vec<EntityId>,
+}
+
+/// Determines which value is kept when the merged entities specify the same property.
+///
+/// Conflicts are resolved per top-level property. The metadata of the kept property is kept as
+/// well.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyMergeStrategy {
+    /// The value of the target entity is kept. Properties missing on the target entity are taken
+    /// from the first duplicate specifying them.
+    #[default]
+    PreferTarget,
+    /// The value of the last duplicate specifying the property is kept.
+    PreferDuplicates,
+    /// The value with the highest confidence is kept. Values without a confidence are only kept if
+    /// no other value is available. On ties, the earlier value is kept, starting with the target
+    /// entity.
+    PreferHigherConfidence,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MergeEntitiesParams {
+    pub target_entity_id: EntityId,
+    pub duplicate_entity_ids: Vec<EntityId>,
+    #[serde(default)]
+    pub conflict_strategy: PropertyMergeStrategy,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+/// A declarative change to the properties of an [`Entity`] when migrating it to another version
+/// of its [`EntityType`].
+///
+/// A migration which does not apply to an entity, e.g. because the property to rename is not
+/// present, is skipped for that entity.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum PropertyMigration {
+    /// Renames a top-level property while keeping its value and metadata.
+    Rename { from: BaseUrl, to: BaseUrl },
+    /// Removes the value at `path`.
+    Drop { path: PropertyPath<'static> },
+    /// Sets `value` at `path` if no value is present yet.
+    SetDefault {
+        path: PropertyPath<'static>,
+        value: Property,
+    },
+    /// Moves the value at `from` to `path` while keeping its metadata.
+    Move {
+        from: PropertyPath<'static>,
+        path: PropertyPath<'static>,
+    },
+}
+
+impl PropertyMigration {
+    /// Returns the operation which applies this migration to the provided properties.
+    ///
+    /// Returns `None` if the migration does not apply to the properties.
+    #[must_use]
+    pub fn patch_operation(
+        &self,
+        properties: &PropertyObject,
+        metadata: &PropertyMetadataMap<'_>,
+    ) -> Option<PropertyPatchOperation> {
+        let move_operation = |from: PropertyPath<'static>, path: PropertyPath<'static>| {
+            let metadata = metadata.get(&from).cloned().unwrap_or_default();
+            PropertyPatchOperation::Move {
+                from,
+                path,
+                confidence: metadata.confidence,
+                provenance: metadata.provenance,
+            }
+        };
+
+        match self {
+            Self::Rename { from, to } => {
+                let from = once(PropertyPathElement::from(from.clone())).collect();
+                properties.path_exists(&from).then(|| {
+                    move_operation(from, once(PropertyPathElement::from(to.clone())).collect())
+                })
+            }
+            Self::Drop { path } => properties
+                .path_exists(path)
+                .then(|| PropertyPatchOperation::Remove { path: path.clone() }),
+            Self::SetDefault { path, value } => {
+                (!properties.path_exists(path)).then(|| PropertyPatchOperation::Add {
+                    path: path.clone(),
+                    value: value.clone(),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                })
+            }
+            Self::Move { from, path } => properties
+                .path_exists(from)
+                .then(|| move_operation(from.clone(), path.clone())),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MigrateEntitiesParams {
+    pub source_entity_type_id: VersionedUrl,
+    pub target_entity_type_id: VersionedUrl,
+    /// The migrations to apply to the properties, in order.
+    #[serde(default)]
+    pub property_migrations: Vec<PropertyMigration>,
+    #[serde(default)]
+    pub include_drafts: bool,
+    /// If set, the migration is validated for every entity but no entity is changed.
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityMigrationFailure {
+    pub entity_id: EntityId,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateEntitiesResponse {
+    /// The entities which were migrated, or would be migrated in a dry run.
+    pub migrated_entity_ids: Vec<EntityId>,
+    /// The entities which could not be migrated.
+    pub failures: Vec<EntityMigrationFailure>,
 }
 
 /// Describes the API of a store implementation for [Entities].
@@ -362,10 +948,14 @@ pub trait EntityStore {
         R: IntoIterator<Item = EntityRelationAndSubject> + Send,
     {
         self.create_entities(actor_id, vec![params])
-            .map_ok(|mut entities| {
-                let entity = entities.pop().expect("Expected a single entity");
-                assert!(entities.is_empty(), "Expected a single entity");
-                entity
+            .and_then(|entities| async move {
+                let [entity] = <[_; 1]>::try_from(entities).map_err(|entities: Vec<_>| {
+                    Report::new(InsertionError).attach_printable(format!(
+                        "Expected a single entity but {} were created",
+                        entities.len()
+                    ))
+                })?;
+                Ok(entity)
             })
     }
 
@@ -415,13 +1005,38 @@ pub trait EntityStore {
         params: GetEntitiesParams<'_>,
     ) -> impl Future<Output = Result<GetEntitiesResponse<'static>, Report<QueryError>>> + Send;
 
+    /// Get a stream of the entities specified by the [`StreamEntitiesParams`].
+    ///
+    /// In contrast to [`get_entities`], the entities are not collected in memory but yielded as
+    /// they are read from the store, so this is suitable for exporting large result sets.
+    /// Entities the actor is not permitted to view are skipped.
+    ///
+    /// [`get_entities`]: Self::get_entities
+    ///
+    /// # Errors
+    ///
+    /// - if the query cannot be started
+    ///
+    /// The stream itself yields an error if reading an [`Entity`] or checking its permissions
+    /// fails.
+    fn stream_entities(
+        &self,
+        actor_id: AccountId,
+        params: StreamEntitiesParams<'_>,
+    ) -> impl Future<
+        Output = Result<
+            impl Stream<Item = Result<Entity, Report<QueryError>>> + Send,
+            Report<QueryError>,
+        >,
+    > + Send;
+
     /// Get the [`Subgraph`]s specified by the [`GetEntitySubgraphParams`].
     ///
     /// # Errors
     ///
     /// - if the requested [`Entities`][Entity] cannot be retrieved
     fn get_entity_subgraph(
-        &self,
+        &mut self,
         actor_id: AccountId,
         params: GetEntitySubgraphParams<'_>,
     ) -> impl Future<Output = Result<GetEntitySubgraphResponse<'static>, Report<QueryError>>> + Send;
@@ -439,6 +1054,37 @@ pub trait EntityStore {
         params: CountEntitiesParams<'_>,
     ) -> impl Future<Output = Result<usize, Report<QueryError>>> + Send;
 
+    /// Groups the entities matching the filter and aggregates their values.
+    ///
+    /// Only entities the actor is permitted to view are taken into account. Like in
+    /// [`count_entities`], each edition in the temporal axes is a separate entry.
+    ///
+    /// # Errors
+    ///
+    /// - if an aggregation other than [`AggregationFunction::Count`] does not specify a path
+    /// - if the request to the database fails
+    ///
+    /// [`count_entities`]: Self::count_entities
+    fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<Vec<EntityAggregationGroup>, Report<QueryError>>> + Send;
+
+    /// Finds the shortest paths between two entities through link entities.
+    ///
+    /// Only entities the actor is permitted to view are traversed.
+    ///
+    /// # Errors
+    ///
+    /// - if the source entity cannot be retrieved
+    /// - if the request to the database fails
+    fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams,
+    ) -> impl Future<Output = Result<FindEntityPathsResponse, Report<QueryError>>> + Send;
+
     fn get_entity_by_id(
         &self,
         actor_id: AccountId,
@@ -447,12 +1093,177 @@ pub trait EntityStore {
         decision_time: Option<Timestamp<DecisionTime>>,
     ) -> impl Future<Output = Result<Entity, Report<QueryError>>> + Send;
 
+    /// Patches an existing [`Entity`].
+    ///
+    /// # Errors:
+    ///
+    /// - if the [`Entity`] doesn't exist
+    /// - if the patched [`Entity`] is not valid
+    /// - if the actor is not permitted to update the [`Entity`]
     fn patch_entity(
         &mut self,
         actor_id: AccountId,
         params: PatchEntityParams,
+    ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send {
+        self.patch_entities(actor_id, vec![params])
+            .and_then(|entities| async move {
+                let [entity] = <[_; 1]>::try_from(entities).map_err(|entities: Vec<_>| {
+                    Report::new(UpdateError).attach_printable(format!(
+                        "Expected a single entity but {} were updated",
+                        entities.len()
+                    ))
+                })?;
+                Ok(entity)
+            })
+    }
+
+    /// Patches existing [`Entities`][Entity] in a single transaction.
+    ///
+    /// Either all patches are applied or none of them. If a patch fails, the returned error states
+    /// the index of the failing patch.
+    ///
+    /// # Errors:
+    ///
+    /// - if any [`Entity`] doesn't exist
+    /// - if any patched [`Entity`] is not valid
+    /// - if the actor is not permitted to update any of the [`Entities`][Entity]
+    fn patch_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<PatchEntityParams>,
+    ) -> impl Future<Output = Result<Vec<EntityMetadata>, Report<UpdateError>>> + Send;
+
+    /// Reverts an [`Entity`] to a previous edition.
+    ///
+    /// A new edition is created whose properties, property metadata, entity types, and confidence
+    /// equal the target edition. The provenance of the new edition refers to the target edition.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] or the target edition doesn't exist
+    /// - if the reverted [`Entity`] is not valid
+    /// - if the actor is not permitted to update the [`Entity`]
+    fn revert_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: RevertEntityParams,
     ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send;
 
+    /// Corrects the properties of an [`Entity`] for a bounded interval in decision time.
+    ///
+    /// The patch is applied to every edition which is valid within the interval. The decision
+    /// times of these editions are split at the bounds of the interval, so the history outside of
+    /// the interval is kept intact. Returns the metadata of the corrected editions.
+    ///
+    /// # Errors
+    ///
+    /// - if the interval is empty
+    /// - if the [`Entity`] doesn't exist within the interval
+    /// - if a corrected edition is not valid
+    /// - if the actor is not permitted to update the [`Entity`]
+    fn correct_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: CorrectEntityParams,
+    ) -> impl Future<Output = Result<Vec<EntityMetadata>, Report<UpdateError>>> + Send;
+
+    /// Returns the drafts the actor is working on.
+    ///
+    /// A draft is returned if its latest edition was created by the actor. Only drafts the actor is
+    /// permitted to view are returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the drafts cannot be retrieved
+    fn list_drafts(
+        &self,
+        actor_id: AccountId,
+        params: ListDraftsParams,
+    ) -> impl Future<Output = Result<Vec<Entity>, Report<QueryError>>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let mut filters = vec![
+                Filter::NotEqual(Some(FilterExpression::Path(EntityQueryPath::DraftId)), None),
+                Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::EditionProvenance(
+                        Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+                            Cow::Borrowed("createdById"),
+                        )])),
+                    ))),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Owned(
+                        actor_id.into_uuid().to_string(),
+                    )))),
+                ),
+            ];
+            if let Some(owned_by_id) = params.owned_by_id {
+                filters.push(Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::OwnedById)),
+                    Some(FilterExpression::Parameter(Parameter::Uuid(
+                        owned_by_id.into_uuid(),
+                    ))),
+                ));
+            }
+
+            Ok(self
+                .get_entities(
+                    actor_id,
+                    GetEntitiesParams {
+                        filter: Filter::All(filters),
+                        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                            pinned: PinnedTemporalAxisUnresolved::new(None),
+                            variable: VariableTemporalAxisUnresolved::new(None, None),
+                        },
+                        sorting: EntityQuerySorting {
+                            paths: Vec::new(),
+                            relevance: None,
+                            cursor: None,
+                        },
+                        limit: None,
+                        include_drafts: true,
+                        include_count: false,
+                    },
+                )
+                .await?
+                .entities)
+        }
+    }
+
+    /// Publishes drafts in a single transaction.
+    ///
+    /// Either all drafts are published or none of them. Drafts which are not links are published
+    /// before link drafts, so a draft entity can be published together with its draft links.
+    /// Returns the metadata of the published editions in the order of the requested drafts.
+    ///
+    /// # Errors
+    ///
+    /// - if one of the IDs does not refer to a draft
+    /// - if a published [`Entity`] is not valid
+    /// - if a published link [`Entity`] links to an [`Entity`] which is only a draft
+    /// - if the actor is not permitted to update one of the [`Entities`][Entity]
+    fn publish_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: PublishDraftsParams,
+    ) -> impl Future<Output = Result<Vec<EntityMetadata>, Report<UpdateError>>> + Send;
+
+    /// Discards drafts in a single transaction.
+    ///
+    /// In contrast to archiving, the editions of the drafts are removed. If an [`Entity`] only
+    /// existed as draft, the [`Entity`] is removed entirely.
+    ///
+    /// # Errors
+    ///
+    /// - if one of the IDs does not refer to an existing draft
+    /// - if a removed [`Entity`] is linked by a link [`Entity`] which is not discarded as well
+    /// - if the actor is not permitted to update one of the [`Entities`][Entity]
+    fn discard_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: DiscardDraftsParams,
+    ) -> impl Future<Output = Result<(), Report<DeletionError>>> + Send;
+
     fn diff_entity(
         &self,
         actor_id: AccountId,
@@ -484,16 +1295,156 @@ pub trait EntityStore {
                 .diff(&second_entity.properties, &mut PropertyPath::default())
                 .map(PropertyDiff::into_owned)
                 .collect();
+            let property_metadata_diff = first_entity
+                .metadata
+                .properties
+                .diff(&second_entity.metadata.properties)
+                .into_iter()
+                .map(PropertyMetadataDiff::into_owned)
+                .collect();
+
+            let first_entity_type_ids = &first_entity.metadata.entity_type_ids;
+            let second_entity_type_ids = &second_entity.metadata.entity_type_ids;
+            let entity_type_id_diff = first_entity_type_ids
+                .iter()
+                .filter(|entity_type_id| !second_entity_type_ids.contains(entity_type_id))
+                .map(|entity_type_id| EntityTypeIdDiff::Removed {
+                    removed: Cow::Owned(entity_type_id.clone()),
+                })
+                .chain(
+                    second_entity_type_ids
+                        .iter()
+                        .filter(|entity_type_id| !first_entity_type_ids.contains(entity_type_id))
+                        .map(|entity_type_id| EntityTypeIdDiff::Added {
+                            added: Cow::Owned(entity_type_id.clone()),
+                        }),
+                )
+                .collect();
+
+            let first_draft = first_entity.metadata.record_id.entity_id.draft_id.is_some();
+            let second_draft = second_entity
+                .metadata
+                .record_id
+                .entity_id
+                .draft_id
+                .is_some();
 
             Ok(DiffEntityResult {
                 properties: property_diff,
+                property_metadata: property_metadata_diff,
+                entity_type_ids: entity_type_id_diff,
+                archived: (first_entity.metadata.archived != second_entity.metadata.archived)
+                    .then_some(second_entity.metadata.archived),
+                draft: (first_draft != second_draft).then_some(second_draft),
+                confidence: (first_entity.metadata.confidence != second_entity.metadata.confidence)
+                    .then_some(EntityConfidenceDiff {
+                        old: first_entity.metadata.confidence,
+                        new: second_entity.metadata.confidence,
+                    }),
+                link_data: (first_entity.link_data != second_entity.link_data).then(|| {
+                    LinkDataDiff {
+                        old: first_entity.link_data.map(Cow::Owned),
+                        new: second_entity.link_data.map(Cow::Owned),
+                    }
+                }),
             })
         }
     }
 
+    /// Compares the entities of a web at two points in time.
+    ///
+    /// Only entities with an edition starting or ending between the two points in time along
+    /// either temporal axis are considered. Only entities the actor is permitted to view at the
+    /// time of the request are reported.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities of the web cannot be retrieved
+    fn diff_web(
+        &self,
+        actor_id: AccountId,
+        params: DiffWebParams,
+    ) -> impl Future<Output = Result<DiffWebResult<'static>, Report<QueryError>>> + Send;
+
+    /// Returns every edition of the [`Entity`] ordered along the variable temporal axis.
+    ///
+    /// Each edition contains the changes to the properties compared to the edition before it.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not permitted to view the [`Entity`]
+    /// - if the [`Entity`] doesn't exist
+    fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> impl Future<Output = Result<GetEntityHistoryResponse, Report<QueryError>>> + Send;
+
     fn update_entity_embeddings(
         &mut self,
         actor_id: AccountId,
         params: UpdateEntityEmbeddingsParams<'_>,
     ) -> impl Future<Output = Result<(), Report<UpdateError>>> + Send;
+
+    /// Permanently removes every edition of the specified [`Entities`][Entity] from the store.
+    ///
+    /// In contrast to archiving, this cannot be undone. All drafts, embeddings, and authorization
+    /// relationships of the entities are removed as well. Link entities pointing at an erased
+    /// entity are handled as specified by [`LinkErasureBehavior`].
+    ///
+    /// Returns the IDs of all erased entities, including erased link entities.
+    ///
+    /// # Errors
+    ///
+    /// - if one of the entities does not exist
+    /// - if the actor does not have full access to one of the entities
+    /// - if a link entity points at an erased entity and [`LinkErasureBehavior::Restrict`] was
+    ///   specified
+    fn erase_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: EraseEntitiesParams,
+    ) -> impl Future<Output = Result<EraseEntitiesResponse, Report<DeletionError>>> + Send;
+
+    /// Merges duplicate [`Entities`][Entity] into a target [`Entity`].
+    ///
+    /// The properties of the duplicates are added to the target entity, conflicts are resolved as
+    /// specified by the [`PropertyMergeStrategy`]. The duplicates are archived afterwards and the
+    /// archived editions record the target entity they were merged into.
+    ///
+    /// As the endpoints of a link are not versioned, links pointing at a duplicate are archived
+    /// and replaced by new links pointing at the target entity. The archived link editions record
+    /// the link replacing them.
+    ///
+    /// Returns the metadata of the merged target entity.
+    ///
+    /// # Errors
+    ///
+    /// - if no duplicates are specified, or the target is specified as duplicate
+    /// - if one of the entities does not exist
+    /// - if the actor is not permitted to update one of the entities or a link pointing at a
+    ///   duplicate, or to create the replacing link in the web of that link
+    /// - if the merged entity is not valid
+    fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send;
+
+    /// Migrates all [`Entities`][Entity] of an [`EntityType`] to another version of that type.
+    ///
+    /// The [`PropertyMigration`]s are applied to every entity and the result is validated against
+    /// the target type. Entities which cannot be migrated are reported in the response and left
+    /// unchanged, all other entities are migrated. In a dry run no entity is changed at all.
+    ///
+    /// Only entities the actor is permitted to view are considered.
+    ///
+    /// # Errors
+    ///
+    /// - if the source and target type are the same, or are not versions of the same type
+    fn migrate_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntitiesParams,
+    ) -> impl Future<Output = Result<MigrateEntitiesResponse, Report<UpdateError>>> + Send;
 }
@@ -3,6 +3,7 @@ pub mod error;
 pub mod query;
 
 pub mod account;
+pub mod change;
 mod config;
 pub mod knowledge;
 mod migration;
@@ -10,6 +11,7 @@ pub mod ontology;
 mod pool;
 mod record;
 mod validation;
+pub mod webhook;
 
 mod fetcher;
 pub(crate) mod postgres;
@@ -21,6 +23,7 @@ use utoipa::ToSchema;
 
 pub use self::{
     account::AccountStore,
+    change::ChangeStore,
     config::{DatabaseConnectionInfo, DatabaseType},
     error::{
         BaseUrlAlreadyExists, InsertionError, OntologyVersionDoesNotExist, QueryError, StoreError,
@@ -37,6 +40,7 @@ pub use self::{
     postgres::{AsClient, PostgresStore, PostgresStorePool},
     record::{QueryRecord, SubgraphRecord},
     validation::{StoreCache, StoreProvider},
+    webhook::WebhookStore,
 };
 
 /// Describes the API of a store implementation.
@@ -47,11 +51,23 @@ pub use self::{
 /// raised depending on the implementation, e.g. connection issues.
 #[async_trait]
 pub trait Store:
-    AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    AccountStore
+    + DataTypeStore
+    + PropertyTypeStore
+    + EntityTypeStore
+    + EntityStore
+    + ChangeStore
+    + WebhookStore
 {
 }
 impl<S> Store for S where
-    S: AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    S: AccountStore
+        + DataTypeStore
+        + PropertyTypeStore
+        + EntityTypeStore
+        + EntityStore
+        + ChangeStore
+        + WebhookStore
 {
 }
 
@@ -78,3 +94,16 @@ pub enum NullOrdering {
     First,
     Last,
 }
+
+/// A function to aggregate the values of a group of records.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum AggregationFunction {
+    /// The number of records, or the number of values if a path is specified.
+    Count,
+    Sum,
+    Average,
+    Minimum,
+    Maximum,
+}
@@ -6,6 +6,7 @@ use authorization::schema::{
 use error_stack::Result;
 use graph_types::{
     account::AccountId,
+    knowledge::PropertyPath,
     ontology::{
         DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata, EntityTypeWithMetadata,
         OntologyTemporalMetadata, OntologyTypeClassificationMetadata, PropertyTypeMetadata,
@@ -21,7 +22,10 @@ use type_system::{
 };
 
 use crate::{
-    store::{query::Filter, ConflictBehavior, InsertionError, QueryError, UpdateError},
+    store::{
+        error::DeletionError, query::Filter, ConflictBehavior, InsertionError, QueryError,
+        UpdateError,
+    },
     subgraph::{
         edges::GraphResolveDepths,
         identifier::{DataTypeVertexId, EntityTypeVertexId, PropertyTypeVertexId},
@@ -537,6 +541,33 @@ pub struct UpdateEntityTypeEmbeddingParams<'a> {
     pub reset: bool,
 }
 
+/// Requires the value at `property_path` to be unique across the entities of a web.
+///
+/// The constraint applies to all versions of the entity type and to all entity types inheriting
+/// from it. Entities without a value at `property_path`, drafts, and archived entities are not
+/// constrained.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTypeUniquenessConstraint {
+    pub entity_type_id: VersionedUrl,
+    pub property_path: PropertyPath<'static>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEntityTypeUniquenessConstraintsParams {
+    pub entity_type_id: VersionedUrl,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityTypeUniquenessConstraintsResponse {
+    pub property_paths: Vec<PropertyPath<'static>>,
+}
+
 /// Describes the API of a store implementation for [`EntityType`]s.
 pub trait EntityTypeStore {
     /// Creates a new [`EntityType`].
@@ -649,4 +680,42 @@ pub trait EntityTypeStore {
 
         params: UpdateEntityTypeEmbeddingParams<'_>,
     ) -> impl Future<Output = Result<(), UpdateError>> + Send;
+
+    /// Adds an [`EntityTypeUniquenessConstraint`].
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to update the [`EntityType`]
+    /// - if the constraint already exists
+    /// - if entities in a web already share a value at the property path
+    fn add_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> impl Future<Output = Result<(), InsertionError>> + Send;
+
+    /// Returns the property paths constrained to be unique for the [`EntityType`].
+    ///
+    /// Constraints inherited from parent entity types are not included.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to view the [`EntityType`]
+    fn get_entity_type_uniqueness_constraints(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityTypeUniquenessConstraintsParams,
+    ) -> impl Future<Output = Result<GetEntityTypeUniquenessConstraintsResponse, QueryError>> + Send;
+
+    /// Removes an [`EntityTypeUniquenessConstraint`].
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to update the [`EntityType`]
+    /// - if the constraint does not exist
+    fn remove_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> impl Future<Output = Result<(), DeletionError>> + Send;
 }
@@ -0,0 +1,388 @@
+use authorization::{
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+    AuthorizationApi,
+};
+use error_stack::{Result, ResultExt};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{DraftId, EntityEditionId, EntityId, EntityUuid},
+    ontology::{DataTypeId, EntityTypeId, PropertyTypeId},
+    owned_by_id::OwnedById,
+};
+use temporal_versioning::{Timestamp, TransactionTime};
+use tokio_postgres::GenericClient;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use uuid::Uuid;
+
+use crate::store::{
+    change::{
+        Change, ChangeCursor, ChangeEvent, ChangeStore, EntityChangeOperation, ReadChangesParams,
+        ReadChangesResponse,
+    },
+    AsClient, PostgresStore, QueryError,
+};
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Reads up to `limit` entity changes recorded after `after`.
+    ///
+    /// An entity edition may be referenced by multiple rows in the temporal metadata, e.g. when
+    /// an update splits the decision time of a previous edition. Only the row which recorded the
+    /// edition first is considered a change.
+    ///
+    /// Changes written by transactions which might still be running are not returned, as they
+    /// could become visible after changes with a higher transaction ID have been read. Only the
+    /// changes written by the reading transaction itself are visible to it.
+    #[expect(clippy::too_many_lines)]
+    async fn read_entity_changes(
+        &self,
+        actor_id: AccountId,
+        after: Option<ChangeCursor>,
+        limit: i64,
+    ) -> Result<Vec<(ChangeCursor, Option<Change>)>, QueryError> {
+        let after_transaction_id = after.map(|cursor| cursor.transaction_id);
+        let after_transaction_time = after.map(|cursor| cursor.transaction_time);
+        let after_record_id = after.map(|cursor| cursor.record_id);
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        change.web_id,
+                        change.entity_uuid,
+                        change.draft_id,
+                        change.entity_edition_id,
+                        change.transaction_time,
+                        entity_editions.archived,
+                        (
+                            SELECT previous_edition.archived
+                            FROM entity_edition_changes AS previous
+                            JOIN entity_editions AS previous_edition
+                              ON previous_edition.entity_edition_id = previous.entity_edition_id
+                            WHERE previous.web_id = change.web_id
+                              AND previous.entity_uuid = change.entity_uuid
+                              AND previous.draft_id IS NOT DISTINCT FROM change.draft_id
+                              AND (
+                                  previous.transaction_id,
+                                  previous.transaction_time,
+                                  previous.entity_edition_id
+                              ) < (
+                                  change.transaction_id,
+                                  change.transaction_time,
+                                  change.entity_edition_id
+                              )
+                            ORDER BY
+                                previous.transaction_id DESC,
+                                previous.transaction_time DESC,
+                                previous.entity_edition_id DESC
+                            LIMIT 1
+                        ),
+                        entity_is_of_type_ids.base_urls,
+                        entity_is_of_type_ids.versions,
+                        change.transaction_id::TEXT::BIGINT
+                    FROM entity_edition_changes AS change
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id = change.entity_edition_id
+                    LEFT JOIN entity_is_of_type_ids
+                      ON entity_is_of_type_ids.entity_edition_id = change.entity_edition_id
+                    WHERE (
+                          change.transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+                          OR change.transaction_id = pg_current_xact_id_if_assigned()
+                      )
+                      AND (
+                          $1::BIGINT IS NULL
+                          OR (
+                              change.transaction_id,
+                              change.transaction_time,
+                              change.entity_edition_id
+                          ) > ($1::BIGINT::TEXT::XID8, $2, $3)
+                      )
+                    ORDER BY
+                        change.transaction_id,
+                        change.transaction_time,
+                        change.entity_edition_id
+                    LIMIT $4;
+                ",
+                &[
+                    &after_transaction_id,
+                    &after_transaction_time,
+                    &after_record_id,
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let changes = rows
+            .into_iter()
+            .map(|row| {
+                let entity_id = EntityId {
+                    owned_by_id: row.get::<_, OwnedById>(0),
+                    entity_uuid: row.get::<_, EntityUuid>(1),
+                    draft_id: row.get::<_, Option<DraftId>>(2),
+                };
+                let edition_id = row.get::<_, EntityEditionId>(3);
+                let cursor = ChangeCursor {
+                    transaction_id: row.get(9),
+                    transaction_time: row.get(4),
+                    record_id: edition_id.into_uuid(),
+                };
+                let operation = match (row.get::<_, Option<bool>>(6), row.get::<_, bool>(5)) {
+                    (None, _) => EntityChangeOperation::Create,
+                    (Some(false), true) => EntityChangeOperation::Archive,
+                    (Some(true), false) => EntityChangeOperation::Unarchive,
+                    (Some(_), _) => EntityChangeOperation::Update,
+                };
+                let entity_type_ids = row
+                    .get::<_, Option<Vec<BaseUrl>>>(7)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .zip(
+                        row.get::<_, Option<Vec<OntologyTypeVersion>>>(8)
+                            .unwrap_or_default(),
+                    )
+                    .map(|(base_url, version)| VersionedUrl { base_url, version })
+                    .collect();
+
+                (
+                    cursor,
+                    Change::Entity {
+                        operation,
+                        entity_id,
+                        edition_id,
+                        entity_type_ids,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let entity_ids = changes
+            .iter()
+            .filter_map(|(_, change)| match change {
+                Change::Entity { entity_id, .. } => Some(entity_id),
+                _ => None,
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        let (permissions, _zookie) = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        Ok(changes
+            .into_iter()
+            .map(|(cursor, change)| {
+                let visible = match &change {
+                    Change::Entity { entity_id, .. } => permissions
+                        .get(&entity_id.entity_uuid)
+                        .copied()
+                        .unwrap_or(false),
+                    _ => false,
+                };
+                (cursor, visible.then_some(change))
+            })
+            .collect())
+    }
+
+    /// Reads up to `limit` ontology type creations recorded after `after`.
+    ///
+    /// An ontology type which was archived and unarchived again is only reported once. As for
+    /// entities, changes written by transactions which might still be running are not returned.
+    #[expect(clippy::too_many_lines)]
+    async fn read_ontology_changes(
+        &self,
+        actor_id: AccountId,
+        after: Option<ChangeCursor>,
+        limit: i64,
+    ) -> Result<Vec<(ChangeCursor, Option<Change>)>, QueryError> {
+        let after_transaction_id = after.map(|cursor| cursor.transaction_id);
+        let after_transaction_time = after.map(|cursor| cursor.transaction_time);
+        let after_record_id = after.map(|cursor| cursor.record_id);
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        change.ontology_id,
+                        change.transaction_time,
+                        ontology_ids.base_url,
+                        ontology_ids.version,
+                        data_types.ontology_id IS NOT NULL,
+                        property_types.ontology_id IS NOT NULL,
+                        change.transaction_id::TEXT::BIGINT
+                    FROM ontology_changes AS change
+                    JOIN ontology_ids ON ontology_ids.ontology_id = change.ontology_id
+                    LEFT JOIN data_types ON data_types.ontology_id = change.ontology_id
+                    LEFT JOIN property_types ON property_types.ontology_id = change.ontology_id
+                    WHERE (
+                          change.transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+                          OR change.transaction_id = pg_current_xact_id_if_assigned()
+                      )
+                      AND (
+                          $1::BIGINT IS NULL
+                          OR (change.transaction_id, change.transaction_time, change.ontology_id)
+                            > ($1::BIGINT::TEXT::XID8, $2, $3)
+                      )
+                    ORDER BY change.transaction_id, change.transaction_time, change.ontology_id
+                    LIMIT $4;
+                ",
+                &[
+                    &after_transaction_id,
+                    &after_transaction_time,
+                    &after_record_id,
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let changes = rows
+            .into_iter()
+            .map(|row| {
+                let cursor = ChangeCursor {
+                    transaction_id: row.get(6),
+                    transaction_time: row.get::<_, Timestamp<TransactionTime>>(1),
+                    record_id: row.get::<_, Uuid>(0),
+                };
+                let ontology_type_id = VersionedUrl {
+                    base_url: row.get(2),
+                    version: row.get(3),
+                };
+                let change = if row.get(4) {
+                    Change::DataType {
+                        data_type_id: ontology_type_id,
+                    }
+                } else if row.get(5) {
+                    Change::PropertyType {
+                        property_type_id: ontology_type_id,
+                    }
+                } else {
+                    Change::EntityType {
+                        entity_type_id: ontology_type_id,
+                    }
+                };
+                (cursor, change)
+            })
+            .collect::<Vec<_>>();
+
+        let mut data_type_ids = Vec::new();
+        let mut property_type_ids = Vec::new();
+        let mut entity_type_ids = Vec::new();
+        for (cursor, change) in &changes {
+            match change {
+                Change::DataType { .. } => data_type_ids.push(DataTypeId::new(cursor.record_id)),
+                Change::PropertyType { .. } => {
+                    property_type_ids.push(PropertyTypeId::new(cursor.record_id));
+                }
+                Change::EntityType { .. } => {
+                    entity_type_ids.push(EntityTypeId::new(cursor.record_id));
+                }
+                Change::Entity { .. } => {}
+            }
+        }
+
+        let (data_type_permissions, _zookie) = self
+            .authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                data_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (property_type_permissions, _zookie) = self
+            .authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                property_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (entity_type_permissions, _zookie) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        Ok(changes
+            .into_iter()
+            .map(|(cursor, change)| {
+                let visible = match &change {
+                    Change::DataType { .. } => data_type_permissions
+                        .get(&DataTypeId::new(cursor.record_id))
+                        .copied(),
+                    Change::PropertyType { .. } => property_type_permissions
+                        .get(&PropertyTypeId::new(cursor.record_id))
+                        .copied(),
+                    Change::EntityType { .. } => entity_type_permissions
+                        .get(&EntityTypeId::new(cursor.record_id))
+                        .copied(),
+                    Change::Entity { .. } => None,
+                };
+                (cursor, visible.unwrap_or(false).then_some(change))
+            })
+            .collect())
+    }
+}
+
+impl<C, A> ChangeStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        let limit = i64::try_from(params.limit).unwrap_or(i64::MAX);
+
+        let mut changes = self
+            .read_entity_changes(actor_id, params.after, limit)
+            .await?;
+        changes.extend(
+            self.read_ontology_changes(actor_id, params.after, limit)
+                .await?,
+        );
+        changes.sort_by_key(|(cursor, _)| *cursor);
+
+        // Both sources are limited individually, so the merged changes might contain more than
+        // `limit` changes. If the page is filled, there might be more changes after it.
+        let has_more = changes.len() >= params.limit && params.limit > 0;
+        changes.truncate(params.limit);
+        let cursor = changes.last().map(|(cursor, _)| *cursor).or(params.after);
+
+        Ok(ReadChangesResponse {
+            changes: changes
+                .into_iter()
+                .filter_map(|(cursor, change)| {
+                    Some(ChangeEvent {
+                        cursor,
+                        change: change?,
+                    })
+                })
+                .collect(),
+            cursor,
+            has_more,
+        })
+    }
+}
@@ -0,0 +1,343 @@
+use std::borrow::Cow;
+
+use authorization::{schema::EntityPermission, zanzibar::Consistency, AuthorizationApi};
+use error_stack::{bail, Report, Result, ResultExt};
+use graph_types::{
+    account::{AccountId, EditionCreatedById},
+    knowledge::entity::{Entity, EntityEditionId, EntityEditionProvenance, EntityId},
+};
+use hash_status::StatusCode;
+use temporal_versioning::{
+    ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
+    OpenTemporalBound, TemporalBound, TemporalTagged, Timestamp, TransactionTime,
+};
+use tokio_postgres::error::SqlState;
+use validation::ValidateEntityComponents;
+
+use crate::{
+    knowledge::EntityQueryPath,
+    store::{
+        crud::Read,
+        error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        knowledge::{CorrectEntityParams, EntityValidationType, ValidateEntityParams},
+        postgres::knowledge::entity::LockedEntityEdition,
+        query::{Filter, FilterExpression, Parameter},
+        AsClient, EntityStore, PostgresStore, UpdateError,
+    },
+    subgraph::temporal_axes::{PinnedTemporalAxis, QueryTemporalAxes, VariableTemporalAxis},
+};
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Locks the editions of the entity which are valid within `decision_time`.
+    ///
+    /// The editions are returned in the order of their decision time.
+    async fn lock_entity_editions_in_interval(
+        &self,
+        entity_id: EntityId,
+        transaction_time: Timestamp<TransactionTime>,
+        decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    ) -> Result<Vec<LockedEntityEdition>, UpdateError> {
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        entity_temporal_metadata.entity_edition_id,
+                        entity_temporal_metadata.transaction_time,
+                        entity_temporal_metadata.decision_time
+                    FROM entity_temporal_metadata
+                    WHERE entity_temporal_metadata.web_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.draft_id IS NOT DISTINCT FROM $3
+                      AND entity_temporal_metadata.transaction_time @> $4::timestamptz
+                      AND entity_temporal_metadata.decision_time && $5
+                    ORDER BY lower(entity_temporal_metadata.decision_time)
+                    FOR NO KEY UPDATE NOWAIT;",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_id.draft_id,
+                    &transaction_time,
+                    &decision_time,
+                ],
+            )
+            .await
+            .map_err(|error| match error.code() {
+                Some(&SqlState::LOCK_NOT_AVAILABLE) => Report::new(RaceConditionOnUpdate)
+                    .attach(entity_id)
+                    .change_context(UpdateError),
+                _ => Report::new(error).change_context(UpdateError),
+            })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| LockedEntityEdition {
+                entity_id,
+                entity_edition_id: row.get(0),
+                transaction_time: row.get(1),
+                decision_time: row.get(2),
+            })
+            .collect())
+    }
+
+    /// Ends the transaction time of the locked edition at `transaction_time`.
+    async fn close_temporal_metadata(
+        &self,
+        locked_row: &LockedEntityEdition,
+        transaction_time: Timestamp<TransactionTime>,
+    ) -> Result<(), UpdateError> {
+        self.as_client()
+            .query(
+                "
+                    UPDATE entity_temporal_metadata
+                    SET transaction_time = tstzrange(lower(transaction_time), $4, '[)')
+                    WHERE entity_temporal_metadata.web_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.draft_id IS NOT DISTINCT FROM $3
+                      AND entity_temporal_metadata.transaction_time @> $4::timestamptz
+                      AND entity_temporal_metadata.decision_time = $5;",
+                &[
+                    &locked_row.entity_id.owned_by_id,
+                    &locked_row.entity_id.entity_uuid,
+                    &locked_row.entity_id.draft_id,
+                    &transaction_time,
+                    &locked_row.decision_time,
+                ],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
+    /// Makes the edition valid within `decision_time` from `transaction_time` on.
+    async fn insert_bounded_temporal_metadata(
+        &self,
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+        transaction_time: Timestamp<TransactionTime>,
+        decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    ) -> Result<(), UpdateError> {
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_temporal_metadata (
+                        web_id,
+                        entity_uuid,
+                        draft_id,
+                        entity_edition_id,
+                        transaction_time,
+                        decision_time
+                    ) VALUES (
+                        $1,
+                        $2,
+                        $3,
+                        $4,
+                        tstzrange($5, NULL, '[)'),
+                        $6
+                    );",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_id.draft_id,
+                    &edition_id,
+                    &transaction_time,
+                    &decision_time,
+                ],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
+    /// Applies a correction as part of the current transaction.
+    ///
+    /// Every edition which is valid within the corrected interval is split at the bounds of the
+    /// interval. The parts outside of the interval keep the edition, while the part inside of it
+    /// is replaced by a new, patched edition. Editions which are not changed by the patch are
+    /// left untouched.
+    ///
+    /// Returns the corrected [`Entities`][Entity].
+    #[expect(clippy::too_many_lines)]
+    pub(super) async fn correct_entity_in_transaction(
+        &self,
+        actor_id: AccountId,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<Entity>, UpdateError> {
+        let transaction_time = Timestamp::now().remove_nanosecond();
+        let decision_time_start = params.decision_time_start.remove_nanosecond();
+        let decision_time_end = params.decision_time_end.remove_nanosecond();
+        if decision_time_start >= decision_time_end {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(
+                        "the start of the decision time interval has to be before its end"
+                    )
+            );
+        }
+        let corrected_interval = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(decision_time_start),
+            OpenTemporalBound::Exclusive(decision_time_end),
+        );
+
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let locked_rows = self
+            .lock_entity_editions_in_interval(
+                params.entity_id,
+                transaction_time,
+                corrected_interval,
+            )
+            .await?;
+        if locked_rows.is_empty() {
+            bail!(
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            );
+        }
+
+        let mut corrected_entities = Vec::new();
+        for locked_row in locked_rows {
+            let Some(overlap) = locked_row.decision_time.intersect(corrected_interval) else {
+                continue;
+            };
+
+            let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+                *locked_row.transaction_time.start();
+            let ClosedTemporalBound::Inclusive(overlap_start) = *overlap.start();
+            let mut entity = Read::<Entity>::read_one(
+                self,
+                &Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::EditionId)),
+                    Some(FilterExpression::Parameter(Parameter::Uuid(
+                        locked_row.entity_edition_id.into_uuid(),
+                    ))),
+                ),
+                Some(&QueryTemporalAxes::DecisionTime {
+                    pinned: PinnedTemporalAxis::new(locked_transaction_time),
+                    variable: VariableTemporalAxis::new(
+                        TemporalBound::Inclusive(overlap_start),
+                        LimitedTemporalBound::Inclusive(overlap_start),
+                    ),
+                }),
+                true,
+            )
+            .await
+            .change_context(EntityDoesNotExist)
+            .attach(params.entity_id)
+            .change_context(UpdateError)?;
+
+            let previous_properties = entity.properties.clone();
+            let previous_property_metadata = entity.metadata.properties.clone();
+            entity
+                .patch(&params.properties)
+                .change_context(UpdateError)?;
+            if entity.properties == previous_properties
+                && entity.metadata.properties == previous_property_metadata
+            {
+                continue;
+            }
+
+            let edition_provenance = EntityEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                merged_into: None,
+                reverted_to: None,
+                provided: params.provenance.clone(),
+            };
+            let (edition_id, closed_schema) = self
+                .insert_entity_edition(
+                    entity.metadata.archived,
+                    &entity.metadata.entity_type_ids,
+                    &entity.properties,
+                    entity.metadata.confidence,
+                    &edition_provenance,
+                )
+                .await
+                .change_context(UpdateError)?;
+            self.insert_properties(edition_id, &entity.metadata.properties)
+                .await
+                .change_context(UpdateError)?;
+
+            self.validate_entity(
+                actor_id,
+                Consistency::FullyConsistent,
+                ValidateEntityParams {
+                    entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
+                    properties: Cow::Borrowed(&entity.properties),
+                    property_metadata: Cow::Borrowed(&entity.metadata.properties),
+                    link_data: entity.link_data.as_ref().map(Cow::Borrowed),
+                    components: if params.entity_id.draft_id.is_some() {
+                        ValidateEntityComponents::draft()
+                    } else {
+                        ValidateEntityComponents::full()
+                    },
+                },
+            )
+            .await
+            .change_context(UpdateError)
+            .attach(StatusCode::InvalidArgument)?;
+
+            // Only the currently valid edition is constrained by uniqueness constraints.
+            if params.entity_id.draft_id.is_none()
+                && !entity.metadata.archived
+                && overlap.contains_point(&transaction_time.cast())
+            {
+                self.ensure_unique_properties(
+                    params.entity_id,
+                    &entity.metadata.entity_type_ids,
+                    &entity.properties,
+                )
+                .await
+                .change_context(UpdateError)?;
+            }
+
+            self.close_temporal_metadata(&locked_row, transaction_time)
+                .await?;
+            for remaining in locked_row.decision_time.difference(corrected_interval) {
+                self.insert_bounded_temporal_metadata(
+                    params.entity_id,
+                    locked_row.entity_edition_id,
+                    transaction_time,
+                    remaining,
+                )
+                .await?;
+            }
+            self.insert_bounded_temporal_metadata(
+                params.entity_id,
+                edition_id,
+                transaction_time,
+                overlap,
+            )
+            .await?;
+
+            entity.metadata.record_id.edition_id = edition_id;
+            entity.metadata.temporal_versioning.decision_time = overlap;
+            entity.metadata.temporal_versioning.transaction_time = LeftClosedTemporalInterval::new(
+                ClosedTemporalBound::Inclusive(transaction_time),
+                OpenTemporalBound::Unbounded,
+            );
+            entity.metadata.provenance.edition = edition_provenance;
+            corrected_entities.push(entity);
+        }
+
+        Ok(corrected_entities)
+    }
+}
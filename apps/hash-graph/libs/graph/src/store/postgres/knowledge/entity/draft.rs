@@ -0,0 +1,463 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use authorization::{schema::EntityPermission, zanzibar::Consistency, AuthorizationApi};
+use error_stack::{bail, Report, Result, ResultExt};
+use graph_types::{
+    account::AccountId,
+    knowledge::{
+        entity::{DraftId, Entity, EntityEditionId, EntityId, EntityUuid},
+        link::LinkData,
+    },
+    owned_by_id::OwnedById,
+};
+use hash_status::StatusCode;
+
+use crate::{
+    store::{
+        crud::Read,
+        error::{
+            BatchIndex, DeletionError, EntityDoesNotExist, EntityIsLinked, LinkedEntityIsDraft,
+        },
+        knowledge::{DiscardDraftsParams, PatchEntityParams, PublishDraftsParams},
+        query::Filter,
+        AsClient, PostgresStore, QueryError, UpdateError,
+    },
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+
+/// Returns the positions of the drafts in the order they have to be published in.
+///
+/// A draft is published after the drafts of the entities it links to, which might be link entities
+/// themselves. Apart from that, and for drafts linking to each other in a cycle, the order of the
+/// drafts is kept.
+fn publish_order<'d>(
+    drafts: impl ExactSizeIterator<Item = (EntityId, Option<&'d LinkData>)>,
+) -> Vec<usize> {
+    let mut positions = HashMap::<(OwnedById, EntityUuid), Vec<usize>>::new();
+    let mut links = Vec::with_capacity(drafts.len());
+    for (position, (entity_id, link_data)) in drafts.enumerate() {
+        positions
+            .entry((entity_id.owned_by_id, entity_id.entity_uuid))
+            .or_default()
+            .push(position);
+        links.push(link_data);
+    }
+
+    let mut dependents = vec![Vec::new(); links.len()];
+    let mut dependencies = vec![0_usize; links.len()];
+    for (position, link_data) in links.iter().enumerate() {
+        let Some(link_data) = link_data else {
+            continue;
+        };
+        for endpoint in [link_data.left_entity_id, link_data.right_entity_id] {
+            for &dependency in positions
+                .get(&(endpoint.owned_by_id, endpoint.entity_uuid))
+                .into_iter()
+                .flatten()
+            {
+                if dependency != position {
+                    dependents[dependency].push(position);
+                    dependencies[position] += 1;
+                }
+            }
+        }
+    }
+
+    let mut ready = dependencies
+        .iter()
+        .enumerate()
+        .filter(|(_, dependencies)| **dependencies == 0)
+        .map(|(position, _)| position)
+        .collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(links.len());
+    while let Some(position) = ready.pop_first() {
+        order.push(position);
+        for &dependent in &dependents[position] {
+            dependencies[dependent] -= 1;
+            if dependencies[dependent] == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    // Drafts in a cycle can't be ordered, publishing them fails unless the linked entities are
+    // already published.
+    order.extend(
+        dependencies
+            .iter()
+            .enumerate()
+            .filter(|(_, dependencies)| **dependencies > 0)
+            .map(|(position, _)| position),
+    );
+    order
+}
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Ensures that a non-draft link entity only links to entities which are not only drafts.
+    ///
+    /// Entities published earlier in the current transaction are taken into account.
+    pub(super) async fn ensure_linked_entities_are_published(
+        &self,
+        link_data: &LinkData,
+    ) -> Result<(), QueryError> {
+        for entity_id in [link_data.left_entity_id, link_data.right_entity_id] {
+            let is_published: bool = self
+                .as_client()
+                .query_one(
+                    "
+                        SELECT EXISTS (
+                            SELECT 1 FROM entity_temporal_metadata
+                            WHERE web_id = $1
+                              AND entity_uuid = $2
+                              AND draft_id IS NULL
+                              AND upper_inf(transaction_time)
+                        );
+                    ",
+                    &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+                )
+                .await
+                .change_context(QueryError)?
+                .get(0);
+
+            if !is_published {
+                bail!(
+                    Report::new(LinkedEntityIsDraft)
+                        .attach(StatusCode::InvalidArgument)
+                        .attach_printable(entity_id)
+                        .change_context(QueryError)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Publishes drafts as part of the current transaction.
+    ///
+    /// Returns the published [`Entities`][Entity] in the order of the requested drafts.
+    pub(super) async fn publish_drafts_in_transaction(
+        &mut self,
+        actor_id: AccountId,
+        params: PublishDraftsParams,
+    ) -> Result<Vec<Entity>, UpdateError> {
+        let temporal_axes = QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        }
+        .resolve();
+
+        let mut drafts = Vec::with_capacity(params.entity_ids.len());
+        for (index, entity_id) in params.entity_ids.into_iter().enumerate() {
+            if entity_id.draft_id.is_none() {
+                bail!(
+                    Report::new(UpdateError)
+                        .attach(StatusCode::InvalidArgument)
+                        .attach_printable(entity_id)
+                        .attach_printable("only drafts can be published")
+                );
+            }
+
+            let draft = Read::<Entity>::read_one(
+                &*self,
+                &Filter::for_entity_by_entity_id(entity_id),
+                Some(&temporal_axes),
+                true,
+            )
+            .await
+            .change_context(EntityDoesNotExist)
+            .attach(StatusCode::NotFound)
+            .attach_printable(entity_id)
+            .change_context(UpdateError)?;
+            drafts.push((index, draft));
+        }
+
+        // Link entities are validated against the entities they link to, so these have to be
+        // published first.
+        let order = publish_order(
+            drafts
+                .iter()
+                .map(|(_, draft)| (draft.metadata.record_id.entity_id, draft.link_data.as_ref())),
+        );
+        let mut drafts = drafts.into_iter().map(Some).collect::<Vec<_>>();
+
+        let mut published_entities = Vec::with_capacity(drafts.len());
+        for (index, draft) in order
+            .into_iter()
+            .filter_map(|position| drafts[position].take())
+        {
+            let (entity, _) = self
+                .patch_entity_in_transaction(
+                    actor_id,
+                    PatchEntityParams {
+                        entity_id: draft.metadata.record_id.entity_id,
+                        expected_edition_ids: vec![draft.metadata.record_id.edition_id],
+                        properties: Vec::new(),
+                        entity_type_ids: Vec::new(),
+                        archived: None,
+                        draft: Some(false),
+                        decision_time: None,
+                        confidence: draft.metadata.confidence,
+                        provenance: params.provenance.clone(),
+                    },
+                    None,
+                )
+                .await
+                .attach(BatchIndex(index))
+                .attach_printable_lazy(|| format!("Could not publish draft at index {index}"))?;
+            published_entities.push((index, entity));
+        }
+
+        published_entities.sort_by_key(|(index, _)| *index);
+        Ok(published_entities
+            .into_iter()
+            .map(|(_, entity)| entity)
+            .collect())
+    }
+
+    /// Removes the editions of a draft.
+    ///
+    /// Returns `true` if the entity has no editions left, i.e. it only existed as this draft.
+    async fn delete_draft_records(
+        &self,
+        entity_id: EntityId,
+        draft_id: DraftId,
+    ) -> Result<bool, DeletionError> {
+        let edition_ids = self
+            .as_client()
+            .query(
+                "
+                    DELETE FROM entity_temporal_metadata
+                    WHERE web_id = $1
+                      AND entity_uuid = $2
+                      AND draft_id = $3
+                    RETURNING entity_edition_id;
+                ",
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid, &draft_id],
+            )
+            .await
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityEditionId>(0))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if edition_ids.is_empty() {
+            bail!(
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(entity_id)
+                    .change_context(DeletionError)
+            );
+        }
+
+        for statement in [
+            "DELETE FROM entity_property WHERE entity_edition_id = ANY($1::UUID[]);",
+            "DELETE FROM entity_is_of_type WHERE entity_edition_id = ANY($1::UUID[]);",
+            "DELETE FROM entity_editions WHERE entity_edition_id = ANY($1::UUID[]);",
+        ] {
+            self.as_client()
+                .query(statement, &[&edition_ids])
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        for statement in [
+            "
+                DELETE FROM entity_embeddings
+                WHERE web_id = $1 AND entity_uuid = $2 AND draft_id = $3;
+            ",
+            "
+                DELETE FROM entity_drafts
+                WHERE web_id = $1 AND entity_uuid = $2 AND draft_id = $3;
+            ",
+        ] {
+            self.as_client()
+                .query(
+                    statement,
+                    &[&entity_id.owned_by_id, &entity_id.entity_uuid, &draft_id],
+                )
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        Ok(self
+            .as_client()
+            .query_one(
+                "
+                    SELECT NOT EXISTS (
+                        SELECT 1 FROM entity_temporal_metadata
+                        WHERE web_id = $1 AND entity_uuid = $2
+                    );
+                ",
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(DeletionError)?
+            .get(0))
+    }
+
+    /// Discards drafts as part of the current transaction.
+    ///
+    /// Returns the IDs of the entities which only existed as draft and were removed entirely.
+    /// Removing their authorization relationships is up to the caller.
+    pub(super) async fn discard_drafts_in_transaction(
+        &self,
+        actor_id: AccountId,
+        params: DiscardDraftsParams,
+    ) -> Result<Vec<EntityId>, DeletionError> {
+        let mut removed_entities = HashSet::new();
+        for entity_id in params.entity_ids {
+            let Some(draft_id) = entity_id.draft_id else {
+                bail!(
+                    Report::new(DeletionError)
+                        .attach(StatusCode::InvalidArgument)
+                        .attach_printable(entity_id)
+                        .attach_printable("only drafts can be discarded")
+                );
+            };
+
+            self.authorization_api
+                .check_entity_permission(
+                    actor_id,
+                    EntityPermission::Update,
+                    entity_id,
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(DeletionError)?
+                .assert_permission()
+                .change_context(DeletionError)?;
+
+            if self.delete_draft_records(entity_id, draft_id).await? {
+                removed_entities.insert((entity_id.owned_by_id, entity_id.entity_uuid));
+            }
+        }
+
+        if removed_entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (web_ids, entity_uuids): (Vec<_>, Vec<_>) = removed_entities.iter().copied().unzip();
+        let link_entities = self
+            .as_client()
+            .query(
+                "
+                    SELECT web_id, entity_uuid
+                    FROM entity_has_left_entity
+                    WHERE (left_web_id, left_entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    )
+                    UNION
+                    SELECT web_id, entity_uuid
+                    FROM entity_has_right_entity
+                    WHERE (right_web_id, right_entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    );
+                ",
+                &[&web_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?;
+        for row in link_entities {
+            let link_entity = (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1));
+            if !removed_entities.contains(&link_entity) {
+                bail!(
+                    Report::new(EntityIsLinked)
+                        .attach(StatusCode::FailedPrecondition)
+                        .attach_printable(EntityId {
+                            owned_by_id: link_entity.0,
+                            entity_uuid: link_entity.1,
+                            draft_id: None,
+                        })
+                        .change_context(DeletionError)
+                );
+            }
+        }
+
+        let removed_entity_ids = removed_entities
+            .into_iter()
+            .map(|(owned_by_id, entity_uuid)| EntityId {
+                owned_by_id,
+                entity_uuid,
+                draft_id: None,
+            })
+            .collect::<Vec<_>>();
+        self.delete_entity_records(&removed_entity_ids).await?;
+
+        Ok(removed_entity_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_types::knowledge::PropertyProvenance;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn entity_id(owned_by_id: OwnedById) -> EntityId {
+        EntityId {
+            owned_by_id,
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: Some(DraftId::new(Uuid::new_v4())),
+        }
+    }
+
+    fn link(left_entity_id: EntityId, right_entity_id: EntityId) -> LinkData {
+        LinkData {
+            left_entity_id,
+            right_entity_id,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        }
+    }
+
+    #[test]
+    fn links_to_links() {
+        let owned_by_id = OwnedById::new(Uuid::new_v4());
+        let alice = entity_id(owned_by_id);
+        let bob = entity_id(owned_by_id);
+        let friendship = entity_id(owned_by_id);
+        let friendship_link = link(alice, bob);
+        let annotation = entity_id(owned_by_id);
+        let annotation_link = link(friendship, bob);
+
+        let order = publish_order(
+            [
+                (annotation, Some(&annotation_link)),
+                (friendship, Some(&friendship_link)),
+                (bob, None),
+                (alice, None),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(order, [2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn links_in_a_cycle() {
+        let owned_by_id = OwnedById::new(Uuid::new_v4());
+        let alice = entity_id(owned_by_id);
+        let first = entity_id(owned_by_id);
+        let second = entity_id(owned_by_id);
+        let first_link = link(alice, second);
+        let second_link = link(alice, first);
+
+        let order = publish_order(
+            [
+                (second, Some(&second_link)),
+                (first, Some(&first_link)),
+                (alice, None),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(order, [2, 0, 1]);
+    }
+}
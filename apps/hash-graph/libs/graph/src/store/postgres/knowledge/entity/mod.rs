@@ -1,10 +1,16 @@
+mod correction;
+mod draft;
+mod path;
 mod query;
 mod read;
+mod revert;
+mod uniqueness;
 
 use std::{
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{hash_map, HashMap, HashSet},
     iter::once,
+    mem, slice,
 };
 
 use authorization::{
@@ -16,8 +22,11 @@ use authorization::{
     zanzibar::{Consistency, Zookie},
     AuthorizationApi,
 };
-use error_stack::{bail, Report, Result, ResultExt};
-use futures::TryStreamExt;
+use error_stack::{bail, ensure, Report, Result, ResultExt};
+use futures::{
+    stream::{self, TryChunksError},
+    FutureExt, Stream, TryStreamExt,
+};
 use graph_types::{
     account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
     knowledge::{
@@ -26,7 +35,9 @@ use graph_types::{
             EntityMetadata, EntityProvenance, EntityRecordId, EntityTemporalMetadata, EntityUuid,
             InferredEntityProvenance,
         },
-        Confidence, PropertyMetadataMap, PropertyObject, PropertyPath,
+        link::LinkData,
+        Confidence, PropertyDiff, PropertyMetadataMap, PropertyObject, PropertyPatchOperation,
+        PropertyPath, PropertyPathElement,
     },
     ontology::EntityTypeId,
     owned_by_id::OwnedById,
@@ -36,46 +47,63 @@ use hash_status::StatusCode;
 use postgres_types::{Json, ToSql};
 use temporal_versioning::{
     ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
-    OpenTemporalBound, RightBoundedTemporalInterval, TemporalBound, TemporalTagged, Timestamp,
-    TransactionTime,
+    OpenTemporalBound, RightBoundedTemporalInterval, TemporalBound, TemporalTagged, TimeAxis,
+    Timestamp, TransactionTime,
 };
 use tokio_postgres::{error::SqlState, GenericClient, Row};
-use type_system::{url::VersionedUrl, ClosedEntityType};
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    ClosedEntityType,
+};
 use uuid::Uuid;
-use validation::{Validate, ValidateEntityComponents};
+use validation::{validate_link_removal, Validate, ValidateEntityComponents};
 
 use crate::{
     knowledge::EntityQueryPath,
     ontology::EntityTypeQueryPath,
     store::{
+        change::EntityChangeOperation,
         crud::{QueryResult, Read, ReadPaginated, Sorting},
-        error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
+        error::{
+            BatchIndex, DeletionError, EntityDoesNotExist, EntityEditionMismatch, EntityIsLinked,
+            RaceConditionOnUpdate, SubgraphBudgetExceeded,
+        },
         knowledge::{
-            CountEntitiesParams, CreateEntityParams, EntityQuerySorting, EntityValidationType,
-            GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams,
-            GetEntitySubgraphResponse, PatchEntityParams, UpdateEntityEmbeddingsParams,
-            ValidateEntityError, ValidateEntityParams,
+            AggregateEntitiesParams, CorrectEntityParams, CountEntitiesParams, CreateEntityParams,
+            DiffWebParams, DiffWebResult, DiscardDraftsParams, EntityAggregationGroup,
+            EntityHistoryEdition, EntityMigrationFailure, EntityQuerySorting,
+            EntityTraversalFilters, EntityValidationType, EraseEntitiesParams,
+            EraseEntitiesResponse, FindEntityPathsParams, FindEntityPathsResponse,
+            GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams,
+            GetEntityHistoryResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+            LinkErasureBehavior, MergeEntitiesParams, MigrateEntitiesParams,
+            MigrateEntitiesResponse, ModifiedEntity, PatchEntityParams, PropertyMergeStrategy,
+            PropertyMigration, PublishDraftsParams, RevertEntityParams, StreamEntitiesParams,
+            UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
         },
         postgres::{
             knowledge::entity::read::EntityEdgeTraversalData,
-            ontology::OntologyId,
+            ontology::{OntologyId, OntologyTypeTraversalQueues},
             query::{
                 rows::{
                     EntityDraftRow, EntityEditionRow, EntityHasLeftEntityRow,
                     EntityHasRightEntityRow, EntityIdRow, EntityIsOfTypeRow, EntityPropertyRow,
                     EntityTemporalMetadataRow,
                 },
-                InsertStatementBuilder, ReferenceTable, Table,
+                InsertStatementBuilder, ReferenceTable, SelectCompiler, Table,
             },
             TraversalContext,
         },
         query::{Filter, FilterExpression, Parameter, ParameterList},
         validation::StoreProvider,
-        AsClient, EntityStore, InsertionError, PostgresStore, QueryError, StoreCache,
-        SubgraphRecord, UpdateError,
+        AggregationFunction, AsClient, EntityStore, InsertionError, PostgresStore, QueryError,
+        StoreCache, SubgraphRecord, UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
+        edges::{
+            EdgeDirection, EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind,
+            SharedEdgeKind,
+        },
         identifier::{EntityIdWithInterval, EntityVertexId},
         temporal_axes::{
             PinnedTemporalAxis, PinnedTemporalAxisUnresolved, QueryTemporalAxes,
@@ -103,6 +131,7 @@ where
             RightBoundedTemporalInterval<VariableAxis>,
         )>,
         traversal_context: &mut TraversalContext,
+        traversal_filters: &EntityTraversalFilters<'_>,
         actor_id: AccountId,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
@@ -112,6 +141,10 @@ where
         let mut entity_type_queue = Vec::new();
 
         while !entity_queue.is_empty() {
+            if traversal_context.budget_exhausted(subgraph)? {
+                break;
+            }
+
             let mut shared_edges_to_traverse = Option::<EntityEdgeTraversalData>::None;
             let mut knowledge_edges_to_traverse =
                 HashMap::<(KnowledgeGraphEdgeKind, EdgeDirection), EntityEdgeTraversalData>::new();
@@ -208,6 +241,10 @@ where
             }
 
             for (edge_kind, edge_direction, table) in entity_edges {
+                if traversal_context.budget_exhausted(subgraph)? {
+                    break;
+                }
+
                 if let Some(traversal_data) =
                     knowledge_edges_to_traverse.get(&(edge_kind, edge_direction))
                 {
@@ -234,6 +271,22 @@ where
                         .change_context(QueryError)?
                         .0;
 
+                    let matching_editions =
+                        if let Some(filter) = traversal_filters.for_direction(edge_direction) {
+                            Some(
+                                self.filter_entities_by_ids(
+                                    knowledge_edges
+                                        .iter()
+                                        .map(|edge| edge.right_endpoint_edition_id),
+                                    filter,
+                                    subgraph,
+                                )
+                                .await?,
+                            )
+                        } else {
+                            None
+                        };
+
                     entity_queue.extend(
                         knowledge_edges
                             .into_iter()
@@ -247,6 +300,11 @@ where
                                     .unwrap_or(true)
                                     .then_some(edge)
                             })
+                            .filter(|edge| {
+                                matching_editions.as_ref().map_or(true, |editions| {
+                                    editions.contains(&edge.right_endpoint_edition_id)
+                                })
+                            })
                             .flat_map(|edge| {
                                 subgraph.insert_edge(
                                     &edge.left_endpoint,
@@ -273,8 +331,11 @@ where
             }
         }
 
-        self.traverse_entity_types(
-            entity_type_queue,
+        self.traverse_ontology_types(
+            OntologyTypeTraversalQueues {
+                entity_types: entity_type_queue,
+                ..OntologyTypeTraversalQueues::default()
+            },
             traversal_context,
             actor_id,
             zookie,
@@ -285,6 +346,92 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_subgraph_impl(
+        &self,
+        actor_id: AccountId,
+        params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
+        let unresolved_temporal_axes = params.temporal_axes.clone();
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
+
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetEntitiesResponse {
+                entities: root_entities,
+                cursor,
+                count,
+            },
+            zookie,
+        ) = self
+            .get_entities_impl(
+                actor_id,
+                GetEntitiesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: false,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            unresolved_temporal_axes,
+            temporal_axes,
+        );
+
+        subgraph.roots.extend(
+            root_entities
+                .iter()
+                .map(|entity| entity.vertex_id(time_axis).into()),
+        );
+        subgraph.vertices.entities = root_entities
+            .into_iter()
+            .map(|entity| (entity.vertex_id(time_axis), entity))
+            .collect();
+
+        let mut traversal_context = TraversalContext::with_budget(params.budget);
+
+        // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
+        //       vertices and have to `.collect()` the keys.
+        self.traverse_entities(
+            subgraph
+                .vertices
+                .entities
+                .keys()
+                .map(|id| {
+                    (
+                        *id,
+                        subgraph.depths,
+                        subgraph.temporal_axes.resolved.variable_interval(),
+                    )
+                })
+                .collect(),
+            &mut traversal_context,
+            &params.traversal_filters,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        Ok(GetEntitySubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+            incomplete: traversal_context.is_incomplete(),
+        })
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     pub async fn delete_entities(&mut self) -> Result<(), DeletionError> {
         self.as_client()
@@ -523,6 +670,8 @@ where
                 edition: EntityEditionProvenance {
                     created_by_id: EditionCreatedById::new(actor_id),
                     archived_by_id: None,
+                    merged_into: None,
+                    reverted_to: None,
                     provided: params.provenance,
                 },
             };
@@ -757,6 +906,25 @@ where
             .await
             .change_context(InsertionError)?;
 
+        for entity in &entities {
+            if entity.metadata.record_id.entity_id.draft_id.is_none() {
+                if let Some(link_data) = &entity.link_data {
+                    transaction
+                        .ensure_linked_entities_are_published(link_data)
+                        .await
+                        .change_context(InsertionError)?;
+                }
+                transaction
+                    .ensure_unique_properties(
+                        entity.metadata.record_id.entity_id,
+                        &entity.metadata.entity_type_ids,
+                        &entity.properties,
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+            }
+        }
+
         let validation_params = entities
             .iter()
             .map(|entity| ValidateEntityParams {
@@ -767,11 +935,7 @@ where
                 property_metadata: Cow::Borrowed(&entity.metadata.properties),
                 link_data: entity.link_data.as_ref().map(Cow::Borrowed),
                 components: if entity.metadata.record_id.entity_id.draft_id.is_some() {
-                    ValidateEntityComponents {
-                        num_items: false,
-                        required_properties: false,
-                        ..ValidateEntityComponents::full()
-                    }
+                    ValidateEntityComponents::draft()
                 } else {
                     ValidateEntityComponents::full()
                 },
@@ -784,6 +948,15 @@ where
             .change_context(InsertionError)
             .attach(StatusCode::InvalidArgument)?;
 
+        transaction
+            .enqueue_webhook_deliveries(
+                entities
+                    .iter()
+                    .map(|entity| (EntityChangeOperation::Create, entity)),
+            )
+            .await
+            .change_context(InsertionError)?;
+
         let commit_result = {
             let span = tracing::trace_span!("committing entity");
             let _enter = span.enter();
@@ -824,8 +997,6 @@ where
 
     // TODO: Relax constraints on entity validation for draft entities
     //   see https://linear.app/hash/issue/H-1449
-    // TODO: Restrict non-draft links to non-draft entities
-    //   see https://linear.app/hash/issue/H-1450
     #[tracing::instrument(level = "info", skip(self))]
     async fn validate_entities(
         &self,
@@ -883,7 +1054,7 @@ where
                         .read_closed_schemas(
                             &Filter::In(
                                 FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                                ParameterList::Uuid(&ontology_type_uuids),
+                                ParameterList::Uuid(Cow::Borrowed(&ontology_type_uuids)),
                             ),
                             Some(
                                 &QueryTemporalAxesUnresolved::DecisionTime {
@@ -973,11 +1144,114 @@ where
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
-    async fn get_entity_subgraph(
+    async fn stream_entities(
         &self,
         actor_id: AccountId,
+        params: StreamEntitiesParams<'_>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send, QueryError> {
+        // Permissions are checked for batches of entities to avoid a round-trip to the
+        // authorization backend for every single entity.
+        const PERMISSION_BATCH_SIZE: usize = 1000;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        // Without boxing, the compiler is not able to prove that the future is `Send` because of
+        // the higher-ranked bounds on the sorting.
+        let (rows, artifacts) =
+            ReadPaginated::<Entity, EntityQuerySorting<'static>>::read_paginated(
+                self,
+                &params.filter,
+                Some(&temporal_axes),
+                &params.sorting,
+                None,
+                params.include_drafts,
+            )
+            .boxed()
+            .await?;
+
+        Ok(rows
+            .map_ok(move |row: Row| row.decode_record(&artifacts))
+            .try_chunks(PERMISSION_BATCH_SIZE)
+            .map_err(|TryChunksError(_, error)| error)
+            .and_then(move |entities: Vec<Entity>| async move {
+                let entity_ids = entities
+                    .iter()
+                    .map(|entity| entity.metadata.record_id.entity_id)
+                    .collect::<HashSet<_>>();
+
+                let (permissions, _zookie) = self
+                    .authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        entity_ids,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                Ok(stream::iter(
+                    entities
+                        .into_iter()
+                        .filter(move |entity| {
+                            permissions
+                                .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                                .copied()
+                                .unwrap_or(false)
+                        })
+                        .map(Ok),
+                ))
+            })
+            .try_flatten())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_subgraph(
+        &mut self,
+        actor_id: AccountId,
         params: GetEntitySubgraphParams<'_>,
     ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
+        let Some(statement_timeout) = params.budget.statement_timeout_ms else {
+            return self.get_entity_subgraph_impl(actor_id, params).await;
+        };
+
+        // The timeout is local to the transaction, so it cannot leak to other queries on the
+        // connection, even if this future is dropped before the transaction finished.
+        let transaction = self.transaction().await.change_context(QueryError)?;
+        transaction
+            .as_client()
+            .query(
+                "SELECT set_config('statement_timeout', $1, true);",
+                &[&statement_timeout.to_string()],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let response = transaction
+            .get_entity_subgraph_impl(actor_id, params)
+            .await
+            .map_err(|report| {
+                if report
+                    .downcast_ref::<tokio_postgres::Error>()
+                    .and_then(tokio_postgres::Error::code)
+                    == Some(&SqlState::QUERY_CANCELED)
+                {
+                    report
+                        .attach(StatusCode::DeadlineExceeded)
+                        .attach_printable(SubgraphBudgetExceeded::StatementTimeout)
+                } else {
+                    report
+                }
+            })?;
+
+        transaction.commit().await.change_context(QueryError)?;
+        Ok(response)
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
         let unresolved_temporal_axes = params.temporal_axes.clone();
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
 
@@ -985,19 +1259,22 @@ where
 
         let (
             GetEntitiesResponse {
-                entities: root_entities,
-                cursor,
-                count,
+                entities: source_entities,
+                ..
             },
             zookie,
         ) = self
             .get_entities_impl(
                 actor_id,
                 GetEntitiesParams {
-                    filter: params.filter,
-                    temporal_axes: params.temporal_axes,
-                    sorting: params.sorting,
-                    limit: params.limit,
+                    filter: Filter::for_entity_by_entity_id(params.source_entity_id),
+                    temporal_axes: params.temporal_axes.clone(),
+                    sorting: EntityQuerySorting {
+                        paths: Vec::new(),
+                        relevance: None,
+                        cursor: None,
+                    },
+                    limit: None,
                     include_drafts: params.include_drafts,
                     include_count: false,
                 },
@@ -1005,55 +1282,43 @@ where
             )
             .await?;
 
+        if source_entities.is_empty() {
+            bail!(
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.source_entity_id)
+                    .change_context(QueryError)
+            );
+        }
+
+        let depths = EdgeResolveDepths {
+            incoming: params.max_depth,
+            outgoing: params.max_depth,
+        };
         let mut subgraph = Subgraph::new(
-            params.graph_resolve_depths,
+            GraphResolveDepths {
+                has_left_entity: depths,
+                has_right_entity: depths,
+                ..GraphResolveDepths::default()
+            },
             unresolved_temporal_axes,
             temporal_axes,
         );
 
         subgraph.roots.extend(
-            root_entities
+            source_entities
                 .iter()
                 .map(|entity| entity.vertex_id(time_axis).into()),
         );
-        subgraph.vertices.entities = root_entities
+        subgraph.vertices.entities = source_entities
             .into_iter()
             .map(|entity| (entity.vertex_id(time_axis), entity))
             .collect();
 
-        let mut traversal_context = TraversalContext::default();
-
-        // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
-        //       vertices and have to `.collect()` the keys.
-        self.traverse_entities(
-            subgraph
-                .vertices
-                .entities
-                .keys()
-                .map(|id| {
-                    (
-                        *id,
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
-            &mut traversal_context,
-            actor_id,
-            &zookie,
-            &mut subgraph,
-        )
-        .await?;
-
-        traversal_context
-            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+        self.traverse_entity_paths(&params, actor_id, &zookie, &mut subgraph)
             .await?;
 
-        Ok(GetEntitySubgraphResponse {
-            subgraph,
-            cursor,
-            count,
-        })
+        Ok(FindEntityPathsResponse { subgraph })
     }
 
     async fn count_entities(
@@ -1095,53 +1360,1687 @@ where
             .count())
     }
 
-    async fn get_entity_by_id(
+    async fn aggregate_entities(
         &self,
         actor_id: AccountId,
-        entity_id: EntityId,
-        transaction_time: Option<Timestamp<TransactionTime>>,
-        decision_time: Option<Timestamp<DecisionTime>>,
-    ) -> Result<Entity, QueryError> {
-        self.authorization_api
-            .check_entity_permission(
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<Vec<EntityAggregationGroup>, QueryError> {
+        if let Some(aggregation) = params.aggregations.iter().find(|aggregation| {
+            aggregation.path.is_none() && aggregation.function != AggregationFunction::Count
+        }) {
+            bail!(Report::new(QueryError).attach_printable(format!(
+                "aggregation `{:?}` requires a path",
+                aggregation.function
+            )));
+        }
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        // The aggregation is done by the database, so entities the actor is not permitted to view
+        // have to be excluded. The filter is only applied here: Its joins may return an edition
+        // multiple times, which would distort the aggregation, so the aggregation itself is only
+        // restricted to the matching editions.
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        let owned_by_id_index = compiler.add_selection_path(&EntityQueryPath::OwnedById);
+        let entity_uuid_index = compiler.add_selection_path(&EntityQueryPath::Uuid);
+        let draft_id_index = compiler.add_selection_path(&EntityQueryPath::DraftId);
+        let edition_id_index = compiler.add_selection_path(&EntityQueryPath::EditionId);
+        compiler.add_filter(&params.filter);
+
+        let (statement, parameters) = compiler.compile();
+        let editions = self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                (
+                    EntityId {
+                        owned_by_id: row.get(owned_by_id_index),
+                        entity_uuid: row.get(entity_uuid_index),
+                        draft_id: row.get(draft_id_index),
+                    },
+                    row.get::<_, Uuid>(edition_id_index),
+                )
+            })
+            .collect::<HashSet<_>>();
+
+        let entity_ids = editions
+            .iter()
+            .map(|(entity_id, _)| *entity_id)
+            .collect::<HashSet<_>>();
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
                 actor_id,
                 EntityPermission::View,
-                entity_id,
+                entity_ids,
                 Consistency::FullyConsistent,
             )
             .await
             .change_context(QueryError)?
-            .assert_permission()
-            .change_context(QueryError)?;
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
 
-        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
-            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
-            variable: VariableTemporalAxisUnresolved::new(
-                transaction_time.map(TemporalBound::Inclusive),
-                transaction_time.map(LimitedTemporalBound::Inclusive),
+        let edition_filter = Filter::<Entity>::In(
+            FilterExpression::Path(EntityQueryPath::EditionId),
+            ParameterList::Uuid(
+                editions
+                    .into_iter()
+                    .filter(|(entity_id, _)| permitted_ids.contains(&entity_id.entity_uuid))
+                    .map(|(_, edition_id)| edition_id)
+                    .collect(),
             ),
-        }
-        .resolve();
+        );
+
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        let key_indices = params
+            .group_by
+            .iter()
+            .map(|path| compiler.add_grouping(path))
+            .collect::<Vec<_>>();
+        let value_indices = params
+            .aggregations
+            .iter()
+            .map(|aggregation| {
+                compiler.add_aggregation(aggregation.function, aggregation.path.as_ref())
+            })
+            .collect::<Vec<_>>();
+        compiler.add_filter(&edition_filter);
+
+        let (statement, parameters) = compiler.compile();
+        let decode = |row: &Row, indices: &[usize]| {
+            indices
+                .iter()
+                .map(|&index| {
+                    row.get::<_, Option<serde_json::Value>>(index)
+                        .unwrap_or(serde_json::Value::Null)
+                })
+                .collect()
+        };
+
+        Ok(self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .iter()
+            .map(|row| EntityAggregationGroup {
+                keys: decode(row, &key_indices),
+                values: decode(row, &value_indices),
+            })
+            .collect())
+    }
+
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, QueryError> {
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
+            variable: VariableTemporalAxisUnresolved::new(
+                transaction_time.map(TemporalBound::Inclusive),
+                transaction_time.map(LimitedTemporalBound::Inclusive),
+            ),
+        }
+        .resolve();
 
         Read::<Entity>::read_one(
             self,
             &Filter::for_entity_by_entity_id(entity_id),
             Some(&temporal_axes),
-            entity_id.draft_id.is_some(),
+            entity_id.draft_id.is_some(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, QueryError> {
+        // The permission is checked first to not reveal if an entity exists which the actor is not
+        // allowed to view.
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let entity_exists: bool = self
+            .as_client()
+            .query_one(
+                "
+                    SELECT EXISTS (
+                        SELECT 1 FROM entity_temporal_metadata
+                        WHERE web_id = $1
+                          AND entity_uuid = $2
+                          AND draft_id IS NOT DISTINCT FROM $3
+                    );
+                ",
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &params.entity_id.draft_id,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .get(0);
+        ensure!(
+            entity_exists,
+            Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(QueryError)
+        );
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let variable_time_axis = temporal_axes.variable_time_axis();
+        let variable_start = |entity: &Entity| -> Timestamp<VariableAxis> {
+            let temporal_versioning = &entity.metadata.temporal_versioning;
+            match variable_time_axis {
+                TimeAxis::DecisionTime => {
+                    Timestamp::from(*temporal_versioning.decision_time.start()).cast()
+                }
+                TimeAxis::TransactionTime => {
+                    Timestamp::from(*temporal_versioning.transaction_time.start()).cast()
+                }
+            }
+        };
+
+        // Only the editions of the requested page are read. The diff of the first edition on the
+        // page requires the edition before it, so the last edition before the cursor is read as
+        // well. One more edition than requested is read to know if there is a next page.
+        let (pinned_column, variable_column) = match variable_time_axis {
+            TimeAxis::DecisionTime => ("transaction_time", "decision_time"),
+            TimeAxis::TransactionTime => ("decision_time", "transaction_time"),
+        };
+        let limit = params
+            .limit
+            .map(|limit| i64::try_from(limit.saturating_add(1)))
+            .transpose()
+            .change_context(QueryError)?;
+        let edition_ids = self
+            .as_client()
+            .query(
+                &format!(
+                    "
+                        (
+                            SELECT entity_edition_id
+                            FROM entity_temporal_metadata
+                            WHERE web_id = $1
+                              AND entity_uuid = $2
+                              AND draft_id IS NOT DISTINCT FROM $3
+                              AND {pinned_column} @> $4::TIMESTAMPTZ
+                              AND {variable_column} && $5
+                              AND ($6::TIMESTAMPTZ IS NULL OR lower({variable_column}) > $6)
+                            ORDER BY lower({variable_column})
+                            LIMIT $7
+                        ) UNION ALL (
+                            SELECT entity_edition_id
+                            FROM entity_temporal_metadata
+                            WHERE web_id = $1
+                              AND entity_uuid = $2
+                              AND draft_id IS NOT DISTINCT FROM $3
+                              AND {pinned_column} @> $4::TIMESTAMPTZ
+                              AND {variable_column} && $5
+                              AND lower({variable_column}) <= $6
+                            ORDER BY lower({variable_column}) DESC
+                            LIMIT 1
+                        );
+                    "
+                ),
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &params.entity_id.draft_id,
+                    &temporal_axes.pinned_timestamp(),
+                    &temporal_axes.variable_interval(),
+                    &params.after,
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| row.get::<_, Uuid>(0))
+            .collect::<Vec<_>>();
+
+        let mut entities = Read::<Entity>::read_vec(
+            self,
+            &Filter::All(vec![
+                Filter::for_entity_by_entity_id(params.entity_id),
+                Filter::In(
+                    FilterExpression::Path(EntityQueryPath::EditionId),
+                    ParameterList::Uuid(Cow::Owned(edition_ids)),
+                ),
+            ]),
+            Some(&temporal_axes),
+            params.entity_id.draft_id.is_some(),
+        )
+        .await?;
+        entities.sort_by_key(variable_start);
+
+        let empty_properties = PropertyObject::empty();
+        let mut editions = Vec::new();
+        let mut last_start = None;
+        let mut cursor = None;
+        for (index, entity) in entities.iter().enumerate() {
+            let start = variable_start(entity);
+            if params.after.is_some_and(|after| start <= after) {
+                continue;
+            }
+            if params.limit.is_some_and(|limit| editions.len() == limit) {
+                cursor = last_start;
+                break;
+            }
+
+            let previous_properties = index
+                .checked_sub(1)
+                .map_or(&empty_properties, |previous| &entities[previous].properties);
+            editions.push(EntityHistoryEdition {
+                edition_id: entity.metadata.record_id.edition_id,
+                temporal_versioning: entity.metadata.temporal_versioning.clone(),
+                provenance: entity.metadata.provenance.edition.clone(),
+                actor_id: entity.metadata.provenance.edition.created_by_id,
+                property_diff: previous_properties
+                    .diff(&entity.properties, &mut PropertyPath::default())
+                    .map(PropertyDiff::into_owned)
+                    .collect(),
+            });
+            last_start = Some(start);
+        }
+
+        Ok(GetEntityHistoryResponse { editions, cursor })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn diff_web(
+        &self,
+        actor_id: AccountId,
+        params: DiffWebParams,
+    ) -> Result<DiffWebResult<'static>, QueryError> {
+        let first_transaction_time = params.first_transaction_time.unwrap_or_else(Timestamp::now);
+        let first_decision_time = params.first_decision_time.unwrap_or_else(Timestamp::now);
+        let second_transaction_time = params
+            .second_transaction_time
+            .unwrap_or_else(Timestamp::now);
+        let second_decision_time = params.second_decision_time.unwrap_or_else(Timestamp::now);
+        let (entity_type_base_urls, entity_type_versions): (Option<Vec<_>>, Option<Vec<_>>) =
+            params.entity_type_ids.as_ref().map_or((None, None), |ids| {
+                let (base_urls, versions) = ids
+                    .iter()
+                    .map(|id| (id.base_url.as_str(), id.version))
+                    .unzip();
+                (Some(base_urls), Some(versions))
+            });
+        let limit = params
+            .limit
+            .map(i64::try_from)
+            .transpose()
+            .change_context(QueryError)?;
+
+        // The entity visible at a point in time can only differ between the two points in time if
+        // one of its intervals starts or ends between them on either temporal axis.
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    WITH changed_entities AS (
+                        SELECT DISTINCT entity_uuid
+                        FROM entity_temporal_metadata
+                        WHERE web_id = $1
+                          AND draft_id IS NULL
+                          AND ($6::UUID IS NULL OR entity_uuid > $6)
+                          AND (
+                              tstzrange(
+                                  least($2::TIMESTAMPTZ, $4::TIMESTAMPTZ), greatest($2, $4), '(]'
+                              ) @> ANY(ARRAY[lower(transaction_time), upper(transaction_time)])
+                              OR tstzrange(
+                                  least($3::TIMESTAMPTZ, $5::TIMESTAMPTZ), greatest($3, $5), '(]'
+                              ) @> ANY(ARRAY[lower(decision_time), upper(decision_time)])
+                          )
+                    )
+                    SELECT
+                        changed_entities.entity_uuid,
+                        first.entity_edition_id,
+                        first.archived,
+                        first.properties,
+                        second.entity_edition_id,
+                        second.archived,
+                        second.properties
+                    FROM changed_entities
+                    LEFT JOIN LATERAL (
+                        SELECT
+                            entity_editions.entity_edition_id,
+                            entity_editions.archived,
+                            entity_editions.properties
+                        FROM entity_temporal_metadata
+                        JOIN entity_editions
+                          ON entity_editions.entity_edition_id
+                           = entity_temporal_metadata.entity_edition_id
+                        WHERE entity_temporal_metadata.web_id = $1
+                          AND entity_temporal_metadata.entity_uuid = changed_entities.entity_uuid
+                          AND entity_temporal_metadata.draft_id IS NULL
+                          AND entity_temporal_metadata.transaction_time @> $2::TIMESTAMPTZ
+                          AND entity_temporal_metadata.decision_time @> $3::TIMESTAMPTZ
+                    ) AS first ON TRUE
+                    LEFT JOIN LATERAL (
+                        SELECT
+                            entity_editions.entity_edition_id,
+                            entity_editions.archived,
+                            entity_editions.properties
+                        FROM entity_temporal_metadata
+                        JOIN entity_editions
+                          ON entity_editions.entity_edition_id
+                           = entity_temporal_metadata.entity_edition_id
+                        WHERE entity_temporal_metadata.web_id = $1
+                          AND entity_temporal_metadata.entity_uuid = changed_entities.entity_uuid
+                          AND entity_temporal_metadata.draft_id IS NULL
+                          AND entity_temporal_metadata.transaction_time @> $4::TIMESTAMPTZ
+                          AND entity_temporal_metadata.decision_time @> $5::TIMESTAMPTZ
+                    ) AS second ON TRUE
+                    WHERE first.entity_edition_id IS DISTINCT FROM second.entity_edition_id
+                      AND NOT (coalesce(first.archived, TRUE) AND coalesce(second.archived, TRUE))
+                      AND (
+                          $7::TEXT[] IS NULL
+                          OR EXISTS (
+                              SELECT 1
+                              FROM entity_is_of_type
+                              JOIN ontology_ids
+                                ON ontology_ids.ontology_id
+                                 = entity_is_of_type.entity_type_ontology_id
+                              JOIN UNNEST($7, $8::INT8[]) AS filter(base_url, version)
+                                ON filter.base_url = ontology_ids.base_url
+                               AND filter.version = ontology_ids.version
+                              WHERE entity_is_of_type.entity_edition_id
+                                IN (first.entity_edition_id, second.entity_edition_id)
+                          )
+                      )
+                    ORDER BY changed_entities.entity_uuid
+                    LIMIT $9;
+                ",
+                &[
+                    &params.owned_by_id,
+                    &first_transaction_time,
+                    &first_decision_time,
+                    &second_transaction_time,
+                    &second_decision_time,
+                    &params.after,
+                    &entity_type_base_urls,
+                    &entity_type_versions,
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let cursor = params
+            .limit
+            .is_some_and(|limit| rows.len() == limit)
+            .then(|| rows.last().map(|row| row.get::<_, EntityUuid>(0)))
+            .flatten();
+
+        let entity_ids = rows
+            .iter()
+            .map(|row| EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: row.get(0),
+                draft_id: None,
+            })
+            .collect::<Vec<_>>();
+        let (permissions, _zookie) = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let mut result = DiffWebResult {
+            created: Vec::new(),
+            archived: Vec::new(),
+            modified: Vec::new(),
+            cursor,
+        };
+        for row in rows {
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: row.get(0),
+                draft_id: None,
+            };
+            if !permissions
+                .get(&entity_id.entity_uuid)
+                .copied()
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            let first_edition_id = row
+                .get::<_, Option<bool>>(2)
+                .is_some_and(|archived| !archived)
+                .then(|| row.get::<_, EntityEditionId>(1));
+            let second_edition_id = row
+                .get::<_, Option<bool>>(5)
+                .is_some_and(|archived| !archived)
+                .then(|| row.get::<_, EntityEditionId>(4));
+            match (first_edition_id, second_edition_id) {
+                (None, Some(_)) => result.created.push(entity_id),
+                (Some(_), None) => result.archived.push(entity_id),
+                (Some(first_edition_id), Some(second_edition_id)) => {
+                    let first_properties = row.get::<_, PropertyObject>(3);
+                    let second_properties = row.get::<_, PropertyObject>(6);
+                    result.modified.push(ModifiedEntity {
+                        entity_id,
+                        first_edition_id,
+                        second_edition_id,
+                        properties: first_properties
+                            .diff(&second_properties, &mut PropertyPath::default())
+                            .map(PropertyDiff::into_owned)
+                            .collect(),
+                    });
+                }
+                (None, None) => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<PatchEntityParams>,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let mut updated_entities = Vec::new();
+        let mut operations = Vec::new();
+        let mut entity_metadata = Vec::with_capacity(params.len());
+        for (index, params) in params.into_iter().enumerate() {
+            let (entity, operation) = transaction
+                .patch_entity_in_transaction(actor_id, params, None)
+                .await
+                .attach(BatchIndex(index))
+                .attach_printable_lazy(|| format!("Could not patch entity at index {index}"))?;
+            entity_metadata.push(entity.metadata.clone());
+            if let Some(operation) = operation {
+                updated_entities.push(entity);
+                operations.push(operation);
+            }
+        }
+
+        transaction
+            .enqueue_webhook_deliveries(operations.into_iter().zip(&updated_entities))
+            .await
+            .change_context(UpdateError)?;
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if !updated_entities.is_empty() {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, &updated_entities)
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        Ok(entity_metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn revert_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: RevertEntityParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+        let (entity, reverted) = transaction
+            .revert_entity_in_transaction(actor_id, params)
+            .await?;
+        if reverted {
+            transaction
+                .enqueue_webhook_deliveries(once((EntityChangeOperation::Update, &entity)))
+                .await
+                .change_context(UpdateError)?;
+        }
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if reverted {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(
+                        actor_id,
+                        std::slice::from_ref(&entity),
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        Ok(entity.metadata)
+    }
+
+    async fn correct_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+        let entities = transaction
+            .correct_entity_in_transaction(actor_id, params)
+            .await?;
+        transaction
+            .enqueue_webhook_deliveries(
+                entities
+                    .iter()
+                    .map(|entity| (EntityChangeOperation::Update, entity)),
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(entities.into_iter().map(|entity| entity.metadata).collect())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn publish_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: PublishDraftsParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+        let entities = transaction
+            .publish_drafts_in_transaction(actor_id, params)
+            .await?;
+        transaction
+            .enqueue_webhook_deliveries(
+                entities
+                    .iter()
+                    .map(|entity| (EntityChangeOperation::Update, entity)),
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if !entities.is_empty() {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, &entities)
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        Ok(entities.into_iter().map(|entity| entity.metadata).collect())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn discard_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: DiscardDraftsParams,
+    ) -> Result<(), DeletionError> {
+        let transaction = self.transaction().await.change_context(DeletionError)?;
+        let removed_entity_ids = transaction
+            .discard_drafts_in_transaction(actor_id, params)
+            .await?;
+
+        let mut relationships = Vec::new();
+        for entity_id in &removed_entity_ids {
+            relationships.extend(
+                transaction
+                    .authorization_api
+                    .get_entity_relations(*entity_id, Consistency::FullyConsistent)
+                    .await
+                    .change_context(DeletionError)?
+                    .into_iter()
+                    .map(|relation_and_subject| (*entity_id, relation_and_subject)),
+            );
+        }
+
+        transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Delete,
+                        entity_id,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(DeletionError)?;
+
+        if let Err(mut error) = transaction.commit().await.change_context(DeletionError) {
+            if let Err(auth_error) = self
+                .authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(DeletionError)
+            {
+                // TODO: Use `add_child`
+                //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                error.extend_one(auth_error);
+            }
+
+            Err(error)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), UpdateError> {
+        #[derive(Debug, ToSql)]
+        #[postgres(name = "entity_embeddings")]
+        pub struct EntityEmbeddingsRow<'a> {
+            web_id: OwnedById,
+            entity_uuid: EntityUuid,
+            draft_id: Option<DraftId>,
+            property: Option<String>,
+            embedding: Embedding<'a>,
+            updated_at_transaction_time: Timestamp<TransactionTime>,
+            updated_at_decision_time: Timestamp<DecisionTime>,
+        }
+        let entity_embeddings = params
+            .embeddings
+            .into_iter()
+            .map(|embedding: EntityEmbedding<'_>| EntityEmbeddingsRow {
+                web_id: params.entity_id.owned_by_id,
+                entity_uuid: params.entity_id.entity_uuid,
+                draft_id: params.entity_id.draft_id,
+                property: embedding.property.as_ref().map(ToString::to_string),
+                embedding: embedding.embedding,
+                updated_at_transaction_time: params.updated_at_transaction_time,
+                updated_at_decision_time: params.updated_at_decision_time,
+            })
+            .collect::<Vec<_>>();
+
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        // let permissions = authorization_api
+        //     .check_entities_permission(
+        //         actor_id,
+        //         EntityPermission::UpdateEmbeddings,
+        //         entity_ids.iter().copied(),
+        //         Consistency::FullyConsistent,
+        //     )
+        //     .await
+        //     .change_context(UpdateError)?
+        //     .0
+        //     .into_iter()
+        //     .filter_map(|(entity_id, has_permission)| (!has_permission).then_some(entity_id))
+        //     .collect::<Vec<_>>();
+        // if !permissions.is_empty() {
+        //     let mut status = Report::new(PermissionAssertion);
+        //     for entity_id in permissions {
+        //         status = status.attach(format!("Permission denied for entity {entity_id}"));
+        //     }
+        //     return Err(status.change_context(UpdateError));
+        // }
+
+        if params.reset {
+            if let Some(draft_id) = params.entity_id.draft_id {
+                self.as_client()
+                    .query(
+                        "
+                        DELETE FROM entity_embeddings
+                        WHERE web_id = $1
+                          AND entity_uuid = $2
+                          AND draft_id = $3
+                          AND updated_at_transaction_time <= $4
+                          AND updated_at_decision_time <= $5;
+                    ",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &draft_id,
+                            &params.updated_at_transaction_time,
+                            &params.updated_at_decision_time,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            } else {
+                self.as_client()
+                    .query(
+                        "
+                        DELETE FROM entity_embeddings
+                        WHERE web_id = $1
+                          AND entity_uuid = $2
+                          AND draft_id IS NULL
+                          AND updated_at_transaction_time <= $3
+                          AND updated_at_decision_time <= $4;
+                    ",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &params.updated_at_transaction_time,
+                            &params.updated_at_decision_time,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_embeddings
+                    SELECT * FROM UNNEST($1::entity_embeddings[])
+                    ON CONFLICT (web_id, entity_uuid, property) DO UPDATE
+                    SET
+                        embedding = EXCLUDED.embedding,
+                        updated_at_transaction_time = EXCLUDED.updated_at_transaction_time,
+                        updated_at_decision_time = EXCLUDED.updated_at_decision_time
+                    WHERE entity_embeddings.updated_at_transaction_time <= \
+                 EXCLUDED.updated_at_transaction_time
+                    AND entity_embeddings.updated_at_decision_time <= \
+                 EXCLUDED.updated_at_decision_time;
+                ",
+                &[&entity_embeddings],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn erase_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: EraseEntitiesParams,
+    ) -> Result<EraseEntitiesResponse, DeletionError> {
+        let transaction = self.transaction().await.change_context(DeletionError)?;
+
+        let mut erased_entities = params
+            .entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .collect::<HashSet<_>>();
+
+        let (web_ids, entity_uuids): (Vec<_>, Vec<_>) = erased_entities.iter().copied().unzip();
+        let num_existing_entities = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT 1 FROM entity_ids
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    );
+                ",
+                &[&web_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?
+            .len();
+        if num_existing_entities != erased_entities.len() {
+            bail!(
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .change_context(DeletionError)
+            );
+        }
+
+        // Link entities may point at other link entities, so the lookup is repeated for every
+        // newly added link entity until no more link entities are found.
+        let mut entity_queue = erased_entities.iter().copied().collect::<Vec<_>>();
+        while !entity_queue.is_empty() {
+            let (web_ids, entity_uuids): (Vec<_>, Vec<_>) =
+                mem::take(&mut entity_queue).into_iter().unzip();
+            let link_entities = transaction
+                .as_client()
+                .query(
+                    "
+                        SELECT web_id, entity_uuid
+                        FROM entity_has_left_entity
+                        WHERE (left_web_id, left_entity_uuid) IN (
+                            SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                        )
+                        UNION
+                        SELECT web_id, entity_uuid
+                        FROM entity_has_right_entity
+                        WHERE (right_web_id, right_entity_uuid) IN (
+                            SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                        );
+                    ",
+                    &[&web_ids, &entity_uuids],
+                )
+                .await
+                .change_context(DeletionError)?;
+
+            for row in link_entities {
+                let link_entity = (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1));
+                if erased_entities.contains(&link_entity) {
+                    continue;
+                }
+
+                match params.link_behavior {
+                    LinkErasureBehavior::Cascade => {
+                        erased_entities.insert(link_entity);
+                        entity_queue.push(link_entity);
+                    }
+                    LinkErasureBehavior::Restrict => {
+                        bail!(
+                            Report::new(EntityIsLinked)
+                                .attach(StatusCode::FailedPrecondition)
+                                .attach_printable(EntityId {
+                                    owned_by_id: link_entity.0,
+                                    entity_uuid: link_entity.1,
+                                    draft_id: None,
+                                })
+                                .change_context(DeletionError)
+                        );
+                    }
+                }
+            }
+        }
+
+        let erased_entity_ids = erased_entities
+            .into_iter()
+            .map(|(owned_by_id, entity_uuid)| EntityId {
+                owned_by_id,
+                entity_uuid,
+                draft_id: None,
+            })
+            .collect::<Vec<_>>();
+
+        let (permissions, _zookie) = transaction
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::FullAccess,
+                erased_entity_ids.iter().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(DeletionError)?;
+        let forbidden_entities = permissions
+            .into_iter()
+            .filter_map(|(entity_uuid, permission)| (!permission).then_some(entity_uuid))
+            .collect::<Vec<_>>();
+        if !forbidden_entities.is_empty() {
+            return Err(Report::new(DeletionError)
+                .attach(StatusCode::PermissionDenied)
+                .attach_printable(
+                    "The actor does not have permission to erase one or more entities",
+                )
+                .attach_printable(
+                    forbidden_entities
+                        .into_iter()
+                        .map(|entity_uuid| entity_uuid.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
+        }
+
+        transaction
+            .delete_entity_records(&erased_entity_ids)
+            .await?;
+
+        let mut relationships = Vec::new();
+        for entity_id in &erased_entity_ids {
+            relationships.extend(
+                transaction
+                    .authorization_api
+                    .get_entity_relations(*entity_id, Consistency::FullyConsistent)
+                    .await
+                    .change_context(DeletionError)?
+                    .into_iter()
+                    .map(|relation_and_subject| (*entity_id, relation_and_subject)),
+            );
+        }
+
+        transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Delete,
+                        entity_id,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(DeletionError)?;
+
+        if let Err(mut error) = transaction.commit().await.change_context(DeletionError) {
+            if let Err(auth_error) = self
+                .authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(DeletionError)
+            {
+                // TODO: Use `add_child`
+                //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                error.extend_one(auth_error);
+            }
+
+            Err(error)
+        } else {
+            Ok(EraseEntitiesResponse { erased_entity_ids })
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let target_id = (
+            params.target_entity_id.owned_by_id,
+            params.target_entity_id.entity_uuid,
+        );
+        let mut seen_ids = HashSet::from([target_id]);
+        for duplicate_entity_id in &params.duplicate_entity_ids {
+            if !seen_ids.insert((
+                duplicate_entity_id.owned_by_id,
+                duplicate_entity_id.entity_uuid,
+            )) {
+                bail!(
+                    Report::new(UpdateError)
+                        .attach(StatusCode::InvalidArgument)
+                        .attach_printable(
+                            "The target entity and the duplicates must be distinct entities",
+                        )
+                        .attach_printable(*duplicate_entity_id)
+                );
+            }
+        }
+        if params.duplicate_entity_ids.is_empty() {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable("At least one duplicate has to be specified")
+            );
+        }
+
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let mut entities = Vec::with_capacity(params.duplicate_entity_ids.len() + 1);
+        for entity_id in once(params.target_entity_id).chain(params.duplicate_entity_ids) {
+            entities.push(
+                transaction
+                    .get_entity_by_id(actor_id, entity_id, None, None)
+                    .await
+                    .change_context(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(entity_id)
+                    .change_context(UpdateError)?,
+            );
+        }
+        let (target, duplicates) = entities
+            .split_first()
+            .expect("the target entity should be present");
+
+        let (duplicate_web_ids, duplicate_entity_uuids): (Vec<_>, Vec<_>) = duplicates
+            .iter()
+            .map(|duplicate| {
+                (
+                    duplicate.metadata.record_id.entity_id.owned_by_id,
+                    duplicate.metadata.record_id.entity_id.entity_uuid,
+                )
+            })
+            .unzip();
+        let link_entity_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT DISTINCT
+                        links.web_id,
+                        links.entity_uuid,
+                        entity_temporal_metadata.draft_id
+                    FROM (
+                        SELECT web_id, entity_uuid, left_web_id, left_entity_uuid
+                        FROM entity_has_left_entity
+                        UNION ALL
+                        SELECT web_id, entity_uuid, right_web_id, right_entity_uuid
+                        FROM entity_has_right_entity
+                    ) AS links (web_id, entity_uuid, linked_web_id, linked_entity_uuid)
+                    JOIN entity_temporal_metadata
+                      ON entity_temporal_metadata.web_id = links.web_id
+                     AND entity_temporal_metadata.entity_uuid = links.entity_uuid
+                     AND entity_temporal_metadata.transaction_time @> now()
+                     AND entity_temporal_metadata.decision_time @> now()
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                       = entity_temporal_metadata.entity_edition_id
+                    WHERE NOT entity_editions.archived
+                      AND (links.linked_web_id, links.linked_entity_uuid) IN (
+                          SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                      );
+                ",
+                &[&duplicate_web_ids, &duplicate_entity_uuids],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: row.get(2),
+            })
+            .collect::<Vec<_>>();
+
+        // Every permission is checked before anything is written.
+        let updated_entity_ids = entities
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .chain(link_entity_ids.iter().copied())
+            .collect::<Vec<_>>();
+        let (permissions, _zookie) = transaction
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::Update,
+                updated_entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?;
+        if permissions.values().any(|permission| !permission) {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::PermissionDenied)
+                    .attach_printable(
+                        "The actor does not have permission to update one of the merged entities \
+                         or a link pointing at a duplicate",
+                    )
+            );
+        }
+
+        let mut links = Vec::with_capacity(link_entity_ids.len());
+        for link_entity_id in link_entity_ids {
+            let link = transaction
+                .get_entity_by_id(actor_id, link_entity_id, None, None)
+                .await
+                .change_context(UpdateError)?;
+            let relationships = transaction
+                .authorization_api
+                .get_entity_relations(link_entity_id, Consistency::FullyConsistent)
+                .await
+                .change_context(UpdateError)?
+                .into_iter()
+                .filter(|relationship| {
+                    // The owner of the replacing link is set when creating it.
+                    !matches!(relationship, EntityRelationAndSubject::Owner { .. })
+                })
+                .collect::<Vec<_>>();
+            // A link between the merged entities would link the target entity to itself, so it's
+            // archived without being replaced.
+            let replacing_link_id = link
+                .link_data
+                .as_ref()
+                .is_some_and(|link_data| {
+                    ![link_data.left_entity_id, link_data.right_entity_id]
+                        .iter()
+                        .all(|entity_id| {
+                            seen_ids.contains(&(entity_id.owned_by_id, entity_id.entity_uuid))
+                        })
+                })
+                .then(|| EntityId {
+                    owned_by_id: link_entity_id.owned_by_id,
+                    entity_uuid: EntityUuid::new(Uuid::new_v4()),
+                    draft_id: None,
+                });
+            links.push((link, relationships, replacing_link_id));
+        }
+
+        let link_web_ids = links
+            .iter()
+            .filter_map(|(_, _, replacing_link_id)| {
+                replacing_link_id.map(|entity_id| entity_id.owned_by_id)
+            })
+            .collect::<HashSet<_>>();
+        let (create_entity_permissions, _zookie) = transaction
+            .authorization_api
+            .check_webs_permission(
+                actor_id,
+                WebPermission::CreateEntity,
+                link_web_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?;
+        if create_entity_permissions
+            .values()
+            .any(|permission| !permission)
+        {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::PermissionDenied)
+                    .attach_printable(
+                        "The actor does not have permission to create the links replacing the \
+                         links pointing at a duplicate",
+                    )
+            );
+        }
+
+        // The endpoints of a link are not versioned, so instead of redirecting a link pointing at
+        // a duplicate, it is archived and replaced by a new link pointing at the target entity.
+        // A draft of a link is replaced by a draft of a new link. The links are archived first to
+        // not count them against the link cardinality of the replacing links.
+        let mut archived_entities = Vec::with_capacity(duplicates.len() + links.len());
+        for (link, _, replacing_link_id) in &links {
+            let (archived_link, archive_operation) = transaction
+                .patch_entity_in_transaction(
+                    actor_id,
+                    PatchEntityParams {
+                        entity_id: link.metadata.record_id.entity_id,
+                        expected_edition_ids: vec![link.metadata.record_id.edition_id],
+                        decision_time: None,
+                        entity_type_ids: Vec::new(),
+                        properties: Vec::new(),
+                        draft: None,
+                        archived: Some(true),
+                        confidence: link.metadata.confidence,
+                        provenance: params.provenance.clone(),
+                    },
+                    *replacing_link_id,
+                )
+                .await?;
+            if let Some(archive_operation) = archive_operation {
+                archived_entities.push((archive_operation, archived_link));
+            }
+        }
+
+        for duplicate in duplicates {
+            let (archived_entity, archive_operation) = transaction
+                .patch_entity_in_transaction(
+                    actor_id,
+                    PatchEntityParams {
+                        entity_id: duplicate.metadata.record_id.entity_id,
+                        expected_edition_ids: vec![duplicate.metadata.record_id.edition_id],
+                        decision_time: None,
+                        entity_type_ids: Vec::new(),
+                        properties: Vec::new(),
+                        draft: None,
+                        archived: Some(true),
+                        confidence: duplicate.metadata.confidence,
+                        provenance: params.provenance.clone(),
+                    },
+                    Some(params.target_entity_id),
+                )
+                .await?;
+            if let Some(archive_operation) = archive_operation {
+                archived_entities.push((archive_operation, archived_entity));
+            }
+        }
+
+        // The duplicates are archived before the target is updated, so values constrained to be
+        // unique can be moved from a duplicate to the target.
+        let (merged_entity, merge_operation) = transaction
+            .patch_entity_in_transaction(
+                actor_id,
+                PatchEntityParams {
+                    entity_id: target.metadata.record_id.entity_id,
+                    expected_edition_ids: vec![target.metadata.record_id.edition_id],
+                    decision_time: None,
+                    entity_type_ids: Vec::new(),
+                    properties: merge_properties(target, duplicates, params.conflict_strategy),
+                    draft: None,
+                    archived: None,
+                    confidence: target.metadata.confidence,
+                    provenance: params.provenance.clone(),
+                },
+                None,
+            )
+            .await?;
+
+        let replaced_links = links
+            .iter()
+            .filter_map(|(link, relationships, replacing_link_id)| {
+                Some((link, relationships, (*replacing_link_id)?))
+            })
+            .collect::<Vec<_>>();
+        let redirect = |entity_id: EntityId| {
+            if seen_ids.contains(&(entity_id.owned_by_id, entity_id.entity_uuid)) {
+                params.target_entity_id
+            } else {
+                entity_id
+            }
+        };
+        let replacing_links = transaction
+            .create_entities(
+                actor_id,
+                replaced_links
+                    .iter()
+                    .map(
+                        |(link, relationships, replacing_link_id)| CreateEntityParams {
+                            owned_by_id: replacing_link_id.owned_by_id,
+                            entity_uuid: Some(replacing_link_id.entity_uuid),
+                            decision_time: None,
+                            entity_type_ids: link.metadata.entity_type_ids.clone(),
+                            properties: link.properties.clone(),
+                            confidence: link.metadata.confidence,
+                            property_metadata: link.metadata.properties.clone(),
+                            link_data: link.link_data.clone().map(|link_data| LinkData {
+                                left_entity_id: redirect(link_data.left_entity_id),
+                                right_entity_id: redirect(link_data.right_entity_id),
+                                ..link_data
+                            }),
+                            draft: link.metadata.record_id.entity_id.draft_id.is_some(),
+                            relationships: (*relationships).clone(),
+                            provenance: params.provenance.clone(),
+                        },
+                    )
+                    .collect(),
+            )
+            .await
+            .change_context(UpdateError)?;
+        let commit_result = async {
+            transaction
+                .enqueue_webhook_deliveries(
+                    merge_operation
+                        .map(|operation| (operation, &merged_entity))
+                        .into_iter()
+                        .chain(
+                            archived_entities
+                                .iter()
+                                .map(|(operation, entity)| (*operation, entity)),
+                        ),
+                )
+                .await
+                .change_context(UpdateError)?;
+            transaction.commit().await.change_context(UpdateError)
+        }
+        .await;
+        if let Err(mut error) = commit_result {
+            // The relationships of the replacing links were already written when creating them.
+            let mut relationships = Vec::new();
+            for (replacing_link, (_, link_relationships, _)) in
+                replacing_links.iter().zip(&replaced_links)
+            {
+                let entity_id = replacing_link.record_id.entity_id;
+                relationships.extend(
+                    link_relationships
+                        .iter()
+                        .copied()
+                        .chain(once(EntityRelationAndSubject::Owner {
+                            subject: EntityOwnerSubject::Web {
+                                id: entity_id.owned_by_id,
+                            },
+                            level: 0,
+                        }))
+                        .map(|relation_and_subject| {
+                            (
+                                ModifyRelationshipOperation::Delete,
+                                entity_id,
+                                relation_and_subject,
+                            )
+                        }),
+                );
+            }
+            if let Err(auth_error) = self
+                .authorization_api
+                .modify_entity_relations(relationships)
+                .await
+                .change_context(UpdateError)
+            {
+                // TODO: Use `add_child`
+                //   see https://linear.app/hash/issue/GEN-105/add-ability-to-add-child-errors
+                error.extend_one(auth_error);
+            }
+
+            return Err(error);
+        }
+
+        if merge_operation.is_some() {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(
+                        actor_id,
+                        std::slice::from_ref(&merged_entity),
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        Ok(merged_entity.metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn migrate_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntitiesParams,
+    ) -> Result<MigrateEntitiesResponse, UpdateError> {
+        if params.source_entity_type_id.base_url != params.target_entity_type_id.base_url
+            || params.source_entity_type_id.version == params.target_entity_type_id.version
+        {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(
+                        "The target type has to be a different version of the source type",
+                    )
+            );
+        }
+
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let temporal_axes = QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        }
+        .resolve();
+        let mut entities = Read::<Entity>::read_vec(
+            &transaction,
+            &Filter::for_entity_by_type_id(&params.source_entity_type_id),
+            Some(&temporal_axes),
+            params.include_drafts,
         )
         .await
+        .change_context(UpdateError)?;
+
+        let entity_ids = entities
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>();
+        let (permissions, _) = transaction
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?;
+        entities.retain(|entity| {
+            permissions
+                .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                .copied()
+                .unwrap_or(false)
+        });
+
+        let mut response = MigrateEntitiesResponse {
+            migrated_entity_ids: Vec::new(),
+            failures: Vec::new(),
+        };
+        let mut migrated_entities = Vec::new();
+        for entity in entities {
+            let entity_id = entity.metadata.record_id.entity_id;
+            let entity_type_ids = entity
+                .metadata
+                .entity_type_ids
+                .iter()
+                .map(|entity_type_id| {
+                    if *entity_type_id == params.source_entity_type_id {
+                        params.target_entity_type_id.clone()
+                    } else {
+                        entity_type_id.clone()
+                    }
+                })
+                .collect();
+
+            // Every entity is migrated in its own savepoint, so a failing entity does not affect
+            // the other entities.
+            let result = async {
+                let properties = migrate_properties(&entity, &params.property_migrations)?;
+                let mut savepoint = transaction
+                    .transaction()
+                    .await
+                    .change_context(UpdateError)?;
+                let (migrated_entity, operation) = savepoint
+                    .patch_entity_in_transaction(
+                        actor_id,
+                        PatchEntityParams {
+                            entity_id,
+                            expected_edition_ids: vec![entity.metadata.record_id.edition_id],
+                            decision_time: None,
+                            entity_type_ids,
+                            properties,
+                            draft: None,
+                            archived: None,
+                            confidence: entity.metadata.confidence,
+                            provenance: params.provenance.clone(),
+                        },
+                        None,
+                    )
+                    .await?;
+                savepoint.commit().await.change_context(UpdateError)?;
+                Ok::<_, Report<UpdateError>>((migrated_entity, operation))
+            }
+            .await;
+
+            match result {
+                Ok((migrated_entity, operation)) => {
+                    response.migrated_entity_ids.push(entity_id);
+                    if operation.is_some() {
+                        migrated_entities.push(migrated_entity);
+                    }
+                }
+                Err(report) => response.failures.push(EntityMigrationFailure {
+                    entity_id,
+                    reason: format!("{report:#}"),
+                }),
+            }
+        }
+
+        if params.dry_run {
+            transaction.rollback().await.change_context(UpdateError)?;
+            return Ok(response);
+        }
+
+        transaction
+            .enqueue_webhook_deliveries(
+                migrated_entities
+                    .iter()
+                    .map(|entity| (EntityChangeOperation::Update, entity)),
+            )
+            .await
+            .change_context(UpdateError)?;
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if !migrated_entities.is_empty() {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, &migrated_entities)
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
+        Ok(response)
     }
+}
 
-    #[expect(
-        clippy::significant_drop_tightening,
-        reason = "The connection is required to borrow the client"
-    )]
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn patch_entity(
+/// Creates the patch operations to merge the properties of `duplicates` into `target`.
+///
+/// For every property taken from a duplicate, the property is added with its metadata. Metadata
+/// of nested values is restored by replacing the nested values, starting with the outermost.
+fn merge_properties(
+    target: &Entity,
+    duplicates: &[Entity],
+    strategy: PropertyMergeStrategy,
+) -> Vec<PropertyPatchOperation> {
+    let starts_with_property = |path: &PropertyPath<'_>, base_url: &BaseUrl| {
+        matches!(
+            path.as_ref().first(),
+            Some(PropertyPathElement::Property(key)) if **key == *base_url
+        )
+    };
+    let top_level_metadata = |entity: &Entity, base_url: &BaseUrl| {
+        entity
+            .metadata
+            .properties
+            .iter()
+            .find(|(path, _)| path.len() == 1 && starts_with_property(path, base_url))
+            .map(|(_, metadata)| metadata.clone())
+            .unwrap_or_default()
+    };
+
+    // Maps every property to the entity the value is taken from, `None` denotes the target
+    let mut selected = HashMap::<&BaseUrl, (Option<usize>, Option<Confidence>)>::new();
+    for (base_url, _) in target.properties.iter() {
+        selected.insert(
+            base_url,
+            (None, top_level_metadata(target, base_url).confidence),
+        );
+    }
+    for (index, duplicate) in duplicates.iter().enumerate() {
+        for (base_url, _) in duplicate.properties.iter() {
+            let confidence = top_level_metadata(duplicate, base_url).confidence;
+            match selected.entry(base_url) {
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert((Some(index), confidence));
+                }
+                hash_map::Entry::Occupied(mut entry) => {
+                    let replace = match strategy {
+                        PropertyMergeStrategy::PreferTarget => false,
+                        PropertyMergeStrategy::PreferDuplicates => true,
+                        PropertyMergeStrategy::PreferHigherConfidence => confidence > entry.get().1,
+                    };
+                    if replace {
+                        entry.insert((Some(index), confidence));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut operations = Vec::new();
+    for (base_url, (source, _)) in selected {
+        let Some(source) = source.map(|index| &duplicates[index]) else {
+            continue;
+        };
+        let value = &source.properties.properties()[base_url];
+        let metadata = top_level_metadata(source, base_url);
+        operations.push(PropertyPatchOperation::Add {
+            path: once(PropertyPathElement::from(base_url.clone())).collect(),
+            value: value.clone(),
+            confidence: metadata.confidence,
+            provenance: metadata.provenance,
+        });
+
+        let mut nested_metadata = source
+            .metadata
+            .properties
+            .iter()
+            .filter(|(path, _)| path.len() > 1 && starts_with_property(path, base_url))
+            .collect::<Vec<_>>();
+        nested_metadata.sort_by_key(|(path, _)| path.len());
+        for (path, metadata) in nested_metadata {
+            let elements: &[PropertyPathElement<'_>] = path.as_ref();
+            if let Some(nested_value) = value.get(&elements[1..]) {
+                operations.push(PropertyPatchOperation::Replace {
+                    path: path.clone().into_owned(),
+                    value: nested_value.clone(),
+                    confidence: metadata.confidence,
+                    provenance: metadata.provenance.clone(),
+                });
+            }
+        }
+    }
+    operations
+}
+
+/// Creates the patch operations to apply the `migrations` to the properties of `entity`.
+///
+/// Each migration is evaluated against the result of the previous migrations.
+fn migrate_properties(
+    entity: &Entity,
+    migrations: &[PropertyMigration],
+) -> Result<Vec<PropertyPatchOperation>, UpdateError> {
+    let mut entity = entity.clone();
+    let mut operations = Vec::new();
+    for migration in migrations {
+        if let Some(operation) =
+            migration.patch_operation(&entity.properties, &entity.metadata.properties)
+        {
+            entity
+                .patch(slice::from_ref(&operation))
+                .change_context(UpdateError)?;
+            operations.push(operation);
+        }
+    }
+    Ok(operations)
+}
+
+#[derive(Debug)]
+#[must_use]
+struct LockedEntityEdition {
+    entity_id: EntityId,
+    entity_edition_id: EntityEditionId,
+    decision_time: LeftClosedTemporalInterval<DecisionTime>,
+    transaction_time: LeftClosedTemporalInterval<TransactionTime>,
+}
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Applies a single patch as part of the current transaction.
+    ///
+    /// Returns the patched [`Entity`] alongside whether a new edition was created. Committing the
+    /// transaction and triggering the embedding generation is up to the caller.
+    ///
+    /// If `merged_into` is specified, the new edition records that the entity was merged into it.
+    #[tracing::instrument(level = "trace", skip(self, params))]
+    async fn patch_entity_in_transaction(
         &mut self,
         actor_id: AccountId,
         mut params: PatchEntityParams,
-    ) -> Result<EntityMetadata, UpdateError> {
+        merged_into: Option<EntityId>,
+    ) -> Result<(Entity, Option<EntityChangeOperation>), UpdateError> {
         let transaction_time = Timestamp::now().remove_nanosecond();
         let decision_time = params
             .decision_time
@@ -1181,9 +3080,7 @@ where
             .assert_permission()
             .change_context(UpdateError)?;
 
-        let transaction = self.transaction().await.change_context(UpdateError)?;
-
-        let locked_row = transaction
+        let locked_row = self
             .lock_entity_edition(params.entity_id, transaction_time, decision_time)
             .await?
             .ok_or_else(|| {
@@ -1192,12 +3089,36 @@ where
                     .attach_printable(params.entity_id)
                     .change_context(UpdateError)
             })?;
+        if !params.expected_edition_ids.is_empty() {
+            // The edition locked above is the one valid at the decision time of the patch, which
+            // is not necessarily the latest edition of the entity.
+            let latest_edition_id = self
+                .lock_latest_entity_edition(params.entity_id, transaction_time)
+                .await?
+                .unwrap_or(locked_row.entity_edition_id);
+            if !params.expected_edition_ids.contains(&latest_edition_id) {
+                bail!(
+                    Report::new(EntityEditionMismatch)
+                        .attach(StatusCode::Aborted)
+                        .attach_printable(format!(
+                            "expected one of the editions {:?} but the latest edition is `{}`",
+                            params
+                                .expected_edition_ids
+                                .iter()
+                                .map(EntityEditionId::as_uuid)
+                                .collect::<Vec<_>>(),
+                            latest_edition_id.as_uuid()
+                        ))
+                        .change_context(UpdateError)
+                );
+            }
+        }
         let ClosedTemporalBound::Inclusive(locked_transaction_time) =
             *locked_row.transaction_time.start();
         let ClosedTemporalBound::Inclusive(locked_decision_time) =
             *locked_row.decision_time.start();
         let mut previous_entity = Read::<Entity>::read_one(
-            &transaction,
+            &*self,
             &Filter::Equal(
                 Some(FilterExpression::Path(EntityQueryPath::EditionId)),
                 Some(FilterExpression::Parameter(Parameter::Uuid(
@@ -1244,7 +3165,8 @@ where
             .draft_id
             .is_some();
         let draft = params.draft.unwrap_or(was_draft_before);
-        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
+        let was_archived_before = previous_entity.metadata.archived;
+        let archived = params.archived.unwrap_or(was_archived_before);
         let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
             (previous_entity.metadata.entity_type_ids, false)
         } else {
@@ -1263,8 +3185,7 @@ where
                 has_changed = true;
 
                 let entity_type_id = EntityTypeId::from_url(entity_type_id);
-                transaction
-                    .authorization_api
+                self.authorization_api
                     .check_entity_type_permission(
                         actor_id,
                         EntityTypePermission::Instantiate,
@@ -1288,21 +3209,29 @@ where
 
         if diff.is_empty()
             && was_draft_before == draft
-            && archived == previous_entity.metadata.archived
+            && archived == was_archived_before
             && !entity_types_updated
             && previous_property_metadata == property_metadata
             && params.confidence == previous_entity.metadata.confidence
+            && merged_into.is_none()
         {
             // No changes were made to the entity.
-            return Ok(EntityMetadata {
-                record_id: previous_entity.metadata.record_id,
-                temporal_versioning: previous_entity.metadata.temporal_versioning,
-                entity_type_ids,
-                provenance: previous_entity.metadata.provenance,
-                archived,
-                confidence: previous_entity.metadata.confidence,
-                properties: property_metadata,
-            });
+            return Ok((
+                Entity {
+                    properties,
+                    link_data: previous_entity.link_data,
+                    metadata: EntityMetadata {
+                        record_id: previous_entity.metadata.record_id,
+                        temporal_versioning: previous_entity.metadata.temporal_versioning,
+                        entity_type_ids,
+                        provenance: previous_entity.metadata.provenance,
+                        archived,
+                        confidence: previous_entity.metadata.confidence,
+                        properties: property_metadata,
+                    },
+                },
+                None,
+            ));
         }
 
         let link_data = previous_entity.link_data;
@@ -1310,9 +3239,11 @@ where
         let edition_provenance = EntityEditionProvenance {
             created_by_id: EditionCreatedById::new(actor_id),
             archived_by_id: None,
+            merged_into,
+            reverted_to: None,
             provided: params.provenance,
         };
-        let (edition_id, closed_schema) = transaction
+        let (edition_id, closed_schema) = self
             .insert_entity_edition(
                 archived,
                 &entity_type_ids,
@@ -1323,28 +3254,25 @@ where
             .await
             .change_context(UpdateError)?;
 
-        transaction
-            .insert_properties(edition_id, &property_metadata)
+        self.insert_properties(edition_id, &property_metadata)
             .await
             .change_context(UpdateError)?;
 
         let temporal_versioning = match (was_draft_before, draft) {
             (true, true) | (false, false) => {
                 // regular update
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
-                        transaction_time,
-                        decision_time,
-                        edition_id,
-                        false,
-                    )
-                    .await?
+                self.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    false,
+                )
+                .await?
             }
             (false, true) => {
                 let draft_id = DraftId::new(Uuid::new_v4());
-                transaction
-                    .as_client()
+                self.as_client()
                     .query(
                         "
                         INSERT INTO entity_drafts (
@@ -1361,23 +3289,21 @@ where
                     .await
                     .change_context(UpdateError)?;
                 params.entity_id.draft_id = Some(draft_id);
-                transaction
-                    .insert_temporal_metadata(
-                        params.entity_id,
-                        edition_id,
-                        transaction_time,
-                        decision_time,
-                    )
-                    .await
-                    .change_context(UpdateError)?
+                self.insert_temporal_metadata(
+                    params.entity_id,
+                    edition_id,
+                    transaction_time,
+                    decision_time,
+                )
+                .await
+                .change_context(UpdateError)?
             }
             (true, false) => {
                 // Publish a draft
                 params.entity_id.draft_id = None;
 
                 if first_non_draft_created_at_decision_time.is_none() {
-                    transaction
-                        .as_client()
+                    self.as_client()
                         .query(
                             "
                             UPDATE entity_ids
@@ -1402,54 +3328,85 @@ where
                     first_non_draft_created_at_decision_time = Some(decision_time);
                 }
 
-                if let Some(previous_live_entity) = transaction
+                if let Some(previous_live_entity) = self
                     .lock_entity_edition(params.entity_id, transaction_time, decision_time)
                     .await?
                 {
-                    transaction
-                        .archive_entity(
-                            actor_id,
-                            previous_live_entity,
-                            transaction_time,
-                            decision_time,
-                        )
-                        .await?;
-                }
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
+                    self.archive_entity(
+                        actor_id,
+                        previous_live_entity,
                         transaction_time,
                         decision_time,
-                        edition_id,
-                        true,
                     )
-                    .await?
+                    .await?;
+                }
+                self.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    true,
+                )
+                .await?
             }
         };
 
+        if was_draft_before && !draft {
+            if let Some(link_data) = &link_data {
+                self.ensure_linked_entities_are_published(link_data)
+                    .await
+                    .change_context(UpdateError)?;
+            }
+        }
+
         let validation_components = if draft {
             ValidateEntityComponents::draft()
         } else {
             ValidateEntityComponents::full()
         };
 
-        transaction
-            .validate_entity(
-                actor_id,
-                Consistency::FullyConsistent,
-                ValidateEntityParams {
-                    entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
-                    properties: Cow::Borrowed(&properties),
-                    property_metadata: Cow::Borrowed(&property_metadata),
-                    link_data: link_data.as_ref().map(Cow::Borrowed),
-                    components: validation_components,
-                },
-            )
-            .await
-            .change_context(UpdateError)
-            .attach(StatusCode::InvalidArgument)?;
+        self.validate_entity(
+            actor_id,
+            Consistency::FullyConsistent,
+            ValidateEntityParams {
+                entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
+                properties: Cow::Borrowed(&properties),
+                property_metadata: Cow::Borrowed(&property_metadata),
+                link_data: link_data.as_ref().map(Cow::Borrowed),
+                components: validation_components,
+            },
+        )
+        .await
+        .change_context(UpdateError)
+        .attach(StatusCode::InvalidArgument)?;
+
+        if !was_archived_before && archived {
+            if let Some(link_data) = &link_data {
+                validate_link_removal(
+                    &closed_schema,
+                    link_data,
+                    validation_components,
+                    &StoreProvider {
+                        store: &*self,
+                        cache: StoreCache::default(),
+                        authorization: Some((
+                            &self.authorization_api,
+                            actor_id,
+                            Consistency::FullyConsistent,
+                        )),
+                    },
+                )
+                .await
+                .change_context(UpdateError)
+                .attach(StatusCode::InvalidArgument)?;
+            }
+        }
 
-        transaction.commit().await.change_context(UpdateError)?;
+        if !draft && !archived {
+            self.ensure_unique_properties(params.entity_id, &entity_type_ids, &properties)
+                .await
+                .change_context(UpdateError)?;
+        }
 
         let entity_metadata = EntityMetadata {
             record_id: EntityRecordId {
@@ -1470,153 +3427,21 @@ where
             properties: property_metadata,
             archived,
         };
-        if let Some(temporal_client) = &self.temporal_client {
-            temporal_client
-                .start_update_entity_embeddings_workflow(
-                    actor_id,
-                    &[Entity {
-                        properties,
-                        link_data,
-                        metadata: entity_metadata.clone(),
-                    }],
-                )
-                .await
-                .change_context(UpdateError)?;
-        }
-        Ok(entity_metadata)
-    }
-
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn update_entity_embeddings(
-        &mut self,
-        _: AccountId,
-        params: UpdateEntityEmbeddingsParams<'_>,
-    ) -> Result<(), UpdateError> {
-        #[derive(Debug, ToSql)]
-        #[postgres(name = "entity_embeddings")]
-        pub struct EntityEmbeddingsRow<'a> {
-            web_id: OwnedById,
-            entity_uuid: EntityUuid,
-            draft_id: Option<DraftId>,
-            property: Option<String>,
-            embedding: Embedding<'a>,
-            updated_at_transaction_time: Timestamp<TransactionTime>,
-            updated_at_decision_time: Timestamp<DecisionTime>,
-        }
-        let entity_embeddings = params
-            .embeddings
-            .into_iter()
-            .map(|embedding: EntityEmbedding<'_>| EntityEmbeddingsRow {
-                web_id: params.entity_id.owned_by_id,
-                entity_uuid: params.entity_id.entity_uuid,
-                draft_id: params.entity_id.draft_id,
-                property: embedding.property.as_ref().map(ToString::to_string),
-                embedding: embedding.embedding,
-                updated_at_transaction_time: params.updated_at_transaction_time,
-                updated_at_decision_time: params.updated_at_decision_time,
-            })
-            .collect::<Vec<_>>();
-
-        // TODO: Add permission to allow updating embeddings
-        //   see https://linear.app/hash/issue/H-1870
-        // let permissions = authorization_api
-        //     .check_entities_permission(
-        //         actor_id,
-        //         EntityPermission::UpdateEmbeddings,
-        //         entity_ids.iter().copied(),
-        //         Consistency::FullyConsistent,
-        //     )
-        //     .await
-        //     .change_context(UpdateError)?
-        //     .0
-        //     .into_iter()
-        //     .filter_map(|(entity_id, has_permission)| (!has_permission).then_some(entity_id))
-        //     .collect::<Vec<_>>();
-        // if !permissions.is_empty() {
-        //     let mut status = Report::new(PermissionAssertion);
-        //     for entity_id in permissions {
-        //         status = status.attach(format!("Permission denied for entity {entity_id}"));
-        //     }
-        //     return Err(status.change_context(UpdateError));
-        // }
-
-        if params.reset {
-            if let Some(draft_id) = params.entity_id.draft_id {
-                self.as_client()
-                    .query(
-                        "
-                        DELETE FROM entity_embeddings
-                        WHERE web_id = $1
-                          AND entity_uuid = $2
-                          AND draft_id = $3
-                          AND updated_at_transaction_time <= $4
-                          AND updated_at_decision_time <= $5;
-                    ",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &draft_id,
-                            &params.updated_at_transaction_time,
-                            &params.updated_at_decision_time,
-                        ],
-                    )
-                    .await
-                    .change_context(UpdateError)?;
-            } else {
-                self.as_client()
-                    .query(
-                        "
-                        DELETE FROM entity_embeddings
-                        WHERE web_id = $1
-                          AND entity_uuid = $2
-                          AND draft_id IS NULL
-                          AND updated_at_transaction_time <= $3
-                          AND updated_at_decision_time <= $4;
-                    ",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &params.updated_at_transaction_time,
-                            &params.updated_at_decision_time,
-                        ],
-                    )
-                    .await
-                    .change_context(UpdateError)?;
-            }
-        }
-        self.as_client()
-            .query(
-                "
-                    INSERT INTO entity_embeddings
-                    SELECT * FROM UNNEST($1::entity_embeddings[])
-                    ON CONFLICT (web_id, entity_uuid, property) DO UPDATE
-                    SET
-                        embedding = EXCLUDED.embedding,
-                        updated_at_transaction_time = EXCLUDED.updated_at_transaction_time,
-                        updated_at_decision_time = EXCLUDED.updated_at_decision_time
-                    WHERE entity_embeddings.updated_at_transaction_time <= \
-                 EXCLUDED.updated_at_transaction_time
-                    AND entity_embeddings.updated_at_decision_time <= \
-                 EXCLUDED.updated_at_decision_time;
-                ",
-                &[&entity_embeddings],
-            )
-            .await
-            .change_context(UpdateError)?;
-
-        Ok(())
+        Ok((
+            Entity {
+                properties,
+                link_data,
+                metadata: entity_metadata,
+            },
+            Some(match (was_archived_before, archived) {
+                (false, true) => EntityChangeOperation::Archive,
+                (true, false) => EntityChangeOperation::Unarchive,
+                _ => EntityChangeOperation::Update,
+            }),
+        ))
     }
 }
 
-#[derive(Debug)]
-#[must_use]
-struct LockedEntityEdition {
-    entity_id: EntityId,
-    entity_edition_id: EntityEditionId,
-    decision_time: LeftClosedTemporalInterval<DecisionTime>,
-    transaction_time: LeftClosedTemporalInterval<TransactionTime>,
-}
-
 impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
 where
     A: Send + Sync,
@@ -1799,6 +3624,44 @@ where
             })
     }
 
+    /// Locks the latest edition of the entity at the given transaction time.
+    ///
+    /// The latest edition is the edition with the most recent decision time.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn lock_latest_entity_edition(
+        &self,
+        entity_id: EntityId,
+        transaction_time: Timestamp<TransactionTime>,
+    ) -> Result<Option<EntityEditionId>, UpdateError> {
+        self.as_client()
+            .query_opt(
+                "
+                    SELECT entity_temporal_metadata.entity_edition_id
+                    FROM entity_temporal_metadata
+                    WHERE entity_temporal_metadata.web_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.draft_id IS NOT DISTINCT FROM $3
+                      AND entity_temporal_metadata.transaction_time @> $4::timestamptz
+                    ORDER BY lower(entity_temporal_metadata.decision_time) DESC
+                    LIMIT 1
+                    FOR NO KEY UPDATE NOWAIT;",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_id.draft_id,
+                    &transaction_time,
+                ],
+            )
+            .await
+            .map(|row| row.map(|row| row.get(0)))
+            .map_err(|error| match error.code() {
+                Some(&SqlState::LOCK_NOT_AVAILABLE) => Report::new(RaceConditionOnUpdate)
+                    .attach(entity_id)
+                    .change_context(UpdateError),
+                _ => Report::new(error).change_context(UpdateError),
+            })
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     async fn insert_temporal_metadata(
         &self,
@@ -2005,6 +3868,78 @@ where
         })
     }
 
+    /// Removes all records of the specified entities from the database.
+    ///
+    /// This includes every edition, draft, embedding, and link record of the entities.
+    #[tracing::instrument(level = "trace", skip(self))]
+    async fn delete_entity_records(&self, entity_ids: &[EntityId]) -> Result<(), DeletionError> {
+        let (web_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+
+        let edition_ids = self
+            .as_client()
+            .query(
+                "
+                    DELETE FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    )
+                    RETURNING entity_edition_id;
+                ",
+                &[&web_ids, &entity_uuids],
+            )
+            .await
+            .change_context(DeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityEditionId>(0))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for statement in [
+            "DELETE FROM entity_property WHERE entity_edition_id = ANY($1::UUID[]);",
+            "DELETE FROM entity_is_of_type WHERE entity_edition_id = ANY($1::UUID[]);",
+            "DELETE FROM entity_editions WHERE entity_edition_id = ANY($1::UUID[]);",
+        ] {
+            self.as_client()
+                .query(statement, &[&edition_ids])
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        for statement in [
+            "
+                DELETE FROM entity_has_left_entity
+                WHERE (web_id, entity_uuid) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]));
+            ",
+            "
+                DELETE FROM entity_has_right_entity
+                WHERE (web_id, entity_uuid) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]));
+            ",
+            "
+                DELETE FROM entity_embeddings
+                WHERE (web_id, entity_uuid) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]));
+            ",
+            "
+                DELETE FROM entity_drafts
+                WHERE (web_id, entity_uuid) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]));
+            ",
+            "
+                DELETE FROM entity_ids
+                WHERE (web_id, entity_uuid) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]));
+            ",
+        ] {
+            self.as_client()
+                .query(statement, &[&web_ids, &entity_uuids])
+                .await
+                .change_context(DeletionError)?;
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     async fn archive_entity(
         &self,
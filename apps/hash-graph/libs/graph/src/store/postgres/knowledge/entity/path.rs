@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+
+use authorization::{
+    schema::EntityPermission,
+    zanzibar::{Consistency, Zookie},
+    AuthorizationApi,
+};
+use error_stack::{Result, ResultExt};
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityEditionId, EntityId},
+};
+use temporal_versioning::{LeftClosedTemporalInterval, RightBoundedTemporalInterval};
+
+use crate::{
+    store::{
+        knowledge::FindEntityPathsParams,
+        postgres::{
+            knowledge::entity::read::{EntityEdgeTraversalData, KnowledgeEdgeTraversal},
+            query::ReferenceTable,
+        },
+        query::Filter,
+        AsClient, PostgresStore, QueryError,
+    },
+    subgraph::{
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind},
+        identifier::{EntityIdWithInterval, EntityVertexId},
+        temporal_axes::VariableAxis,
+        Subgraph,
+    },
+};
+
+/// A hop from an entity through a link entity to a neighbouring entity.
+#[derive(Debug, Copy, Clone)]
+struct PathStep {
+    entity: EntityVertexId,
+    /// The kind of the edge between the link entity and `entity`.
+    entity_edge_kind: KnowledgeGraphEdgeKind,
+    entity_edge_interval: LeftClosedTemporalInterval<VariableAxis>,
+    link_entity: EntityVertexId,
+    link_entity_edition_id: EntityEditionId,
+    /// The kind of the edge between the link entity and `next_entity`.
+    next_entity_edge_kind: KnowledgeGraphEdgeKind,
+    next_entity_edge_interval: LeftClosedTemporalInterval<VariableAxis>,
+    next_entity: EntityVertexId,
+    next_entity_edition_id: EntityEditionId,
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
+impl PathStep {
+    fn insert_edges(&self, subgraph: &mut Subgraph) {
+        subgraph.insert_edge(
+            &self.entity,
+            self.entity_edge_kind,
+            EdgeDirection::Incoming,
+            EntityIdWithInterval {
+                entity_id: self.link_entity.base_id,
+                interval: self.entity_edge_interval,
+            },
+        );
+        subgraph.insert_edge(
+            &self.link_entity,
+            self.next_entity_edge_kind,
+            EdgeDirection::Outgoing,
+            EntityIdWithInterval {
+                entity_id: self.next_entity.base_id,
+                interval: self.next_entity_edge_interval,
+            },
+        );
+    }
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Returns the entity IDs out of `entity_ids` which the actor is permitted to view.
+    async fn filter_viewable_entities(
+        &self,
+        entity_ids: HashSet<EntityId>,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+    ) -> Result<HashSet<EntityId>, QueryError> {
+        let permissions = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids.iter().copied(),
+                Consistency::AtExactSnapshot(zookie),
+            )
+            .await
+            .change_context(QueryError)?
+            .0;
+
+        Ok(entity_ids
+            .into_iter()
+            .filter(|entity_id| {
+                permissions
+                    .get(&entity_id.entity_uuid)
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .collect())
+    }
+
+    /// Reads the link entities pointing at the entities in `traversal_data`.
+    ///
+    /// Only link entities matching `link_filter` and viewable by the actor are returned.
+    async fn read_link_edges(
+        &self,
+        traversal_data: &EntityEdgeTraversalData,
+        reference_table: ReferenceTable,
+        link_filter: Option<&Filter<'_, Entity>>,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &Subgraph,
+    ) -> Result<Vec<KnowledgeEdgeTraversal>, QueryError> {
+        let mut link_edges = self
+            .read_knowledge_edges(traversal_data, reference_table, EdgeDirection::Incoming)
+            .await?
+            .map(|(_, edge)| edge)
+            .collect::<Vec<_>>();
+
+        if let Some(filter) = link_filter {
+            let matching_editions = self
+                .filter_entities_by_ids(
+                    link_edges.iter().map(|edge| edge.right_endpoint_edition_id),
+                    filter,
+                    subgraph,
+                )
+                .await?;
+            link_edges.retain(|edge| matching_editions.contains(&edge.right_endpoint_edition_id));
+        }
+
+        let link_entity_ids = link_edges
+            .iter()
+            .map(|edge| edge.right_endpoint.base_id)
+            .collect::<HashSet<_>>();
+        let viewable_link_entities = self
+            .filter_viewable_entities(link_entity_ids, actor_id, zookie)
+            .await?;
+        link_edges.retain(|edge| viewable_link_entities.contains(&edge.right_endpoint.base_id));
+
+        Ok(link_edges)
+    }
+
+    /// Reads the hops from the `entities` through link entities to their neighbouring entities.
+    ///
+    /// Link entities are followed in both directions. Only link entities matching `link_filter`
+    /// are followed, and both the link entity and the neighbouring entity have to be viewable by
+    /// the actor.
+    async fn read_path_steps(
+        &self,
+        entities: &[(EntityVertexId, RightBoundedTemporalInterval<VariableAxis>)],
+        link_filter: Option<&Filter<'_, Entity>>,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &Subgraph,
+    ) -> Result<Vec<PathStep>, QueryError> {
+        let pinned_timestamp = subgraph.temporal_axes.resolved.pinned_timestamp();
+        let variable_axis = subgraph.temporal_axes.resolved.variable_time_axis();
+
+        let mut steps = Vec::new();
+
+        for (entity_edge_kind, entity_table, next_entity_edge_kind, next_entity_table) in [
+            (
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                ReferenceTable::EntityHasLeftEntity,
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                ReferenceTable::EntityHasRightEntity,
+            ),
+            (
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                ReferenceTable::EntityHasRightEntity,
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                ReferenceTable::EntityHasLeftEntity,
+            ),
+        ] {
+            let mut traversal_data = EntityEdgeTraversalData::new(pinned_timestamp, variable_axis);
+            for &(vertex_id, interval) in entities {
+                traversal_data.push(vertex_id, interval, subgraph.depths);
+            }
+
+            let link_edges = self
+                .read_link_edges(
+                    &traversal_data,
+                    entity_table,
+                    link_filter,
+                    actor_id,
+                    zookie,
+                    subgraph,
+                )
+                .await?;
+
+            if link_edges.is_empty() {
+                continue;
+            }
+
+            // A link entity may be reached from several revisions of an entity, but its edges only
+            // have to be read once.
+            let mut link_edges_by_vertex = HashMap::<_, Vec<&KnowledgeEdgeTraversal>>::new();
+            let mut link_traversal_data =
+                EntityEdgeTraversalData::new(pinned_timestamp, variable_axis);
+            for edge in &link_edges {
+                let vertex_edges = link_edges_by_vertex.entry(edge.right_endpoint).or_default();
+                if vertex_edges.is_empty() {
+                    link_traversal_data.push(
+                        edge.right_endpoint,
+                        edge.traversal_interval,
+                        subgraph.depths,
+                    );
+                }
+                vertex_edges.push(edge);
+            }
+
+            let next_edges = self
+                .read_knowledge_edges(
+                    &link_traversal_data,
+                    next_entity_table,
+                    EdgeDirection::Outgoing,
+                )
+                .await?
+                .map(|(_, edge)| edge)
+                .collect::<Vec<_>>();
+
+            let next_entity_ids = next_edges
+                .iter()
+                .map(|edge| edge.right_endpoint.base_id)
+                .collect::<HashSet<_>>();
+            let viewable_next_entities = self
+                .filter_viewable_entities(next_entity_ids, actor_id, zookie)
+                .await?;
+
+            for next_edge in next_edges {
+                if !viewable_next_entities.contains(&next_edge.right_endpoint.base_id) {
+                    continue;
+                }
+
+                for link_edge in link_edges_by_vertex
+                    .get(&next_edge.left_endpoint)
+                    .into_iter()
+                    .flatten()
+                {
+                    steps.push(PathStep {
+                        entity: link_edge.left_endpoint,
+                        entity_edge_kind,
+                        entity_edge_interval: link_edge.edge_interval,
+                        link_entity: link_edge.right_endpoint,
+                        link_entity_edition_id: link_edge.right_endpoint_edition_id,
+                        next_entity_edge_kind,
+                        next_entity_edge_interval: next_edge.edge_interval,
+                        next_entity: next_edge.right_endpoint,
+                        next_entity_edition_id: next_edge.right_endpoint_edition_id,
+                        traversal_interval: next_edge.traversal_interval,
+                    });
+                }
+            }
+        }
+
+        Ok(steps)
+    }
+
+    /// Finds the shortest paths from the entities in the subgraph to the target entity.
+    ///
+    /// The subgraph is expected to only contain the source entity. The entities and edges on the
+    /// shortest paths are inserted into the subgraph, if any path within the maximum depth exists.
+    pub(crate) async fn traverse_entity_paths(
+        &self,
+        params: &FindEntityPathsParams,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        if params.source_entity_id == params.target_entity_id {
+            return Ok(());
+        }
+
+        let link_filter = params.link_entity_type_ids.as_ref().map(|entity_type_ids| {
+            Filter::Any(
+                entity_type_ids
+                    .iter()
+                    .map(Filter::for_entity_by_type_id)
+                    .collect(),
+            )
+        });
+
+        let mut frontier = subgraph
+            .vertices
+            .entities
+            .keys()
+            .map(|vertex_id| {
+                (
+                    *vertex_id,
+                    subgraph.temporal_axes.resolved.variable_interval(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut distances = HashMap::from([(params.source_entity_id, 0)]);
+        let mut predecessors = HashMap::<EntityVertexId, Vec<PathStep>>::new();
+
+        for depth in 1..=params.max_depth {
+            if frontier.is_empty() || distances.contains_key(&params.target_entity_id) {
+                break;
+            }
+
+            let mut next_frontier = HashMap::new();
+            for step in self
+                .read_path_steps(&frontier, link_filter.as_ref(), actor_id, zookie, subgraph)
+                .await?
+            {
+                // Only steps reaching an entity for the first time, or on another path of the
+                // same length, are part of a shortest path.
+                if *distances.entry(step.next_entity.base_id).or_insert(depth) == depth {
+                    predecessors.entry(step.next_entity).or_default().push(step);
+                    next_frontier
+                        .entry(step.next_entity)
+                        .or_insert(step.traversal_interval);
+                }
+            }
+
+            frontier = next_frontier.into_iter().collect();
+        }
+
+        let mut queue = predecessors
+            .keys()
+            .filter(|vertex_id| vertex_id.base_id == params.target_entity_id)
+            .copied()
+            .collect::<Vec<_>>();
+        let mut visited = queue.iter().copied().collect::<HashSet<_>>();
+        let mut edition_ids = HashSet::new();
+
+        while let Some(vertex_id) = queue.pop() {
+            for step in predecessors.get(&vertex_id).into_iter().flatten() {
+                step.insert_edges(subgraph);
+                edition_ids.insert(step.link_entity_edition_id);
+                edition_ids.insert(step.next_entity_edition_id);
+
+                if visited.insert(step.entity) {
+                    queue.push(step.entity);
+                }
+            }
+        }
+
+        if !edition_ids.is_empty() {
+            self.read_entities_by_ids(edition_ids, subgraph, params.include_drafts)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+use std::borrow::Cow;
+
+use authorization::{
+    schema::{EntityPermission, EntityTypePermission},
+    zanzibar::Consistency,
+    AuthorizationApi,
+};
+use error_stack::{bail, Report, Result, ResultExt};
+use graph_types::{
+    account::{AccountId, EditionCreatedById},
+    knowledge::entity::{
+        Entity, EntityEditionProvenance, EntityMetadata, EntityProvenance, EntityRecordId,
+    },
+    ontology::EntityTypeId,
+};
+use hash_status::StatusCode;
+use temporal_versioning::{
+    ClosedTemporalBound, LimitedTemporalBound, TemporalBound, TemporalTagged, Timestamp,
+    TransactionTime,
+};
+use validation::ValidateEntityComponents;
+
+use crate::{
+    knowledge::EntityQueryPath,
+    store::{
+        crud::Read,
+        error::EntityDoesNotExist,
+        knowledge::{
+            EntityRevertTarget, EntityValidationType, RevertEntityParams, ValidateEntityParams,
+        },
+        query::{Filter, FilterExpression, Parameter},
+        EntityStore, PostgresStore, UpdateError,
+    },
+    subgraph::temporal_axes::{PinnedTemporalAxis, QueryTemporalAxes, VariableTemporalAxis},
+};
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Reads the edition of the entity the revert targets.
+    ///
+    /// The edition is read as it was visible at the current decision time.
+    async fn read_revert_target(
+        &self,
+        params: &RevertEntityParams,
+        transaction_time: Timestamp<TransactionTime>,
+    ) -> Result<Entity, UpdateError> {
+        let decision_time = transaction_time.cast();
+        let (filter, variable) = match params.target {
+            EntityRevertTarget::EditionId(edition_id) => (
+                Filter::All(vec![
+                    Filter::for_entity_by_entity_id(params.entity_id),
+                    Filter::Equal(
+                        Some(FilterExpression::Path(EntityQueryPath::EditionId)),
+                        Some(FilterExpression::Parameter(Parameter::Uuid(
+                            edition_id.into_uuid(),
+                        ))),
+                    ),
+                ]),
+                VariableTemporalAxis::new(
+                    TemporalBound::Unbounded,
+                    LimitedTemporalBound::Inclusive(transaction_time),
+                ),
+            ),
+            EntityRevertTarget::TransactionTime(target_transaction_time) => (
+                Filter::for_entity_by_entity_id(params.entity_id),
+                VariableTemporalAxis::new(
+                    TemporalBound::Inclusive(target_transaction_time),
+                    LimitedTemporalBound::Inclusive(target_transaction_time),
+                ),
+            ),
+        };
+
+        Read::<Entity>::read_vec(
+            self,
+            &filter,
+            Some(&QueryTemporalAxes::TransactionTime {
+                pinned: PinnedTemporalAxis::new(decision_time),
+                variable,
+            }),
+            true,
+        )
+        .await
+        .change_context(UpdateError)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(UpdateError)
+        })
+    }
+
+    /// Reverts an entity as part of the current transaction.
+    ///
+    /// Returns the reverted [`Entity`] alongside whether a new edition was created.
+    #[expect(clippy::too_many_lines)]
+    pub(super) async fn revert_entity_in_transaction(
+        &self,
+        actor_id: AccountId,
+        params: RevertEntityParams,
+    ) -> Result<(Entity, bool), UpdateError> {
+        let transaction_time = Timestamp::now().remove_nanosecond();
+        let decision_time = transaction_time.cast();
+
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let locked_row = self
+            .lock_entity_edition(params.entity_id, transaction_time, decision_time)
+            .await?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            })?;
+        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+            *locked_row.transaction_time.start();
+        let ClosedTemporalBound::Inclusive(locked_decision_time) =
+            *locked_row.decision_time.start();
+        let current_entity = Read::<Entity>::read_one(
+            self,
+            &Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::EditionId)),
+                Some(FilterExpression::Parameter(Parameter::Uuid(
+                    locked_row.entity_edition_id.into_uuid(),
+                ))),
+            ),
+            Some(&QueryTemporalAxes::DecisionTime {
+                pinned: PinnedTemporalAxis::new(locked_transaction_time),
+                variable: VariableTemporalAxis::new(
+                    TemporalBound::Inclusive(locked_decision_time),
+                    LimitedTemporalBound::Inclusive(locked_decision_time),
+                ),
+            }),
+            true,
+        )
+        .await
+        .change_context(EntityDoesNotExist)
+        .attach(params.entity_id)
+        .change_context(UpdateError)?;
+
+        let target_entity = self.read_revert_target(&params, transaction_time).await?;
+        let target_edition_id = target_entity.metadata.record_id.edition_id;
+        if target_edition_id == locked_row.entity_edition_id {
+            return Ok((current_entity, false));
+        }
+
+        if !self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                target_entity
+                    .metadata
+                    .entity_type_ids
+                    .iter()
+                    .map(EntityTypeId::from_url),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .0
+            .into_iter()
+            .all(|(_, permission)| permission)
+        {
+            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        }
+
+        let edition_provenance = EntityEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            merged_into: None,
+            reverted_to: Some(target_edition_id),
+            provided: params.provenance,
+        };
+        let archived = current_entity.metadata.archived;
+        let (edition_id, closed_schema) = self
+            .insert_entity_edition(
+                archived,
+                &target_entity.metadata.entity_type_ids,
+                &target_entity.properties,
+                target_entity.metadata.confidence,
+                &edition_provenance,
+            )
+            .await
+            .change_context(UpdateError)?;
+        self.insert_properties(edition_id, &target_entity.metadata.properties)
+            .await
+            .change_context(UpdateError)?;
+
+        let temporal_versioning = self
+            .update_temporal_metadata(
+                locked_row,
+                transaction_time,
+                decision_time,
+                edition_id,
+                false,
+            )
+            .await?;
+
+        self.validate_entity(
+            actor_id,
+            Consistency::FullyConsistent,
+            ValidateEntityParams {
+                entity_types: EntityValidationType::ClosedSchema(Cow::Borrowed(&closed_schema)),
+                properties: Cow::Borrowed(&target_entity.properties),
+                property_metadata: Cow::Borrowed(&target_entity.metadata.properties),
+                link_data: current_entity.link_data.as_ref().map(Cow::Borrowed),
+                components: if params.entity_id.draft_id.is_some() {
+                    ValidateEntityComponents::draft()
+                } else {
+                    ValidateEntityComponents::full()
+                },
+            },
+        )
+        .await
+        .change_context(UpdateError)
+        .attach(StatusCode::InvalidArgument)?;
+
+        if params.entity_id.draft_id.is_none() && !archived {
+            self.ensure_unique_properties(
+                params.entity_id,
+                &target_entity.metadata.entity_type_ids,
+                &target_entity.properties,
+            )
+            .await
+            .change_context(UpdateError)?;
+        }
+
+        Ok((
+            Entity {
+                properties: target_entity.properties,
+                link_data: current_entity.link_data,
+                metadata: EntityMetadata {
+                    record_id: EntityRecordId {
+                        entity_id: params.entity_id,
+                        edition_id,
+                    },
+                    temporal_versioning,
+                    entity_type_ids: target_entity.metadata.entity_type_ids,
+                    provenance: EntityProvenance {
+                        inferred: current_entity.metadata.provenance.inferred,
+                        edition: edition_provenance,
+                    },
+                    archived,
+                    confidence: target_entity.metadata.confidence,
+                    properties: target_entity.metadata.properties,
+                },
+            },
+            true,
+        ))
+    }
+}
@@ -0,0 +1,233 @@
+use authorization::AuthorizationApi;
+use error_stack::{bail, Report, Result, ResultExt};
+use graph_types::{
+    knowledge::{
+        entity::{EntityId, EntityUuid},
+        PropertyObject, PropertyPath, PropertyPathElement,
+    },
+    ontology::EntityTypeId,
+};
+use hash_status::StatusCode;
+use postgres_types::Json;
+use temporal_versioning::{DecisionTime, Timestamp};
+use type_system::url::{BaseUrl, VersionedUrl};
+
+use crate::store::{error::PropertyIsNotUnique, AsClient, PostgresStore, QueryError};
+
+/// Returns the elements of the path as expected by the `#>` operator of Postgres.
+fn path_elements(property_path: &PropertyPath<'_>) -> Vec<String> {
+    property_path
+        .iter()
+        .map(|element| match element {
+            PropertyPathElement::Property(key) => key.as_str().to_owned(),
+            PropertyPathElement::Index(index) => index.to_string(),
+        })
+        .collect()
+}
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Ensures that the entity does not share a value, which is constrained to be unique, with
+    /// another entity in its web.
+    ///
+    /// Only the currently valid, non-draft and non-archived editions of other entities are taken
+    /// into account. Concurrent checks for the same value are serialized until the end of the
+    /// transaction, so two transactions cannot insert the same value at the same time.
+    #[expect(clippy::too_many_lines)]
+    pub(super) async fn ensure_unique_properties(
+        &self,
+        entity_id: EntityId,
+        entity_type_ids: &[VersionedUrl],
+        properties: &PropertyObject,
+    ) -> Result<(), QueryError> {
+        let entity_type_ids = entity_type_ids
+            .iter()
+            .map(|entity_type_id| EntityTypeId::from_url(entity_type_id).into_uuid())
+            .collect::<Vec<_>>();
+
+        let constraints = self
+            .as_client()
+            .query(
+                "
+                    SELECT DISTINCT entity_type_base_url, property_path
+                    FROM entity_type_uniqueness_constraints
+                    JOIN ontology_ids ON ontology_ids.base_url = entity_type_base_url
+                    WHERE ontology_ids.ontology_id = ANY($1::UUID[])
+                       OR ontology_ids.ontology_id IN (
+                           SELECT target_entity_type_ontology_id
+                           FROM closed_entity_type_inherits_from
+                           WHERE source_entity_type_ontology_id = ANY($1::UUID[])
+                       );
+                ",
+                &[&entity_type_ids],
+            )
+            .await
+            .change_context(QueryError)?;
+        if constraints.is_empty() {
+            return Ok(());
+        }
+
+        let properties = serde_json::to_value(properties).change_context(QueryError)?;
+        let decision_time = Timestamp::<DecisionTime>::now();
+        for row in constraints {
+            let entity_type_base_url: BaseUrl = row.get(0);
+            let property_path: PropertyPath<'_> = row.get(1);
+            let json_pointer = property_path.to_json_pointer();
+            let Some(value) = properties.pointer(&json_pointer) else {
+                continue;
+            };
+
+            self.as_client()
+                .query(
+                    "SELECT pg_advisory_xact_lock(hashtextextended($1::TEXT, 0));",
+                    &[&format!(
+                        "{}:{entity_type_base_url}:{json_pointer}:{value}",
+                        entity_id.owned_by_id
+                    )],
+                )
+                .await
+                .change_context(QueryError)?;
+
+            let conflicting_entity = self
+                .as_client()
+                .query_opt(
+                    "
+                        SELECT entity_temporal_metadata.entity_uuid
+                        FROM entity_temporal_metadata
+                        JOIN entity_editions USING (entity_edition_id)
+                        JOIN entity_is_of_type USING (entity_edition_id)
+                        JOIN ontology_ids
+                          ON ontology_ids.ontology_id = entity_is_of_type.entity_type_ontology_id
+                        WHERE entity_temporal_metadata.web_id = $1
+                          AND entity_temporal_metadata.entity_uuid <> $2
+                          AND entity_temporal_metadata.draft_id IS NULL
+                          AND upper_inf(entity_temporal_metadata.transaction_time)
+                          AND entity_temporal_metadata.decision_time @> $3::TIMESTAMPTZ
+                          AND NOT entity_editions.archived
+                          AND entity_editions.properties #> $4::TEXT[] = $5::JSONB
+                          AND (
+                              ontology_ids.base_url = $6
+                              OR EXISTS (
+                                  SELECT 1
+                                  FROM closed_entity_type_inherits_from
+                                  JOIN ontology_ids AS parent_ids
+                                    ON parent_ids.ontology_id = target_entity_type_ontology_id
+                                  WHERE source_entity_type_ontology_id
+                                        = entity_is_of_type.entity_type_ontology_id
+                                    AND parent_ids.base_url = $6
+                              )
+                          )
+                        LIMIT 1;
+                    ",
+                    &[
+                        &entity_id.owned_by_id,
+                        &entity_id.entity_uuid,
+                        &decision_time,
+                        &path_elements(&property_path),
+                        &Json(value),
+                        &entity_type_base_url.as_str(),
+                    ],
+                )
+                .await
+                .change_context(QueryError)?;
+
+            if let Some(conflicting_entity) = conflicting_entity {
+                bail!(
+                    Report::new(PropertyIsNotUnique {
+                        entity_type_base_url,
+                        property_path: property_path.into_owned(),
+                        conflicting_entity_id: EntityId {
+                            owned_by_id: entity_id.owned_by_id,
+                            entity_uuid: conflicting_entity.get::<_, EntityUuid>(0),
+                            draft_id: None,
+                        },
+                    })
+                    .attach(StatusCode::AlreadyExists)
+                    .change_context(QueryError)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that no two entities in a web share a value at `property_path`.
+    ///
+    /// This is used to check existing entities when a uniqueness constraint is added.
+    pub(crate) async fn ensure_uniqueness_constraint_holds(
+        &self,
+        entity_type_base_url: &BaseUrl,
+        property_path: &PropertyPath<'_>,
+    ) -> Result<(), QueryError> {
+        let duplicate = self
+            .as_client()
+            .query_opt(
+                "
+                    SELECT
+                        entity_temporal_metadata.web_id,
+                        array_agg(DISTINCT entity_temporal_metadata.entity_uuid)
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions USING (entity_edition_id)
+                    JOIN entity_is_of_type USING (entity_edition_id)
+                    JOIN ontology_ids
+                      ON ontology_ids.ontology_id = entity_is_of_type.entity_type_ontology_id
+                    WHERE entity_temporal_metadata.draft_id IS NULL
+                      AND upper_inf(entity_temporal_metadata.transaction_time)
+                      AND entity_temporal_metadata.decision_time @> $1::TIMESTAMPTZ
+                      AND NOT entity_editions.archived
+                      AND entity_editions.properties #> $2::TEXT[] IS NOT NULL
+                      AND (
+                          ontology_ids.base_url = $3
+                          OR EXISTS (
+                              SELECT 1
+                              FROM closed_entity_type_inherits_from
+                              JOIN ontology_ids AS parent_ids
+                                ON parent_ids.ontology_id = target_entity_type_ontology_id
+                              WHERE source_entity_type_ontology_id
+                                    = entity_is_of_type.entity_type_ontology_id
+                                AND parent_ids.base_url = $3
+                          )
+                      )
+                    GROUP BY
+                        entity_temporal_metadata.web_id,
+                        entity_editions.properties #> $2::TEXT[]
+                    HAVING count(DISTINCT entity_temporal_metadata.entity_uuid) > 1
+                    LIMIT 1;
+                ",
+                &[
+                    &Timestamp::<DecisionTime>::now(),
+                    &path_elements(property_path),
+                    &entity_type_base_url.as_str(),
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        if let Some(duplicate) = duplicate {
+            let owned_by_id = duplicate.get(0);
+            let entity_uuids = duplicate.get::<_, Vec<EntityUuid>>(1);
+            bail!(
+                Report::new(PropertyIsNotUnique {
+                    entity_type_base_url: entity_type_base_url.clone(),
+                    property_path: property_path.clone().into_owned(),
+                    conflicting_entity_id: EntityId {
+                        owned_by_id,
+                        entity_uuid: entity_uuids[1],
+                        draft_id: None,
+                    },
+                })
+                .attach(StatusCode::AlreadyExists)
+                .attach_printable(EntityId {
+                    owned_by_id,
+                    entity_uuid: entity_uuids[0],
+                    draft_id: None,
+                })
+                .change_context(QueryError)
+            );
+        }
+
+        Ok(())
+    }
+}
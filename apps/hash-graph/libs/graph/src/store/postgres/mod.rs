@@ -1,3 +1,4 @@
+mod change;
 mod crud;
 mod knowledge;
 mod ontology;
@@ -7,6 +8,7 @@ mod migration;
 mod pool;
 pub(crate) mod query;
 mod traversal_context;
+mod webhook;
 
 use std::{fmt::Debug, sync::Arc};
 
@@ -54,6 +56,7 @@ use crate::store::{
         VersionedUrlAlreadyExists,
     },
     postgres::ontology::OntologyDatabaseType,
+    webhook::WebhookSecretKey,
     AccountStore, BaseUrlAlreadyExists, ConflictBehavior, InsertionError, QueryError, StoreError,
     UpdateError,
 };
@@ -63,6 +66,7 @@ pub struct PostgresStore<C, A> {
     client: C,
     pub authorization_api: A,
     pub temporal_client: Option<Arc<TemporalClient>>,
+    webhook_secret_key: Option<WebhookSecretKey>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -87,6 +91,7 @@ where
             client,
             authorization_api,
             temporal_client,
+            webhook_secret_key: None,
         }
     }
 
@@ -706,15 +711,17 @@ where
     pub async fn transaction(
         &mut self,
     ) -> Result<PostgresStore<tokio_postgres::Transaction<'_>, &'_ mut A>, StoreError> {
-        Ok(PostgresStore::new(
-            self.client
+        Ok(PostgresStore {
+            client: self
+                .client
                 .as_mut_client()
                 .transaction()
                 .await
                 .change_context(StoreError)?,
-            &mut self.authorization_api,
-            self.temporal_client.clone(),
-        ))
+            authorization_api: &mut self.authorization_api,
+            temporal_client: self.temporal_client.clone(),
+            webhook_secret_key: self.webhook_secret_key.clone(),
+        })
     }
 }
 
@@ -1141,6 +1148,11 @@ where
 {
     #[tracing::instrument(level = "trace", skip(self))]
     pub async fn delete_accounts(&mut self, actor_id: AccountId) -> Result<(), DeletionError> {
+        self.as_client()
+            .client()
+            .simple_query("DELETE FROM webhooks;")
+            .await
+            .change_context(DeletionError)?;
         self.as_client()
             .client()
             .simple_query("DELETE FROM webs;")
@@ -17,7 +17,7 @@ use graph_types::{
     Embedding,
 };
 use postgres_types::{Json, ToSql};
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use temporal_versioning::{Timestamp, TransactionTime};
 use tokio_postgres::{GenericClient, Row};
 use type_system::{
     url::{OntologyTypeVersion, VersionedUrl},
@@ -36,17 +36,20 @@ use crate::{
         },
         postgres::{
             crud::QueryRecordDecode,
-            ontology::{OntologyId, PostgresOntologyTypeClassificationMetadata},
-            query::{Distinctness, PostgresRecord, SelectCompiler, Table},
+            ontology::{
+                read::{OntologyTypeTraversalData, OntologyTypeTraversalQueues},
+                OntologyId, PostgresOntologyTypeClassificationMetadata,
+            },
+            query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
             TraversalContext,
         },
         AsClient, DataTypeStore, InsertionError, PostgresStore, QueryError, SubgraphRecord,
         UpdateError,
     },
     subgraph::{
-        edges::GraphResolveDepths,
-        identifier::GraphElementVertexId,
-        temporal_axes::{QueryTemporalAxes, VariableAxis},
+        edges::{EdgeDirection, OntologyEdgeKind},
+        identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
+        temporal_axes::QueryTemporalAxes,
         Subgraph,
     },
 };
@@ -158,23 +161,68 @@ where
 
     /// Internal method to read a [`DataTypeWithMetadata`] into a [`TraversalContext`].
     ///
-    /// This is used to recursively resolve a type, so the result can be reused.
-    #[tracing::instrument(level = "info", skip(self))]
+    /// This is used to recursively resolve a type, so the result can be reused. Property types
+    /// found by following incoming edges are added to the property type queue.
+    #[tracing::instrument(level = "info", skip(self, traversal_context, subgraph, zookie))]
     pub(crate) async fn traverse_data_types(
         &self,
-        queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
-        _: &mut TraversalContext,
+        queues: &mut OntologyTypeTraversalQueues,
+        traversal_context: &mut TraversalContext,
         actor_id: AccountId,
-        _: &Zookie<'static>,
-        _: &mut Subgraph,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
     ) -> Result<(), QueryError> {
-        // TODO: data types currently have no references to other types, so we don't need to do
-        //       anything here
-        //   see https://app.asana.com/0/1200211978612931/1202464168422955/f
+        let mut traversal_data = OntologyTypeTraversalData::default();
+
+        // Data types currently have no outgoing references to other types, so only incoming
+        // edges have to be followed.
+        for (data_type_ontology_id, graph_resolve_depths, traversal_interval) in
+            queues.data_types.drain(..)
+        {
+            if let Some(new_graph_resolve_depths) = graph_resolve_depths.decrement_depth_for_edge(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Incoming,
+            ) {
+                traversal_data.push(
+                    data_type_ontology_id,
+                    new_graph_resolve_depths,
+                    traversal_interval,
+                );
+            }
+        }
+
+        if traversal_data.is_empty() {
+            return Ok(());
+        }
+
+        queues.property_types.extend(
+            Self::filter_property_types_by_permission(
+                self.read_ontology_edges::<DataTypeVertexId, PropertyTypeVertexId>(
+                    &traversal_data,
+                    ReferenceTable::PropertyTypeConstrainsValuesOn,
+                    EdgeDirection::Incoming,
+                )
+                .await?,
+                actor_id,
+                &self.authorization_api,
+                zookie,
+            )
+            .await?
+            .flat_map(|edge| {
+                subgraph.insert_edge(
+                    &edge.left_endpoint,
+                    OntologyEdgeKind::ConstrainsValuesOn,
+                    EdgeDirection::Incoming,
+                    edge.right_endpoint.clone(),
+                );
+
+                traversal_context.add_property_type_id(
+                    edge.right_endpoint_ontology_id,
+                    edge.resolve_depths,
+                    edge.traversal_interval,
+                )
+            }),
+        );
 
         Ok(())
     }
@@ -419,17 +467,20 @@ where
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_data_types(
-            data_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse_ontology_types(
+            OntologyTypeTraversalQueues {
+                data_types: data_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..OntologyTypeTraversalQueues::default()
+            },
             &mut traversal_context,
             actor_id,
             &zookie,
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     iter::once,
 };
@@ -15,6 +16,7 @@ use error_stack::{ensure, Report, Result, ResultExt};
 use futures::TryStreamExt;
 use graph_types::{
     account::{AccountId, EditionArchivedById, EditionCreatedById},
+    knowledge::PropertyPath,
     ontology::{
         DataTypeId, EntityTypeId, EntityTypeMetadata, EntityTypeWithMetadata,
         OntologyEditionProvenance, OntologyProvenance, OntologyTemporalMetadata,
@@ -22,9 +24,10 @@ use graph_types::{
     },
     Embedding,
 };
+use hash_status::StatusCode;
 use postgres_types::{Json, ToSql};
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
-use tokio_postgres::{GenericClient, Row};
+use temporal_versioning::{Timestamp, TransactionTime};
+use tokio_postgres::{error::SqlState, GenericClient, Row};
 use type_system::{
     url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
     ClosedEntityType, EntityType,
@@ -37,15 +40,17 @@ use crate::{
         crud::{QueryResult, ReadPaginated, VertexIdSorting},
         error::DeletionError,
         ontology::{
-            ArchiveEntityTypeParams, CreateEntityTypeParams, GetEntityTypeSubgraphParams,
-            GetEntityTypeSubgraphResponse, GetEntityTypesParams, GetEntityTypesResponse,
-            UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            ArchiveEntityTypeParams, CreateEntityTypeParams, EntityTypeUniquenessConstraint,
+            GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
+            GetEntityTypeUniquenessConstraintsParams, GetEntityTypeUniquenessConstraintsResponse,
+            GetEntityTypesParams, GetEntityTypesResponse, UnarchiveEntityTypeParams,
+            UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
         },
         postgres::{
             crud::QueryRecordDecode,
             ontology::{
-                read::OntologyTypeTraversalData, OntologyId,
-                PostgresOntologyTypeClassificationMetadata,
+                read::{OntologyTypeTraversalData, OntologyTypeTraversalQueues},
+                OntologyId, PostgresOntologyTypeClassificationMetadata,
             },
             query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
             TraversalContext,
@@ -55,11 +60,11 @@ use crate::{
         UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
+        edges::{EdgeDirection, OntologyEdgeKind},
         identifier::{EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
-            VariableAxis, VariableTemporalAxisUnresolved,
+            VariableTemporalAxisUnresolved,
         },
         Subgraph,
     },
@@ -177,56 +182,66 @@ where
     #[tracing::instrument(level = "info", skip(self, traversal_context, subgraph, zookie))]
     pub(crate) async fn traverse_entity_types(
         &self,
-        mut entity_type_queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
+        queues: &mut OntologyTypeTraversalQueues,
         traversal_context: &mut TraversalContext,
         actor_id: AccountId,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
     ) -> Result<(), QueryError> {
-        let mut property_type_queue = Vec::new();
-
-        while !entity_type_queue.is_empty() {
+        while !queues.entity_types.is_empty() {
             let mut edges_to_traverse =
-                HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+                HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
 
-            #[expect(clippy::iter_with_drain, reason = "false positive, vector is reused")]
             for (entity_type_ontology_id, graph_resolve_depths, traversal_interval) in
-                entity_type_queue.drain(..)
+                queues.entity_types.drain(..)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::ConstrainsPropertiesOn,
-                    OntologyEdgeKind::InheritsFrom,
-                    OntologyEdgeKind::ConstrainsLinksOn,
-                    OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                for (edge_kind, edge_direction) in [
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Outgoing),
+                    (OntologyEdgeKind::InheritsFrom, EdgeDirection::Incoming),
+                    (OntologyEdgeKind::ConstrainsLinksOn, EdgeDirection::Outgoing),
+                    (OntologyEdgeKind::ConstrainsLinksOn, EdgeDirection::Incoming),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            entity_type_ontology_id,
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                entity_type_ontology_id,
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Outgoing,
+            )) {
                 // TODO: Filter for entity types, which were not already added to the
                 //       subgraph to avoid unnecessary lookups.
-                property_type_queue.extend(
+                queues.property_types.extend(
                     Self::filter_property_types_by_permission(
                         self.read_ontology_edges::<EntityTypeVertexId, PropertyTypeVertexId>(
                             traversal_data,
                             ReferenceTable::EntityTypeConstrainsPropertiesOn {
                                 inheritance_depth: None,
                             },
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -271,47 +286,43 @@ where
                     },
                 ),
             ] {
-                if let Some(traversal_data) = edges_to_traverse.get(&edge_kind) {
-                    entity_type_queue.extend(
-                        Self::filter_entity_types_by_permission(
-                            self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
-                                traversal_data,
-                                table,
-                            )
-                            .await?,
-                            actor_id,
-                            &self.authorization_api,
-                            zookie,
-                        )
-                        .await?
-                        .flat_map(|edge| {
-                            subgraph.insert_edge(
-                                &edge.left_endpoint,
-                                edge_kind,
-                                EdgeDirection::Outgoing,
-                                edge.right_endpoint.clone(),
-                            );
-
-                            traversal_context.add_entity_type_id(
-                                edge.right_endpoint_ontology_id,
-                                edge.resolve_depths,
-                                edge.traversal_interval,
+                for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                    if let Some(traversal_data) =
+                        edges_to_traverse.get(&(edge_kind, edge_direction))
+                    {
+                        queues.entity_types.extend(
+                            Self::filter_entity_types_by_permission(
+                                self.read_ontology_edges::<EntityTypeVertexId, EntityTypeVertexId>(
+                                    traversal_data,
+                                    table,
+                                    edge_direction,
+                                )
+                                .await?,
+                                actor_id,
+                                &self.authorization_api,
+                                zookie,
                             )
-                        }),
-                    );
+                            .await?
+                            .flat_map(|edge| {
+                                subgraph.insert_edge(
+                                    &edge.left_endpoint,
+                                    edge_kind,
+                                    edge_direction,
+                                    edge.right_endpoint.clone(),
+                                );
+
+                                traversal_context.add_entity_type_id(
+                                    edge.right_endpoint_ontology_id,
+                                    edge.resolve_depths,
+                                    edge.traversal_interval,
+                                )
+                            }),
+                        );
+                    }
                 }
             }
         }
 
-        self.traverse_property_types(
-            property_type_queue,
-            traversal_context,
-            actor_id,
-            zookie,
-            subgraph,
-        )
-        .await?;
-
         Ok(())
     }
 
@@ -324,6 +335,7 @@ where
             .simple_query(
                 "
                     DELETE FROM entity_type_embeddings;
+                    DELETE FROM entity_type_uniqueness_constraints;
                     DELETE FROM entity_type_inherits_from;
                     DELETE FROM entity_type_constrains_link_destinations_on;
                     DELETE FROM entity_type_constrains_links_on;
@@ -431,7 +443,7 @@ where
             .read_closed_schemas(
                 &Filter::In(
                     FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                    ParameterList::Uuid(&parent_entity_type_ids),
+                    ParameterList::Uuid(Cow::Borrowed(&parent_entity_type_ids)),
                 ),
                 Some(
                     &QueryTemporalAxesUnresolved::DecisionTime {
@@ -731,17 +743,20 @@ where
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_entity_types(
-            entity_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse_ontology_types(
+            OntologyTypeTraversalQueues {
+                entity_types: entity_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..OntologyTypeTraversalQueues::default()
+            },
             &mut traversal_context,
             actor_id,
             &zookie,
@@ -1016,6 +1031,141 @@ where
 
         Ok(())
     }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn add_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), InsertionError> {
+        self.authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::Update,
+                EntityTypeId::from_url(&params.entity_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?
+            .assert_permission()
+            .change_context(InsertionError)?;
+
+        let transaction = self.transaction().await.change_context(InsertionError)?;
+
+        transaction
+            .as_client()
+            .query(
+                "
+                    INSERT INTO entity_type_uniqueness_constraints (
+                        entity_type_base_url,
+                        property_path
+                    ) VALUES ($1, $2);
+                ",
+                &[
+                    &params.entity_type_id.base_url.as_str(),
+                    &params.property_path,
+                ],
+            )
+            .await
+            .map_err(Report::new)
+            .map_err(|report| match report.current_context().code() {
+                Some(&SqlState::UNIQUE_VIOLATION) => report
+                    .attach(StatusCode::AlreadyExists)
+                    .change_context(InsertionError),
+                _ => report.change_context(InsertionError),
+            })?;
+
+        transaction
+            .ensure_uniqueness_constraint_holds(
+                &params.entity_type_id.base_url,
+                &params.property_path,
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        transaction.commit().await.change_context(InsertionError)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_type_uniqueness_constraints(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityTypeUniquenessConstraintsParams,
+    ) -> Result<GetEntityTypeUniquenessConstraintsResponse, QueryError> {
+        self.authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::View,
+                EntityTypeId::from_url(&params.entity_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        Ok(GetEntityTypeUniquenessConstraintsResponse {
+            property_paths: self
+                .as_client()
+                .query(
+                    "
+                        SELECT property_path
+                        FROM entity_type_uniqueness_constraints
+                        WHERE entity_type_base_url = $1
+                        ORDER BY property_path;
+                    ",
+                    &[&params.entity_type_id.base_url.as_str()],
+                )
+                .await
+                .change_context(QueryError)?
+                .into_iter()
+                .map(|row| row.get::<_, PropertyPath>(0).into_owned())
+                .collect(),
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn remove_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), DeletionError> {
+        self.authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::Update,
+                EntityTypeId::from_url(&params.entity_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(DeletionError)?
+            .assert_permission()
+            .change_context(DeletionError)?;
+
+        let deleted = self
+            .as_client()
+            .execute(
+                "
+                    DELETE FROM entity_type_uniqueness_constraints
+                    WHERE entity_type_base_url = $1
+                      AND property_path = $2;
+                ",
+                &[
+                    &params.entity_type_id.base_url.as_str(),
+                    &params.property_path,
+                ],
+            )
+            .await
+            .change_context(DeletionError)?;
+        ensure!(
+            deleted > 0,
+            Report::new(DeletionError)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.property_path.to_json_pointer())
+        );
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -20,6 +20,7 @@ use tokio_postgres::{Row, Transaction};
 use type_system::{url::BaseUrl, DataType, EntityType, PropertyType};
 
 pub use self::ontology_id::OntologyId;
+pub(crate) use self::read::OntologyTypeTraversalQueues;
 use crate::{
     ontology::{DataTypeQueryPath, EntityTypeQueryPath, PropertyTypeQueryPath},
     store::{
@@ -23,7 +23,7 @@ use graph_types::{
     Embedding,
 };
 use postgres_types::{Json, ToSql};
-use temporal_versioning::{RightBoundedTemporalInterval, Timestamp, TransactionTime};
+use temporal_versioning::{Timestamp, TransactionTime};
 use tokio_postgres::{GenericClient, Row};
 use type_system::{
     url::{OntologyTypeVersion, VersionedUrl},
@@ -44,8 +44,8 @@ use crate::{
         postgres::{
             crud::QueryRecordDecode,
             ontology::{
-                read::OntologyTypeTraversalData, OntologyId,
-                PostgresOntologyTypeClassificationMetadata,
+                read::{OntologyTypeTraversalData, OntologyTypeTraversalQueues},
+                OntologyId, PostgresOntologyTypeClassificationMetadata,
             },
             query::{Distinctness, PostgresRecord, ReferenceTable, SelectCompiler, Table},
             TraversalContext,
@@ -54,9 +54,11 @@ use crate::{
         UpdateError,
     },
     subgraph::{
-        edges::{EdgeDirection, GraphResolveDepths, OntologyEdgeKind},
-        identifier::{DataTypeVertexId, GraphElementVertexId, PropertyTypeVertexId},
-        temporal_axes::{QueryTemporalAxes, VariableAxis},
+        edges::{EdgeDirection, OntologyEdgeKind},
+        identifier::{
+            DataTypeVertexId, EntityTypeVertexId, GraphElementVertexId, PropertyTypeVertexId,
+        },
+        temporal_axes::QueryTemporalAxes,
         Subgraph,
     },
 };
@@ -170,54 +172,65 @@ where
 
     /// Internal method to read a [`PropertyTypeWithMetadata`] into two [`TraversalContext`]s.
     ///
-    /// This is used to recursively resolve a type, so the result can be reused.
+    /// This is used to recursively resolve a type, so the result can be reused. Data types and
+    /// entity types found while traversing are added to their respective queues.
     #[tracing::instrument(level = "info", skip(self, traversal_context, subgraph, zookie))]
     pub(crate) async fn traverse_property_types(
         &self,
-        mut property_type_queue: Vec<(
-            OntologyId,
-            GraphResolveDepths,
-            RightBoundedTemporalInterval<VariableAxis>,
-        )>,
+        queues: &mut OntologyTypeTraversalQueues,
         traversal_context: &mut TraversalContext,
         actor_id: AccountId,
         zookie: &Zookie<'static>,
         subgraph: &mut Subgraph,
     ) -> Result<(), QueryError> {
-        let mut data_type_queue = Vec::new();
-        let mut edges_to_traverse = HashMap::<OntologyEdgeKind, OntologyTypeTraversalData>::new();
+        let mut edges_to_traverse =
+            HashMap::<(OntologyEdgeKind, EdgeDirection), OntologyTypeTraversalData>::new();
 
-        while !property_type_queue.is_empty() {
+        while !queues.property_types.is_empty() {
             edges_to_traverse.clear();
 
-            #[expect(clippy::iter_with_drain, reason = "false positive, vector is reused")]
             for (property_type_ontology_id, graph_resolve_depths, traversal_interval) in
-                property_type_queue.drain(..)
+                queues.property_types.drain(..)
             {
-                for edge_kind in [
-                    OntologyEdgeKind::ConstrainsValuesOn,
-                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                for (edge_kind, edge_direction) in [
+                    (
+                        OntologyEdgeKind::ConstrainsValuesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsPropertiesOn,
+                        EdgeDirection::Incoming,
+                    ),
                 ] {
-                    if let Some(new_graph_resolve_depths) = graph_resolve_depths
-                        .decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    if let Some(new_graph_resolve_depths) =
+                        graph_resolve_depths.decrement_depth_for_edge(edge_kind, edge_direction)
                     {
-                        edges_to_traverse.entry(edge_kind).or_default().push(
-                            property_type_ontology_id,
-                            new_graph_resolve_depths,
-                            traversal_interval,
-                        );
+                        edges_to_traverse
+                            .entry((edge_kind, edge_direction))
+                            .or_default()
+                            .push(
+                                property_type_ontology_id,
+                                new_graph_resolve_depths,
+                                traversal_interval,
+                            );
                     }
                 }
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsValuesOn)
-            {
-                data_type_queue.extend(
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsValuesOn,
+                EdgeDirection::Outgoing,
+            )) {
+                queues.data_types.extend(
                     Self::filter_data_types_by_permission(
                         self.read_ontology_edges::<PropertyTypeVertexId, DataTypeVertexId>(
                             traversal_data,
                             ReferenceTable::PropertyTypeConstrainsValuesOn,
+                            EdgeDirection::Outgoing,
                         )
                         .await?,
                         actor_id,
@@ -242,14 +255,53 @@ where
                 );
             }
 
-            if let Some(traversal_data) =
-                edges_to_traverse.get(&OntologyEdgeKind::ConstrainsPropertiesOn)
-            {
-                property_type_queue.extend(
-                    Self::filter_property_types_by_permission(
-                        self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+            for edge_direction in [EdgeDirection::Outgoing, EdgeDirection::Incoming] {
+                if let Some(traversal_data) = edges_to_traverse
+                    .get(&(OntologyEdgeKind::ConstrainsPropertiesOn, edge_direction))
+                {
+                    queues.property_types.extend(
+                        Self::filter_property_types_by_permission(
+                            self.read_ontology_edges::<PropertyTypeVertexId, PropertyTypeVertexId>(
+                                traversal_data,
+                                ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                                edge_direction,
+                            )
+                            .await?,
+                            actor_id,
+                            &self.authorization_api,
+                            zookie,
+                        )
+                        .await?
+                        .flat_map(|edge| {
+                            subgraph.insert_edge(
+                                &edge.left_endpoint,
+                                OntologyEdgeKind::ConstrainsPropertiesOn,
+                                edge_direction,
+                                edge.right_endpoint.clone(),
+                            );
+
+                            traversal_context.add_property_type_id(
+                                edge.right_endpoint_ontology_id,
+                                edge.resolve_depths,
+                                edge.traversal_interval,
+                            )
+                        }),
+                    );
+                }
+            }
+
+            if let Some(traversal_data) = edges_to_traverse.get(&(
+                OntologyEdgeKind::ConstrainsPropertiesOn,
+                EdgeDirection::Incoming,
+            )) {
+                queues.entity_types.extend(
+                    Self::filter_entity_types_by_permission(
+                        self.read_ontology_edges::<PropertyTypeVertexId, EntityTypeVertexId>(
                             traversal_data,
-                            ReferenceTable::PropertyTypeConstrainsPropertiesOn,
+                            ReferenceTable::EntityTypeConstrainsPropertiesOn {
+                                inheritance_depth: None,
+                            },
+                            EdgeDirection::Incoming,
                         )
                         .await?,
                         actor_id,
@@ -261,29 +313,20 @@ where
                         subgraph.insert_edge(
                             &edge.left_endpoint,
                             OntologyEdgeKind::ConstrainsPropertiesOn,
-                            EdgeDirection::Outgoing,
+                            EdgeDirection::Incoming,
                             edge.right_endpoint.clone(),
                         );
 
-                        traversal_context.add_property_type_id(
+                        traversal_context.add_entity_type_id(
                             edge.right_endpoint_ontology_id,
                             edge.resolve_depths,
                             edge.traversal_interval,
                         )
                     }),
                 );
-            };
+            }
         }
 
-        self.traverse_data_types(
-            data_type_queue,
-            traversal_context,
-            actor_id,
-            zookie,
-            subgraph,
-        )
-        .await?;
-
         Ok(())
     }
 
@@ -546,17 +589,20 @@ where
 
         // TODO: We currently pass in the subgraph as mutable reference, thus we cannot borrow the
         //       vertices and have to `.collect()` the keys.
-        self.traverse_property_types(
-            property_type_ids
-                .into_iter()
-                .map(|id| {
-                    (
-                        OntologyId::from(id),
-                        subgraph.depths,
-                        subgraph.temporal_axes.resolved.variable_interval(),
-                    )
-                })
-                .collect(),
+        self.traverse_ontology_types(
+            OntologyTypeTraversalQueues {
+                property_types: property_type_ids
+                    .into_iter()
+                    .map(|id| {
+                        (
+                            OntologyId::from(id),
+                            subgraph.depths,
+                            subgraph.temporal_axes.resolved.variable_interval(),
+                        )
+                    })
+                    .collect(),
+                ..OntologyTypeTraversalQueues::default()
+            },
             &mut traversal_context,
             actor_id,
             &zookie,
@@ -1,8 +1,12 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, mem::swap};
 
+use authorization::{zanzibar::Zookie, AuthorizationApi};
 use error_stack::{Result, ResultExt};
 use futures::{Stream, StreamExt};
-use graph_types::ontology::{EntityTypeId, EntityTypeWithMetadata};
+use graph_types::{
+    account::AccountId,
+    ontology::{EntityTypeId, EntityTypeWithMetadata},
+};
 use postgres_types::Json;
 use temporal_versioning::RightBoundedTemporalInterval;
 use tokio_postgres::GenericClient;
@@ -17,13 +21,15 @@ use crate::{
                 Column, Distinctness, ForeignKeyReference, ReferenceTable, SelectCompiler, Table,
                 Transpile,
             },
+            TraversalContext,
         },
         query::Filter,
         AsClient, PostgresStore, QueryError,
     },
     subgraph::{
-        edges::GraphResolveDepths,
+        edges::{EdgeDirection, GraphResolveDepths},
         temporal_axes::{QueryTemporalAxes, VariableAxis},
+        Subgraph,
     },
 };
 
@@ -45,6 +51,45 @@ impl OntologyTypeTraversalData {
         self.resolve_depths.push(resolve_depth);
         self.traversal_intervals.push(traversal_interval);
     }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ontology_ids.is_empty()
+    }
+}
+
+/// The ontology types which are left to be traversed, grouped by their kind.
+///
+/// Edges can be followed in both directions, so traversing one kind of type may add types of any
+/// other kind to the queues.
+#[derive(Debug, Default)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "the fields are named after the kind of type they contain"
+)]
+pub struct OntologyTypeTraversalQueues {
+    pub data_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+    pub property_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+    pub entity_types: Vec<(
+        OntologyId,
+        GraphResolveDepths,
+        RightBoundedTemporalInterval<VariableAxis>,
+    )>,
+}
+
+impl OntologyTypeTraversalQueues {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data_types.is_empty() && self.property_types.is_empty() && self.entity_types.is_empty()
+    }
 }
 
 pub struct OntologyEdgeTraversal<L, R> {
@@ -93,25 +138,30 @@ impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
         &self,
         record_ids: &'r OntologyTypeTraversalData,
         reference_table: ReferenceTable,
+        edge_direction: EdgeDirection,
     ) -> Result<impl Iterator<Item = (OntologyId, OntologyEdgeTraversal<L, R>)> + 'r, QueryError>
     where
         L: From<VersionedUrl>,
         R: From<VersionedUrl>,
     {
         let table = Table::Reference(reference_table).transpile_to_string();
-        let source =
+        let mut source =
             if let ForeignKeyReference::Single { join, .. } = reference_table.source_relation() {
                 join.to_expression(None).transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
-        let target =
+        let mut target =
             if let ForeignKeyReference::Single { on, .. } = reference_table.target_relation() {
                 on.to_expression(None).transpile_to_string()
             } else {
                 unreachable!("Ontology reference tables don't have multiple conditions")
             };
 
+        if edge_direction == EdgeDirection::Incoming {
+            swap(&mut source, &mut target);
+        }
+
         let depth = reference_table
             .inheritance_depth_column()
             .and_then(Column::inheritance_depth);
@@ -182,3 +232,45 @@ impl<C: AsClient, A: Send + Sync> PostgresStore<C, A> {
             }))
     }
 }
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Traverses the ontology types in the queues until no further edges can be followed.
+    ///
+    /// Every kind of type is traversed in turn. As incoming edges point from data types to
+    /// property types and from property types to entity types, this is repeated until all queues
+    /// are drained.
+    #[tracing::instrument(level = "info", skip(self, traversal_context, subgraph, zookie))]
+    pub(crate) async fn traverse_ontology_types(
+        &self,
+        mut queues: OntologyTypeTraversalQueues,
+        traversal_context: &mut TraversalContext,
+        actor_id: AccountId,
+        zookie: &Zookie<'static>,
+        subgraph: &mut Subgraph,
+    ) -> Result<(), QueryError> {
+        while !queues.is_empty() {
+            if traversal_context.budget_exhausted(subgraph)? {
+                break;
+            }
+
+            self.traverse_entity_types(&mut queues, traversal_context, actor_id, zookie, subgraph)
+                .await?;
+            self.traverse_property_types(
+                &mut queues,
+                traversal_context,
+                actor_id,
+                zookie,
+                subgraph,
+            )
+            .await?;
+            self.traverse_data_types(&mut queues, traversal_context, actor_id, zookie, subgraph)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
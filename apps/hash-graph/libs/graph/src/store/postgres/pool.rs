@@ -13,7 +13,9 @@ use tokio_postgres::{
     Client, Config, Error, GenericClient, Socket, Transaction,
 };
 
-use crate::store::{DatabaseConnectionInfo, PostgresStore, StoreError, StorePool};
+use crate::store::{
+    webhook::WebhookSecretKey, DatabaseConnectionInfo, PostgresStore, StoreError, StorePool,
+};
 
 pub struct PostgresStorePool<Tls>
 where
@@ -21,6 +23,7 @@ where
     PostgresConnectionManager<Tls>: ManageConnection,
 {
     pool: Pool<PostgresConnectionManager<Tls>>,
+    webhook_secret_key: Option<WebhookSecretKey>,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -66,8 +69,18 @@ where
                 .await
                 .change_context(StoreError)
                 .attach_printable_lazy(|| db_info.clone())?,
+            webhook_secret_key: None,
         })
     }
+
+    /// Sets the key used to encrypt and decrypt the secrets of webhooks.
+    ///
+    /// Without a key, webhooks can neither be created nor dispatched.
+    #[must_use]
+    pub const fn with_webhook_secret_key(mut self, webhook_secret_key: WebhookSecretKey) -> Self {
+        self.webhook_secret_key = Some(webhook_secret_key);
+        self
+    }
 }
 
 #[async_trait]
@@ -88,11 +101,12 @@ where
         authorization_api: A,
         temporal_client: Option<Arc<TemporalClient>>,
     ) -> Result<Self::Store<'_, A>, Self::Error> {
-        Ok(PostgresStore::new(
-            self.pool.get().await?,
-            authorization_api,
-            temporal_client,
-        ))
+        let mut store =
+            PostgresStore::new(self.pool.get().await?, authorization_api, temporal_client);
+        store
+            .webhook_secret_key
+            .clone_from(&self.webhook_secret_key);
+        Ok(store)
     }
 
     async fn acquire_owned<A: AuthorizationApi>(
@@ -100,11 +114,15 @@ where
         authorization_api: A,
         temporal_client: Option<Arc<TemporalClient>>,
     ) -> Result<Self::Store<'static, A>, Self::Error> {
-        Ok(PostgresStore::new(
+        let mut store = PostgresStore::new(
             self.pool.get_owned().await?,
             authorization_api,
             temporal_client,
-        ))
+        );
+        store
+            .webhook_secret_key
+            .clone_from(&self.webhook_secret_key);
+        Ok(store)
     }
 }
 
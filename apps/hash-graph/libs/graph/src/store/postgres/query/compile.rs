@@ -13,17 +13,17 @@ use crate::{
             expression::{GroupByExpression, PostgresType},
             statement::FromItem,
             table::{
-                DataTypeEmbeddings, DatabaseColumn, EntityEmbeddings, EntityTemporalMetadata,
-                EntityTypeEmbeddings, JsonField, OntologyIds, OntologyTemporalMetadata,
-                PropertyTypeEmbeddings,
+                DataTypeEmbeddings, DatabaseColumn, EntityEmbeddings, EntityHasLeftEntity,
+                EntityHasRightEntity, EntityIds, EntityTemporalMetadata, EntityTypeEmbeddings,
+                JsonField, OntologyIds, OntologyTemporalMetadata, PropertyTypeEmbeddings,
             },
-            Alias, AliasedTable, Column, Condition, Distinctness, EqualityOperator, Expression,
-            Function, JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord,
-            SelectExpression, SelectStatement, Table, Transpile, WhereExpression, WindowStatement,
-            WithExpression,
+            Alias, AliasedTable, Column, Condition, Constant, Distinctness, EqualityOperator,
+            Expression, Function, JoinExpression, OrderByExpression, PostgresQueryPath,
+            PostgresRecord, SelectExpression, SelectStatement, Table, Transpile, WhereExpression,
+            WindowStatement, WithExpression,
         },
         query::{Filter, FilterExpression, Parameter, ParameterList, ParameterType, PathToken},
-        NullOrdering, Ordering, QueryRecord,
+        AggregationFunction, NullOrdering, Ordering, QueryRecord,
     },
     subgraph::temporal_axes::QueryTemporalAxes,
 };
@@ -383,8 +383,63 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
     }
 
     /// Compiles a [`Filter`] to a `Condition`.
-    #[expect(clippy::too_many_lines)]
     pub fn compile_filter(&mut self, filter: &'p Filter<'q, R>) -> Condition
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let num_joins = self.statement.joins.len();
+        let condition = self.compile_filter_condition(filter);
+        if matches!(filter, Filter::All(_) | Filter::Any(_) | Filter::Not(_)) {
+            return condition;
+        }
+
+        // Tables with more than one row per record are not joined as this would return the record
+        // multiple times. Instead, the condition is moved into a semi-join.
+        let Some(position) = self.statement.joins[num_joins..]
+            .iter()
+            .position(|join| join.table.table == Table::EntityPropertySources)
+        else {
+            return condition;
+        };
+        let mut joins = self.statement.joins.split_off(num_joins + position);
+        let semi_join = joins.remove(0);
+
+        let mut where_expression = WhereExpression::default();
+        for on in semi_join.on {
+            where_expression.add_condition(Condition::Equal(
+                Some(Expression::ColumnReference {
+                    column: on.join,
+                    table_alias: Some(semi_join.table.alias),
+                }),
+                Some(Expression::ColumnReference {
+                    column: on.on,
+                    table_alias: Some(semi_join.on_alias),
+                }),
+            ));
+        }
+        where_expression.add_condition(condition);
+
+        Condition::Exists(Box::new(SelectStatement {
+            with: WithExpression::default(),
+            distinct: Vec::new(),
+            selects: vec![SelectExpression::new(
+                Expression::Constant(Constant::UnsignedInteger(1)),
+                None,
+            )],
+            from: FromItem::Table {
+                table: semi_join.table.table,
+                alias: Some(semi_join.table.alias),
+            },
+            joins,
+            where_expression,
+            order_by_expression: OrderByExpression::default(),
+            group_by_expression: GroupByExpression::default(),
+            limit: None,
+        }))
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn compile_filter_condition(&mut self, filter: &'p Filter<'q, R>) -> Condition
     where
         R::QueryPath<'q>: PostgresQueryPath,
     {
@@ -570,62 +625,250 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                      `parameter` expression."
                 ),
             },
+            Filter::In(FilterExpression::Path(path), rhs @ ParameterList::EntityIds(_)) => {
+                let Expression::ColumnReference {
+                    column: entity_uuid_column,
+                    table_alias,
+                } = self.compile_path_column(path)
+                else {
+                    unreachable!(
+                        "Entity IDs can only be compared to the UUID of an entity, other paths \
+                         are rejected when converting the parameters"
+                    );
+                };
+                let web_id_column = match entity_uuid_column {
+                    Column::EntityTemporalMetadata(EntityTemporalMetadata::EntityUuid) => {
+                        Column::EntityTemporalMetadata(EntityTemporalMetadata::WebId)
+                    }
+                    Column::EntityIds(EntityIds::EntityUuid) => Column::EntityIds(EntityIds::WebId),
+                    Column::EntityHasLeftEntity(EntityHasLeftEntity::LeftEntityUuid) => {
+                        Column::EntityHasLeftEntity(EntityHasLeftEntity::LeftEntityWebId)
+                    }
+                    Column::EntityHasRightEntity(EntityHasRightEntity::RightEntityUuid) => {
+                        Column::EntityHasRightEntity(EntityHasRightEntity::RightEntityWebId)
+                    }
+                    _ => unreachable!(
+                        "Entity IDs can only be compared to the UUID of an entity, other paths \
+                         are rejected when converting the parameters"
+                    ),
+                };
+
+                Condition::In(
+                    Expression::Cast(
+                        Box::new(Expression::Row(vec![
+                            Expression::ColumnReference {
+                                column: web_id_column,
+                                table_alias,
+                            },
+                            Expression::ColumnReference {
+                                column: entity_uuid_column,
+                                table_alias,
+                            },
+                        ])),
+                        PostgresType::EntityId,
+                    ),
+                    self.compile_parameter_list(rhs).0,
+                )
+            }
             Filter::In(lhs, rhs) => Condition::In(
                 self.compile_filter_expression(lhs).0,
                 self.compile_parameter_list(rhs).0,
             ),
-            Filter::StartsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::StartsWithCaseInsensitive(lhs, rhs) => Condition::StartsWithCaseInsensitive(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::EndsWithCaseInsensitive(lhs, rhs) => Condition::EndsWithCaseInsensitive(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::ContainsSegmentCaseInsensitive(lhs, rhs) => {
+                Condition::ContainsSegmentCaseInsensitive(
+                    self.compile_text_expression(lhs),
+                    self.compile_text_expression(rhs),
+                )
+            }
+            Filter::Regex(lhs, rhs) => Condition::Regex(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::RegexCaseSensitive(lhs, rhs) => Condition::RegexCaseSensitive(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::Matches(lhs, rhs) => {
+                let (document, document_type) = self.compile_filter_expression(lhs);
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+                let query = self.compile_text_expression(rhs);
 
-                Condition::StartsWith(left_filter, right_filter)
+                Condition::Matches(
+                    Self::compile_search_document(document, &document_type),
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(query))),
+                )
             }
-            Filter::EndsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
+        }
+    }
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+    /// Compiles `expression` so it can be used in a text comparison.
+    ///
+    /// JSON values are extracted as text, e.g. a JSON string is compared without its quotes.
+    fn compile_text_expression(&mut self, expression: &'p FilterExpression<'q, R>) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (expression, parameter_type) = self.compile_filter_expression(expression);
+        if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(expression)))
+        } else {
+            expression
+        }
+    }
 
-                Condition::EndsWith(left_filter, right_filter)
-            }
-            Filter::ContainsSegment(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
+    /// Converts `document` to a `tsvector` to be used in a full-text search.
+    ///
+    /// JSON documents only take string values into account. Keys and other values are ignored.
+    fn compile_search_document(document: Expression, document_type: &ParameterType) -> Expression {
+        if *document_type == ParameterType::Any {
+            Expression::Function(Function::JsonToTsVector(Box::new(document)))
+        } else {
+            Expression::Function(Function::ToTsVector(Box::new(document)))
+        }
+    }
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+    /// Adds the full-text search rank of `path` for `query` to the selection.
+    ///
+    /// The rank is added in front of the already added orderings, so the most relevant records
+    /// are returned first. If `cursor` is provided, only records ranked after the cursor are
+    /// returned.
+    pub fn add_search_rank_selection(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        query: &'p (dyn ToSql + Sync),
+        cursor: Option<&'p (dyn ToSql + Sync)>,
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (document, document_type) = self.compile_path_expression(path);
+        let query = self.add_parameter(query);
+        let rank = Expression::Cast(
+            Box::new(Expression::Function(Function::TsRank(
+                Box::new(Self::compile_search_document(document, &document_type)),
+                Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                    Box::new(query),
+                ))),
+            ))),
+            PostgresType::Float8,
+        );
 
-                Condition::ContainsSegment(left_filter, right_filter)
-            }
+        if let Some(cursor) = cursor {
+            let cursor = self.add_parameter(cursor);
+            self.statement.where_expression.add_cursor(
+                rank.clone(),
+                Some(cursor),
+                Ordering::Descending,
+                None,
+            );
+            self.artifacts.uses_cursor = true;
         }
+
+        self.statement
+            .selects
+            .push(SelectExpression::new(rank.clone(), None));
+        self.statement.distinct.push(rank.clone());
+        self.statement
+            .order_by_expression
+            .push(rank, Ordering::Descending, None);
+        self.statement.selects.len() - 1
+    }
+
+    /// Groups the records by the value of `path` and adds the value as JSON to the selection.
+    ///
+    /// The groups are ordered by their values.
+    pub fn add_grouping(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.statement
+            .group_by_expression
+            .expressions
+            .push(expression.clone());
+        self.statement
+            .order_by_expression
+            .push(expression.clone(), Ordering::Ascending, None);
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ToJson(Box::new(expression))),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
+    /// Adds the result of `function` over the values of `path` in each group as JSON to the
+    /// selection.
+    ///
+    /// JSON values, e.g. properties, are only taken into account if they are numbers. Only
+    /// [`AggregationFunction::Count`] may be used without a `path`, in which case all records of
+    /// a group are counted.
+    ///
+    /// The aggregation runs over the joined rows, so filters joining a table with multiple rows
+    /// per record cause a record to be taken into account multiple times.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `path` is `None` and `function` is not
+    /// [`AggregationFunction::Count`].
+    pub fn add_aggregation(
+        &mut self,
+        function: AggregationFunction,
+        path: Option<&'p R::QueryPath<'q>>,
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let value = path.map_or_else(
+            || {
+                assert_eq!(
+                    function,
+                    AggregationFunction::Count,
+                    "Only counting is supported without a path"
+                );
+                Expression::Asterisk
+            },
+            |path| {
+                let (expression, parameter_type) = self.compile_path_expression(path);
+                if parameter_type == ParameterType::Any && function != AggregationFunction::Count {
+                    Expression::Function(Function::JsonNumber(Box::new(expression)))
+                } else {
+                    expression
+                }
+            },
+        );
+
+        let aggregate = match function {
+            AggregationFunction::Count => Function::Count(Box::new(value)),
+            AggregationFunction::Sum => Function::Sum(Box::new(value)),
+            AggregationFunction::Average => Function::Avg(Box::new(value)),
+            AggregationFunction::Minimum => Function::Min(Box::new(value)),
+            AggregationFunction::Maximum => Function::Max(Box::new(value)),
+        };
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ToJson(Box::new(Expression::Function(aggregate)))),
+            None,
+        ));
+        self.statement.selects.len() - 1
     }
 
     /// Compiles the `path` to a condition, which is searching for the latest version.
@@ -868,32 +1111,32 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         R::QueryPath<'q>: PostgresQueryPath,
     {
         match expression {
-            FilterExpression::Path(path) => {
-                let (column, json_field) = path.terminating_column();
-                let parameter_type = if let Some(JsonField::StaticText(_)) = json_field {
-                    ParameterType::Text
-                } else {
-                    column.parameter_type()
-                };
-                (self.compile_path_column(path), parameter_type)
-            }
+            FilterExpression::Path(path) => self.compile_path_expression(path),
             FilterExpression::Parameter(parameter) => self.compile_parameter(parameter),
         }
     }
 
+    fn compile_path_expression(&mut self, path: &'p R::QueryPath<'q>) -> (Expression, ParameterType)
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (column, json_field) = path.terminating_column();
+        let parameter_type = if let Some(JsonField::StaticText(_)) = json_field {
+            ParameterType::Text
+        } else {
+            column.parameter_type()
+        };
+        (self.compile_path_column(path), parameter_type)
+    }
+
     pub fn compile_parameter_list<'f: 'p>(
         &mut self,
         parameters: &'p ParameterList<'f>,
     ) -> (Expression, ParameterType) {
-        let parameter_type = match parameters {
-            ParameterList::Uuid(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
-            }
-        };
+        self.artifacts.parameters.push(parameters);
         (
             Expression::Parameter(self.artifacts.parameters.len()),
-            parameter_type,
+            parameters.element_type(),
         )
     }
 
@@ -1,6 +1,6 @@
 use std::{fmt, fmt::Write};
 
-use crate::store::postgres::query::{Expression, Transpile};
+use crate::store::postgres::query::{Expression, SelectStatement, Transpile};
 
 /// A [`Filter`], which can be transpiled.
 ///
@@ -22,6 +22,13 @@ pub enum Condition {
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    StartsWithCaseInsensitive(Expression, Expression),
+    EndsWithCaseInsensitive(Expression, Expression),
+    ContainsSegmentCaseInsensitive(Expression, Expression),
+    Regex(Expression, Expression),
+    RegexCaseSensitive(Expression, Expression),
+    Matches(Expression, Expression),
+    Exists(Box<SelectStatement>),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -141,6 +148,43 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::StartsWithCaseInsensitive(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ILIKE ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str(" || '%'")
+            }
+            Self::EndsWithCaseInsensitive(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ILIKE '%' || ")?;
+                rhs.transpile(fmt)
+            }
+            Self::ContainsSegmentCaseInsensitive(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ILIKE '%' || ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_str(" || '%'")
+            }
+            Self::Regex(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ~* ")?;
+                rhs.transpile(fmt)
+            }
+            Self::RegexCaseSensitive(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ~ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Matches(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Exists(select) => {
+                fmt.write_str("EXISTS (")?;
+                select.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -156,7 +200,7 @@ mod tests {
         ontology::DataTypeQueryPath,
         store::{
             postgres::query::{SelectCompiler, Transpile},
-            query::{Filter, FilterExpression, Parameter},
+            query::{Filter, FilterExpression, Parameter, ParameterList},
         },
     };
 
@@ -315,6 +359,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_matches_condition() {
+        test_condition(
+            &Filter::Matches(
+                FilterExpression::Path(DataTypeQueryPath::Description),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("plain text"))),
+            ),
+            r#"to_tsvector('english', "data_types_0_1_0"."schema"->>'description') @@ websearch_to_tsquery('english', $1)"#,
+            &[&"plain text"],
+        );
+
+        test_condition(
+            &Filter::Matches(
+                FilterExpression::Path(DataTypeQueryPath::Schema(None)),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("plain text"))),
+            ),
+            r#"jsonb_to_tsvector('english', "data_types_0_1_0"."schema", '["string"]') @@ websearch_to_tsquery('english', $1)"#,
+            &[&"plain text"],
+        );
+    }
+
+    #[test]
+    fn transpile_case_insensitive_conditions() {
+        test_condition(
+            &Filter::StartsWithCaseInsensitive(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("tex"))),
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ILIKE $1 || '%'"#,
+            &[&"tex"],
+        );
+
+        test_condition(
+            &Filter::EndsWithCaseInsensitive(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("EXT"))),
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ILIKE '%' || $1"#,
+            &[&"EXT"],
+        );
+
+        test_condition(
+            &Filter::ContainsSegmentCaseInsensitive(
+                FilterExpression::Path(DataTypeQueryPath::Description),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("eX"))),
+            ),
+            r#""data_types_0_1_0"."schema"->>'description' ILIKE '%' || $1 || '%'"#,
+            &[&"eX"],
+        );
+    }
+
+    #[test]
+    fn transpile_regex_condition() {
+        test_condition(
+            &Filter::Regex(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("^te?xt$"))),
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ~* $1"#,
+            &[&"^te?xt$"],
+        );
+
+        test_condition(
+            &Filter::RegexCaseSensitive(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("^Te?xt$"))),
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' ~ $1"#,
+            &[&"^Te?xt$"],
+        );
+    }
+
+    #[test]
+    fn transpile_in_condition() {
+        let titles = ParameterList::Text(Cow::Owned(vec!["Text".to_owned(), "Number".to_owned()]));
+        test_condition(
+            &Filter::In(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                titles.clone(),
+            ),
+            r#""data_types_0_1_0"."schema"->>'title' = ANY($1)"#,
+            &[&titles],
+        );
+    }
+
     #[test]
     fn render_without_parameters() {
         test_condition(
@@ -5,8 +5,8 @@ use crate::{
     store::postgres::query::{
         table::{
             Column, EntityEditions, EntityEmbeddings, EntityHasLeftEntity, EntityHasRightEntity,
-            EntityIds, EntityIsOfTypeIds, EntityProperties, EntityTemporalMetadata, JsonField,
-            ReferenceTable, Relation,
+            EntityIds, EntityIsOfTypeIds, EntityProperties, EntityPropertySources,
+            EntityTemporalMetadata, JsonField, ReferenceTable, Relation,
         },
         PostgresQueryPath,
     },
@@ -30,9 +30,11 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
             Self::RightEntityConfidence | Self::RightEntityProvenance => {
                 vec![Relation::RightEntity]
             }
-            Self::PropertyPaths | Self::PropertyConfidences | Self::PropertyProvenance(_) => {
-                vec![Relation::EntityProperties]
-            }
+            Self::PropertyPaths
+            | Self::PropertyConfidences
+            | Self::PropertyProvenance(_)
+            | Self::PropertyConfidence(_) => vec![Relation::EntityProperties],
+            Self::PropertySource(_) => vec![Relation::EntityPropertySources],
             Self::Properties(_)
             | Self::EditionProvenance(_)
             | Self::Archived
@@ -170,6 +172,14 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                 Column::EntityProperties(EntityProperties::Provenances),
                 path.as_ref().map(JsonField::JsonPath),
             ),
+            Self::PropertyConfidence(path) => (
+                Column::EntityProperties(EntityProperties::ConfidencesByPath),
+                Some(JsonField::JsonPath(path)),
+            ),
+            Self::PropertySource(path) => (
+                Column::EntityPropertySources(EntityPropertySources::Source),
+                path.as_ref().map(JsonField::JsonPath),
+            ),
             Self::EntityConfidence => (Column::EntityEditions(EntityEditions::Confidence), None),
             Self::LeftEntityConfidence => (
                 Column::EntityHasLeftEntity(EntityHasLeftEntity::Confidence),
@@ -10,8 +10,14 @@ use crate::store::{
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Function {
+    Count(Box<Expression>),
+    Sum(Box<Expression>),
+    Avg(Box<Expression>),
     Min(Box<Expression>),
     Max(Box<Expression>),
+    ToJson(Box<Expression>),
+    /// Converts a JSON number to `float8`. Other JSON values are converted to `NULL`.
+    JsonNumber(Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractAsText(Box<Expression>, PathToken<'static>),
     JsonExtractPath(Vec<Expression>),
@@ -23,11 +29,37 @@ pub enum Function {
     Upper(Box<Expression>),
     Unnest(Box<Expression>),
     Now,
+    ToTsVector(Box<Expression>),
+    JsonToTsVector(Box<Expression>),
+    WebSearchToTsQuery(Box<Expression>),
+    TsRank(Box<Expression>, Box<Expression>),
 }
 
+/// The text search configuration used for full-text search.
+///
+/// The GIN index on `entity_editions.properties` is created with the same configuration, so both
+/// have to be changed together.
+const TEXT_SEARCH_CONFIGURATION: &str = "english";
+
 impl Transpile for Function {
+    #[expect(clippy::too_many_lines)]
     fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::Count(expression) => {
+                fmt.write_str("COUNT(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Avg(expression) => {
+                fmt.write_str("AVG(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::Min(expression) => {
                 fmt.write_str("MIN(")?;
                 expression.transpile(fmt)?;
@@ -38,6 +70,18 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonNumber(expression) => {
+                fmt.write_str("(CASE WHEN jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(") = 'number' THEN (")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(")::float8 END)")
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -112,6 +156,28 @@ impl Transpile for Function {
                 path.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ToTsVector(expression) => {
+                write!(fmt, "to_tsvector('{TEXT_SEARCH_CONFIGURATION}', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToTsVector(expression) => {
+                write!(fmt, "jsonb_to_tsvector('{TEXT_SEARCH_CONFIGURATION}', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(", '[\"string\"]')")
+            }
+            Self::WebSearchToTsQuery(expression) => {
+                write!(fmt, "websearch_to_tsquery('{TEXT_SEARCH_CONFIGURATION}', ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -139,6 +205,8 @@ pub enum PostgresType {
     Row(Table),
     Text,
     JsonPath,
+    Float8,
+    EntityId,
 }
 
 impl Transpile for PostgresType {
@@ -151,6 +219,8 @@ impl Transpile for PostgresType {
             Self::Row(table) => table.transpile(fmt),
             Self::Text => fmt.write_str("text"),
             Self::JsonPath => fmt.write_str("jsonpath"),
+            Self::Float8 => fmt.write_str("float8"),
+            Self::EntityId => fmt.write_str("entity_id"),
         }
     }
 }
@@ -178,6 +248,7 @@ pub enum Expression {
     Window(Box<Self>, WindowStatement),
     Cast(Box<Self>, PostgresType),
     FieldAccess(Box<Self>, Box<Self>),
+    Row(Vec<Self>),
     Select(Box<SelectStatement>),
 }
 
@@ -235,6 +306,16 @@ impl Transpile for Expression {
                 fmt.write_str(".")?;
                 subscript.transpile(fmt)
             }
+            Self::Row(expressions) => {
+                fmt.write_str("ROW(")?;
+                for (idx, expression) in expressions.iter().enumerate() {
+                    if idx > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    expression.transpile(fmt)?;
+                }
+                fmt.write_char(')')
+            }
             Self::Select(select) => select.transpile(fmt),
         }
     }
@@ -22,8 +22,11 @@ use std::{
 
 use bytes::BytesMut;
 use error_stack::Context;
-use graph_types::knowledge::entity::Entity;
-use postgres_types::{FromSql, IsNull, ToSql, Type, WasNull};
+use graph_types::{
+    knowledge::entity::{Entity, EntityUuid},
+    owned_by_id::OwnedById,
+};
+use postgres_types::{FromSql, IsNull, Kind, ToSql, Type, WasNull};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use temporal_versioning::{TemporalInterval, Timestamp};
@@ -43,6 +46,7 @@ pub use self::{
     table::{Alias, AliasedTable, Column, ForeignKeyReference, ReferenceTable, Table},
 };
 use crate::{
+    knowledge::EntityQueryPath,
     store::{
         crud::Sorting,
         knowledge::{EntityQueryCursor, EntityQuerySorting},
@@ -50,7 +54,7 @@ use crate::{
             crud::QueryRecordDecode,
             query::table::{JsonField, Relation},
         },
-        query::ParameterConversionError,
+        query::{ParameterConversionError, ParameterList},
         QueryRecord,
     },
     subgraph::temporal_axes::QueryTemporalAxes,
@@ -210,6 +214,49 @@ impl ToSql for CursorField<'_> {
     }
 }
 
+/// The composite `entity_id` type used to compare entities against a list of [`EntityId`]s.
+#[derive(Debug, ToSql)]
+#[postgres(name = "entity_id")]
+struct EntityIdParameter {
+    web_id: OwnedById,
+    entity_uuid: EntityUuid,
+}
+
+impl ToSql for ParameterList<'_> {
+    postgres_types::to_sql_checked!();
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Array(_))
+    }
+
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::I32(values) => values.as_ref().to_sql_checked(ty, out),
+            Self::F64(values) => values.as_ref().to_sql_checked(ty, out),
+            Self::EntityIds(values) => values
+                .iter()
+                .map(|entity_id| EntityIdParameter {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                })
+                .collect::<Vec<_>>()
+                .to_sql_checked(ty, out),
+            Self::Text(values) => values.as_ref().to_sql_checked(ty, out),
+            Self::OntologyTypeVersion(values) => values.as_ref().to_sql_checked(ty, out),
+            Self::Uuid(values) => values.as_ref().to_sql_checked(ty, out),
+            Self::VersionedUrl(values) => values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .to_sql_checked(ty, out),
+            Self::Any(values) => values.as_ref().to_sql_checked(ty, out),
+        }
+    }
+}
+
 impl<'s> QueryRecordDecode for EntityQuerySorting<'s> {
     type CompilationArtifacts = Vec<usize>;
     type Output = EntityQueryCursor<'s>;
@@ -241,10 +288,24 @@ where
     where
         's: 'q,
     {
-        if let Some(cursor) = self.cursor() {
+        static PROPERTIES_PATH: EntityQueryPath<'static> = EntityQueryPath::Properties(None);
+
+        let mut cursor_values = self.cursor().map(|cursor| cursor.values.iter());
+        let relevance_index = self.relevance.as_ref().map(|query| {
+            compiler.add_search_rank_selection(
+                &PROPERTIES_PATH,
+                query,
+                cursor_values
+                    .as_mut()
+                    .and_then(Iterator::next)
+                    .map(|value| value as &(dyn ToSql + Sync)),
+            )
+        });
+
+        let indices = if let Some(cursor_values) = cursor_values {
             self.paths
                 .iter()
-                .zip(&cursor.values)
+                .zip(cursor_values)
                 .map(|(sorting_record, parameter)| {
                     let expression = (*parameter != CursorField::Json(Value::Null))
                         .then(|| compiler.add_parameter(parameter));
@@ -256,7 +317,7 @@ where
                         sorting_record.nulls,
                     )
                 })
-                .collect()
+                .collect::<Vec<_>>()
         } else {
             self.paths
                 .iter()
@@ -268,7 +329,9 @@ where
                     )
                 })
                 .collect()
-        }
+        };
+
+        relevance_index.into_iter().chain(indices).collect()
     }
 }
 
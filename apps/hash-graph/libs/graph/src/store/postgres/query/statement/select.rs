@@ -92,14 +92,14 @@ impl Transpile for SelectStatement {
             self.where_expression.transpile(fmt)?;
         }
 
-        if !self.order_by_expression.is_empty() {
+        if !self.group_by_expression.expressions.is_empty() {
             fmt.write_char('\n')?;
-            self.order_by_expression.transpile(fmt)?;
+            self.group_by_expression.transpile(fmt)?;
         }
 
-        if !self.group_by_expression.expressions.is_empty() {
+        if !self.order_by_expression.is_empty() {
             fmt.write_char('\n')?;
-            self.group_by_expression.transpile(fmt)?;
+            self.order_by_expression.transpile(fmt)?;
         }
 
         if let Some(limit) = self.limit {
@@ -116,8 +116,9 @@ mod tests {
     use std::borrow::Cow;
 
     use graph_types::{
-        knowledge::entity::Entity,
+        knowledge::entity::{Entity, EntityId, EntityUuid},
         ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+        owned_by_id::OwnedById,
         Embedding,
     };
     use postgres_types::ToSql;
@@ -130,8 +131,8 @@ mod tests {
             postgres::query::{
                 test_helper::trim_whitespace, Distinctness, PostgresRecord, SelectCompiler,
             },
-            query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
-            NullOrdering, Ordering,
+            query::{Filter, FilterExpression, JsonPath, Parameter, ParameterList, PathToken},
+            AggregationFunction, NullOrdering, Ordering,
         },
         subgraph::{
             edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
@@ -735,6 +736,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_aggregation() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/age/",
+        ))]);
+        let group_path = EntityQueryPath::OwnedById;
+        let property_path = EntityQueryPath::Properties(Some(json_path.clone()));
+
+        compiler.add_grouping(&group_path);
+        compiler.add_aggregation(AggregationFunction::Count, None);
+        compiler.add_aggregation(AggregationFunction::Sum, Some(&property_path));
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+              to_jsonb("entity_temporal_metadata_0_0_0"."web_id"),
+              to_jsonb(COUNT(*)),
+              to_jsonb(SUM((CASE WHEN jsonb_typeof(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath))) = 'number' THEN (jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath)))::float8 END)))
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            ORDER BY "entity_temporal_metadata_0_0_0"."web_id" ASC
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+            ],
+        );
+    }
+
     #[test]
     fn entity_property_null_query() {
         let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
@@ -1074,11 +1114,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filter_in_entity_ids() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+
+        let entity_ids = ParameterList::EntityIds(Cow::Owned(vec![EntityId {
+            owned_by_id: OwnedById::new(Uuid::nil()),
+            entity_uuid: EntityUuid::new(Uuid::nil()),
+            draft_id: None,
+        }]));
+        let filter = Filter::All(vec![
+            Filter::In(
+                FilterExpression::Path(EntityQueryPath::Uuid),
+                entity_ids.clone(),
+            ),
+            Filter::In(
+                FilterExpression::Path(EntityQueryPath::EntityEdge {
+                    edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                    path: Box::new(EntityQueryPath::Uuid),
+                    direction: EdgeDirection::Outgoing,
+                }),
+                entity_ids.clone(),
+            ),
+        ]);
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            LEFT OUTER JOIN "entity_has_left_entity" AS "entity_has_left_entity_0_1_0"
+              ON "entity_has_left_entity_0_1_0"."web_id" = "entity_temporal_metadata_0_0_0"."web_id"
+             AND "entity_has_left_entity_0_1_0"."entity_uuid" = "entity_temporal_metadata_0_0_0"."entity_uuid"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+              AND ((ROW("entity_temporal_metadata_0_0_0"."web_id", "entity_temporal_metadata_0_0_0"."entity_uuid")::entity_id) = ANY($3))
+              AND ((ROW("entity_has_left_entity_0_1_0"."left_web_id", "entity_has_left_entity_0_1_0"."left_entity_uuid")::entity_id) = ANY($4))
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &entity_ids,
+                &entity_ids,
+            ],
+        );
+    }
+
+    #[test]
+    fn filter_property_metadata() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes), false);
+        let confidence_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1",
+        ))]);
+        let source_path = JsonPath::from_path_tokens(vec![
+            PathToken::Field(Cow::Borrowed("location")),
+            PathToken::Field(Cow::Borrowed("uri")),
+        ]);
+
+        let filter = Filter::All(vec![
+            Filter::Less(
+                FilterExpression::Path(EntityQueryPath::PropertyConfidence(
+                    confidence_path.clone(),
+                )),
+                FilterExpression::Parameter(Parameter::F64(0.5)),
+            ),
+            Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::PropertySource(
+                    Some(source_path.clone()),
+                ))),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    "https://example.com/",
+                )))),
+            ),
+        ]);
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            LEFT OUTER JOIN "entity_properties" AS "entity_properties_0_1_0"
+              ON "entity_properties_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND (jsonb_path_query_first("entity_properties_0_1_0"."confidences_by_path", (($1::text)::jsonpath)) < $4)
+              AND (EXISTS (SELECT 1
+                FROM "entity_property_sources" AS "entity_property_sources_0_1_0"
+                WHERE "entity_property_sources_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+                  AND jsonb_path_query_first("entity_property_sources_0_1_0"."source", (($5::text)::jsonpath)) = $6))
+            "#,
+            &[
+                &confidence_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &0.5,
+                &source_path,
+                &"https://example.com/",
+            ],
+        );
+    }
+
     mod predefined {
-        use graph_types::{
-            knowledge::entity::{EntityId, EntityUuid},
-            owned_by_id::OwnedById,
-        };
         use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
 
         use super::*;
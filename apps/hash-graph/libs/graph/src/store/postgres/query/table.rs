@@ -38,6 +38,7 @@ pub enum Table {
     EntityIsOfTypeIds,
     EntityProperty,
     EntityProperties,
+    EntityPropertySources,
     EntityHasLeftEntity,
     EntityHasRightEntity,
     Reference(ReferenceTable),
@@ -327,6 +328,7 @@ impl Table {
             Self::EntityIsOfTypeIds => "entity_is_of_type_ids",
             Self::EntityProperty => "entity_property",
             Self::EntityProperties => "entity_properties",
+            Self::EntityPropertySources => "entity_property_sources",
             Self::EntityHasLeftEntity => "entity_has_left_entity",
             Self::EntityHasRightEntity => "entity_has_right_entity",
             Self::Reference(table) => table.as_str(),
@@ -989,6 +991,7 @@ pub enum EntityProperties {
     PropertyPaths,
     Confidences,
     Provenances,
+    ConfidencesByPath,
 }
 
 impl DatabaseColumn for EntityProperties {
@@ -997,14 +1000,14 @@ impl DatabaseColumn for EntityProperties {
             Self::EntityEditionId => ParameterType::Uuid,
             Self::PropertyPaths => ParameterType::Vector(Box::new(ParameterType::Text)),
             Self::Confidences => ParameterType::Vector(Box::new(ParameterType::F64)),
-            Self::Provenances => ParameterType::Any,
+            Self::Provenances | Self::ConfidencesByPath => ParameterType::Any,
         }
     }
 
     fn nullable(self) -> bool {
         match self {
             Self::EntityEditionId | Self::Provenances => false,
-            Self::PropertyPaths | Self::Confidences => true,
+            Self::PropertyPaths | Self::Confidences | Self::ConfidencesByPath => true,
         }
     }
 
@@ -1014,6 +1017,36 @@ impl DatabaseColumn for EntityProperties {
             Self::PropertyPaths => "property_paths",
             Self::Confidences => "confidences",
             Self::Provenances => "provenances",
+            Self::ConfidencesByPath => "confidences_by_path",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EntityPropertySources {
+    EntityEditionId,
+    PropertyPath,
+    Source,
+}
+
+impl DatabaseColumn for EntityPropertySources {
+    fn parameter_type(self) -> ParameterType {
+        match self {
+            Self::EntityEditionId => ParameterType::Uuid,
+            Self::PropertyPath => ParameterType::Text,
+            Self::Source => ParameterType::Any,
+        }
+    }
+
+    fn nullable(self) -> bool {
+        false
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::EntityEditionId => "entity_edition_id",
+            Self::PropertyPath => "property_path",
+            Self::Source => "source",
         }
     }
 }
@@ -1303,6 +1336,7 @@ pub enum Column {
     EntityIsOfType(EntityIsOfType, Option<u32>),
     EntityIsOfTypeIds(EntityIsOfTypeIds),
     EntityProperties(EntityProperties),
+    EntityPropertySources(EntityPropertySources),
     EntityHasLeftEntity(EntityHasLeftEntity),
     EntityHasRightEntity(EntityHasRightEntity),
 }
@@ -1451,6 +1485,12 @@ impl From<EntityProperties> for Column {
     }
 }
 
+impl From<EntityPropertySources> for Column {
+    fn from(column: EntityPropertySources) -> Self {
+        Self::EntityPropertySources(column)
+    }
+}
+
 impl From<EntityHasLeftEntity> for Column {
     fn from(column: EntityHasLeftEntity) -> Self {
         Self::EntityHasLeftEntity(column)
@@ -1508,6 +1548,7 @@ impl Column {
             }
             Self::EntityIsOfTypeIds(_) => Table::EntityIsOfTypeIds,
             Self::EntityProperties(_) => Table::EntityProperties,
+            Self::EntityPropertySources(_) => Table::EntityPropertySources,
             Self::EntityHasLeftEntity(_) => Table::Reference(ReferenceTable::EntityHasLeftEntity),
             Self::EntityHasRightEntity(_) => Table::Reference(ReferenceTable::EntityHasRightEntity),
         }
@@ -1559,6 +1600,7 @@ impl DatabaseColumn for Column {
             Self::EntityIsOfType(column, _) => column.parameter_type(),
             Self::EntityIsOfTypeIds(column) => column.parameter_type(),
             Self::EntityProperties(column) => column.parameter_type(),
+            Self::EntityPropertySources(column) => column.parameter_type(),
             Self::EntityHasLeftEntity(column) => column.parameter_type(),
             Self::EntityHasRightEntity(column) => column.parameter_type(),
         }
@@ -1590,6 +1632,7 @@ impl DatabaseColumn for Column {
             Self::EntityIsOfType(column, _) => column.nullable(),
             Self::EntityIsOfTypeIds(column) => column.nullable(),
             Self::EntityProperties(column) => column.nullable(),
+            Self::EntityPropertySources(column) => column.nullable(),
             Self::EntityHasLeftEntity(column) => column.nullable(),
             Self::EntityHasRightEntity(column) => column.nullable(),
         }
@@ -1621,6 +1664,7 @@ impl DatabaseColumn for Column {
             Self::EntityIsOfType(column, _) => column.as_str(),
             Self::EntityIsOfTypeIds(column) => column.as_str(),
             Self::EntityProperties(column) => column.as_str(),
+            Self::EntityPropertySources(column) => column.as_str(),
             Self::EntityHasLeftEntity(column) => column.as_str(),
             Self::EntityHasRightEntity(column) => column.as_str(),
         }
@@ -1709,6 +1753,7 @@ pub enum Relation {
     PropertyTypeIds,
     EntityTypeIds,
     EntityProperties,
+    EntityPropertySources,
     EntityIsOfTypes,
     EntityIds,
     EntityEditions,
@@ -1866,6 +1911,13 @@ impl Relation {
                 join: Column::EntityProperties(EntityProperties::EntityEditionId),
                 join_type: JoinType::LeftOuter,
             }),
+            Self::EntityPropertySources => {
+                ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
+                    on: Column::EntityTemporalMetadata(EntityTemporalMetadata::EditionId),
+                    join: Column::EntityPropertySources(EntityPropertySources::EntityEditionId),
+                    join_type: JoinType::LeftOuter,
+                })
+            }
             Self::EntityTypeEmbeddings => {
                 ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
                     on: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::OntologyId),
@@ -1,18 +1,27 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    time::{Duration, Instant},
+};
 
-use error_stack::Result;
+use error_stack::{Report, Result, ResultExt};
 use graph_types::{
     knowledge::entity::{Entity, EntityEditionId},
     ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
 };
+use hash_status::StatusCode;
 use temporal_versioning::RightBoundedTemporalInterval;
+use tokio_postgres::GenericClient;
 
 use crate::{
     knowledge::EntityQueryPath,
     ontology::{DataTypeQueryPath, EntityTypeQueryPath, PropertyTypeQueryPath},
     store::{
         crud::Read,
-        postgres::ontology::OntologyId,
+        error::SubgraphBudgetExceeded,
+        knowledge::{SubgraphBudget, SubgraphBudgetExceededBehavior},
+        postgres::{ontology::OntologyId, query::SelectCompiler},
         query::{Filter, FilterExpression, ParameterList},
         AsClient, PostgresStore, QueryError, SubgraphRecord,
     },
@@ -38,7 +47,7 @@ where
             self,
             &Filter::<DataTypeWithMetadata>::In(
                 FilterExpression::Path(DataTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
             false,
@@ -68,7 +77,7 @@ where
             self,
             &Filter::<PropertyTypeWithMetadata>::In(
                 FilterExpression::Path(PropertyTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
             false,
@@ -98,7 +107,7 @@ where
             self,
             &Filter::<EntityTypeWithMetadata>::In(
                 FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
             false,
@@ -114,7 +123,7 @@ where
         Ok(())
     }
 
-    async fn read_entities_by_ids(
+    pub(crate) async fn read_entities_by_ids(
         &self,
         edition_ids: impl IntoIterator<Item = EntityEditionId, IntoIter: Send> + Send,
         subgraph: &mut Subgraph,
@@ -129,7 +138,7 @@ where
             self,
             &Filter::<Entity>::In(
                 FilterExpression::Path(EntityQueryPath::EditionId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
             include_drafts,
@@ -144,6 +153,40 @@ where
 
         Ok(())
     }
+
+    /// Returns the editions out of `edition_ids` which match the `filter`.
+    pub(crate) async fn filter_entities_by_ids(
+        &self,
+        edition_ids: impl IntoIterator<Item = EntityEditionId, IntoIter: Send> + Send,
+        filter: &Filter<'_, Entity>,
+        subgraph: &Subgraph,
+    ) -> Result<HashSet<EntityEditionId>, QueryError> {
+        let ids_filter = Filter::<Entity>::In(
+            FilterExpression::Path(EntityQueryPath::EditionId),
+            ParameterList::Uuid(Cow::Owned(
+                edition_ids
+                    .into_iter()
+                    .map(EntityEditionId::into_uuid)
+                    .collect(),
+            )),
+        );
+
+        // Whether drafts are included is decided when reading the traversed entities.
+        let mut compiler = SelectCompiler::new(Some(&subgraph.temporal_axes.resolved), true);
+        let edition_id_index = compiler.add_selection_path(&EntityQueryPath::EditionId);
+        compiler.add_filter(&ids_filter);
+        compiler.add_filter(filter);
+
+        let (statement, parameters) = compiler.compile();
+        Ok(self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| row.get(edition_id_index))
+            .collect())
+    }
 }
 
 #[derive(Debug)]
@@ -221,15 +264,100 @@ impl<K: Eq + Hash + Copy> TraversalContextMap<K> {
     }
 }
 
+#[derive(Debug)]
+struct TraversalBudget {
+    budget: SubgraphBudget,
+    started_at: Instant,
+}
+
 #[derive(Debug, Default)]
 pub struct TraversalContext {
     data_types: TraversalContextMap<OntologyId>,
     property_types: TraversalContextMap<OntologyId>,
     entity_types: TraversalContextMap<OntologyId>,
     entities: TraversalContextMap<EntityEditionId>,
+    budget: Option<TraversalBudget>,
+    incomplete: bool,
 }
 
 impl TraversalContext {
+    /// Creates a traversal context which limits the traversal to the given budget.
+    ///
+    /// The traversal time is measured from the creation of the context.
+    pub fn with_budget(budget: SubgraphBudget) -> Self {
+        Self {
+            budget: Some(TraversalBudget {
+                budget,
+                started_at: Instant::now(),
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if the traversal was stopped early because the budget was exceeded.
+    pub const fn is_incomplete(&self) -> bool {
+        self.incomplete
+    }
+
+    /// Checks if the traversal has exceeded its budget.
+    ///
+    /// Vertices which were traversed but not read yet are counted as well. Returns `true` if the
+    /// traversal should stop and the subgraph is incomplete.
+    ///
+    /// # Errors
+    ///
+    /// - [`SubgraphBudgetExceeded`] if the budget is exceeded and the caller chose to fail in that
+    ///   case
+    pub fn budget_exhausted(&mut self, subgraph: &Subgraph) -> Result<bool, QueryError> {
+        if self.incomplete {
+            return Ok(true);
+        }
+        let Some(TraversalBudget { budget, started_at }) = &self.budget else {
+            return Ok(false);
+        };
+
+        let num_vertices = subgraph.vertices.data_types.len()
+            + subgraph.vertices.property_types.len()
+            + subgraph.vertices.entity_types.len()
+            + subgraph.vertices.entities.len()
+            + self.data_types.0.len()
+            + self.property_types.0.len()
+            + self.entity_types.0.len()
+            + self.entities.0.len();
+
+        let exceeded = if budget
+            .max_vertices
+            .is_some_and(|max_vertices| num_vertices > max_vertices)
+        {
+            SubgraphBudgetExceeded::Vertices
+        } else if budget
+            .max_edges
+            .is_some_and(|max_edges| subgraph.edges.len() > max_edges)
+        {
+            SubgraphBudgetExceeded::Edges
+        } else if budget
+            .max_traversal_time_ms
+            .is_some_and(|max_traversal_time| {
+                started_at.elapsed() > Duration::from_millis(max_traversal_time)
+            })
+        {
+            SubgraphBudgetExceeded::TraversalTime
+        } else {
+            return Ok(false);
+        };
+
+        match budget.on_exceeded {
+            SubgraphBudgetExceededBehavior::Error => Err(Report::new(exceeded)
+                .attach(StatusCode::ResourceExhausted)
+                .change_context(QueryError)),
+            SubgraphBudgetExceededBehavior::Truncate => {
+                tracing::debug!(%exceeded, "stopping traversal early");
+                self.incomplete = true;
+                Ok(true)
+            }
+        }
+    }
+
     pub async fn read_traversed_vertices<C: AsClient, A: Send + Sync>(
         &self,
         store: &PostgresStore<C, A>,
@@ -0,0 +1,526 @@
+use std::time::Duration;
+
+use authorization::{schema::WebPermission, zanzibar::Consistency, AuthorizationApi};
+use error_stack::{Report, Result, ResultExt};
+use graph_types::{account::AccountId, knowledge::entity::Entity, owned_by_id::OwnedById};
+use hash_status::StatusCode;
+use reqwest::Url;
+use time::OffsetDateTime;
+use tokio_postgres::{GenericClient, Row};
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use uuid::Uuid;
+
+use crate::store::{
+    change::EntityChangeOperation,
+    error::DeletionError,
+    webhook::{
+        CreateWebhookParams, PendingWebhookDelivery, Webhook, WebhookDelivery,
+        WebhookDeliveryAttempt, WebhookDeliveryId, WebhookId, WebhookPayload, WebhookStore,
+    },
+    AsClient, InsertionError, PostgresStore, QueryError, UpdateError,
+};
+
+fn webhook_from_row(row: &Row) -> Webhook {
+    Webhook {
+        webhook_id: row.get(0),
+        owned_by_id: row.get(1),
+        url: row.get(2),
+        entity_type_id: row
+            .get::<_, Option<BaseUrl>>(3)
+            .zip(row.get::<_, Option<OntologyTypeVersion>>(4))
+            .map(|(base_url, version)| VersionedUrl { base_url, version }),
+        created_by_id: row.get(5),
+        created_at: row.get(6),
+    }
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Returns the web of the webhook if the actor is allowed to manage it.
+    async fn authorize_webhook(
+        &self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<OwnedById, QueryError> {
+        let owned_by_id = self
+            .as_client()
+            .query_opt(
+                "SELECT web_id FROM webhooks WHERE webhook_id = $1;",
+                &[&webhook_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .ok_or_else(|| {
+                Report::new(QueryError)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(webhook_id)
+            })?
+            .get(0);
+
+        self.authorization_api
+            .check_web_permission(
+                actor_id,
+                WebPermission::ChangePermission,
+                owned_by_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        Ok(owned_by_id)
+    }
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    /// Schedules the deliveries of the changes to the subscribed webhooks.
+    ///
+    /// This is expected to be called in the transaction which made the changes, so the deliveries
+    /// are only scheduled if the changes are committed. The dispatcher picks up the deliveries
+    /// once the transaction is committed.
+    pub(crate) async fn enqueue_webhook_deliveries<'e>(
+        &self,
+        changes: impl IntoIterator<Item = (EntityChangeOperation, &'e Entity)> + Send,
+    ) -> Result<(), QueryError> {
+        let changes = changes.into_iter().collect::<Vec<_>>();
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let web_ids = changes
+            .iter()
+            .map(|(_, entity)| entity.metadata.record_id.entity_id.owned_by_id)
+            .collect::<Vec<_>>();
+        let webhooks = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        webhook_id,
+                        web_id,
+                        url,
+                        entity_type_base_url,
+                        entity_type_version,
+                        created_by_id,
+                        created_at
+                    FROM webhooks
+                    WHERE web_id = ANY($1);
+                ",
+                &[&web_ids],
+            )
+            .await
+            .change_context(QueryError)?
+            .iter()
+            .map(webhook_from_row)
+            .collect::<Vec<_>>();
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let mut delivery_ids = Vec::new();
+        let mut webhook_ids = Vec::new();
+        let mut payloads = Vec::new();
+        for (operation, entity) in changes {
+            for webhook in &webhooks {
+                if webhook.owned_by_id != entity.metadata.record_id.entity_id.owned_by_id {
+                    continue;
+                }
+                if let Some(entity_type_id) = &webhook.entity_type_id
+                    && !entity.metadata.entity_type_ids.contains(entity_type_id)
+                {
+                    continue;
+                }
+
+                let delivery_id = WebhookDeliveryId::new(Uuid::new_v4());
+                payloads.push(
+                    serde_json::to_value(WebhookPayload {
+                        delivery_id,
+                        webhook_id: webhook.webhook_id,
+                        operation,
+                        entity,
+                    })
+                    .change_context(QueryError)?,
+                );
+                delivery_ids.push(delivery_id);
+                webhook_ids.push(webhook.webhook_id);
+            }
+        }
+        if delivery_ids.is_empty() {
+            return Ok(());
+        }
+
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO webhook_deliveries (
+                        delivery_id,
+                        webhook_id,
+                        payload,
+                        created_at,
+                        next_attempt_at
+                    )
+                    SELECT *, now(), now()
+                    FROM UNNEST($1::UUID[], $2::UUID[], $3::JSONB[]);
+                ",
+                &[&delivery_ids, &webhook_ids, &payloads],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        Ok(())
+    }
+}
+
+impl<C, A> WebhookStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_webhook(
+        &mut self,
+        actor_id: AccountId,
+        params: CreateWebhookParams,
+    ) -> Result<Webhook, InsertionError> {
+        self.authorization_api
+            .check_web_permission(
+                actor_id,
+                WebPermission::ChangePermission,
+                params.owned_by_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?
+            .assert_permission()
+            .change_context(InsertionError)?;
+
+        let url = Url::parse(&params.url)
+            .change_context(InsertionError)
+            .attach(StatusCode::InvalidArgument)?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(Report::new(InsertionError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("webhook URLs must use HTTP or HTTPS"));
+        }
+
+        let webhook_id = WebhookId::new(Uuid::new_v4());
+        let encrypted_secret = self
+            .webhook_secret_key
+            .as_ref()
+            .ok_or_else(|| {
+                Report::new(InsertionError)
+                    .attach_printable("no key is configured to encrypt webhook secrets")
+            })?
+            .encrypt(webhook_id, &params.secret)
+            .change_context(InsertionError)?;
+        let entity_type_base_url = params
+            .entity_type_id
+            .as_ref()
+            .map(|entity_type_id| &entity_type_id.base_url);
+        let entity_type_version = params
+            .entity_type_id
+            .as_ref()
+            .map(|entity_type_id| entity_type_id.version);
+        let row = self
+            .as_client()
+            .query_one(
+                "
+                    INSERT INTO webhooks (
+                        webhook_id,
+                        web_id,
+                        url,
+                        encrypted_secret,
+                        entity_type_base_url,
+                        entity_type_version,
+                        created_by_id,
+                        created_at
+                    ) VALUES ($1, $2, $3, $4, $5, $6, $7, now())
+                    RETURNING
+                        webhook_id,
+                        web_id,
+                        url,
+                        entity_type_base_url,
+                        entity_type_version,
+                        created_by_id,
+                        created_at;
+                ",
+                &[
+                    &webhook_id,
+                    &params.owned_by_id,
+                    &url.as_str(),
+                    &encrypted_secret,
+                    &entity_type_base_url,
+                    &entity_type_version,
+                    &actor_id,
+                ],
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        Ok(webhook_from_row(&row))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_webhooks(
+        &self,
+        actor_id: AccountId,
+        owned_by_id: OwnedById,
+    ) -> Result<Vec<Webhook>, QueryError> {
+        self.authorization_api
+            .check_web_permission(
+                actor_id,
+                WebPermission::ChangePermission,
+                owned_by_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        Ok(self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        webhook_id,
+                        web_id,
+                        url,
+                        entity_type_base_url,
+                        entity_type_version,
+                        created_by_id,
+                        created_at
+                    FROM webhooks
+                    WHERE web_id = $1
+                    ORDER BY created_at, webhook_id;
+                ",
+                &[&owned_by_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .iter()
+            .map(webhook_from_row)
+            .collect())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn delete_webhook(
+        &mut self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<(), DeletionError> {
+        self.authorize_webhook(actor_id, webhook_id)
+            .await
+            .change_context(DeletionError)?;
+
+        self.as_client()
+            .execute(
+                "DELETE FROM webhooks WHERE webhook_id = $1;",
+                &[&webhook_id],
+            )
+            .await
+            .change_context(DeletionError)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_webhook_deliveries(
+        &self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<Vec<WebhookDelivery>, QueryError> {
+        self.authorize_webhook(actor_id, webhook_id).await?;
+
+        Ok(self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        webhook_deliveries.delivery_id,
+                        webhook_deliveries.payload,
+                        webhook_deliveries.created_at,
+                        webhook_deliveries.delivered_at,
+                        webhook_deliveries.next_attempt_at,
+                        coalesce(
+                            array_agg(attempts.attempted_at ORDER BY attempts.attempted_at)
+                                FILTER (WHERE attempts.attempted_at IS NOT NULL),
+                            '{}'
+                        ),
+                        coalesce(
+                            array_agg(attempts.status_code ORDER BY attempts.attempted_at)
+                                FILTER (WHERE attempts.attempted_at IS NOT NULL),
+                            '{}'
+                        ),
+                        coalesce(
+                            array_agg(attempts.error ORDER BY attempts.attempted_at)
+                                FILTER (WHERE attempts.attempted_at IS NOT NULL),
+                            '{}'
+                        )
+                    FROM webhook_deliveries
+                    LEFT JOIN webhook_delivery_attempts AS attempts
+                      ON attempts.delivery_id = webhook_deliveries.delivery_id
+                    WHERE webhook_deliveries.webhook_id = $1
+                    GROUP BY webhook_deliveries.delivery_id
+                    ORDER BY webhook_deliveries.created_at DESC, webhook_deliveries.delivery_id;
+                ",
+                &[&webhook_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| WebhookDelivery {
+                delivery_id: row.get(0),
+                webhook_id,
+                payload: row.get(1),
+                created_at: row.get(2),
+                delivered_at: row.get(3),
+                next_attempt_at: row.get(4),
+                attempts: row
+                    .get::<_, Vec<OffsetDateTime>>(5)
+                    .into_iter()
+                    .zip(row.get::<_, Vec<Option<i16>>>(6))
+                    .zip(row.get::<_, Vec<Option<String>>>(7))
+                    .map(
+                        |((attempted_at, status_code), error)| WebhookDeliveryAttempt {
+                            attempted_at,
+                            status_code: status_code
+                                .and_then(|status_code| u16::try_from(status_code).ok()),
+                            error,
+                        },
+                    )
+                    .collect(),
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn claim_webhook_deliveries(
+        &mut self,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<PendingWebhookDelivery>, QueryError> {
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let lease = lease.as_secs_f64();
+        self.as_client()
+            .query(
+                "
+                    WITH claimed AS (
+                        UPDATE webhook_deliveries
+                        SET next_attempt_at = now() + make_interval(secs => $2)
+                        WHERE delivery_id IN (
+                            SELECT delivery_id
+                            FROM webhook_deliveries
+                            WHERE next_attempt_at <= now()
+                            ORDER BY next_attempt_at
+                            LIMIT $1
+                            FOR UPDATE SKIP LOCKED
+                        )
+                        RETURNING delivery_id, webhook_id, payload
+                    )
+                    SELECT
+                        claimed.delivery_id,
+                        claimed.webhook_id,
+                        webhooks.url,
+                        webhooks.encrypted_secret,
+                        claimed.payload,
+                        (
+                            SELECT count(*)
+                            FROM webhook_delivery_attempts
+                            WHERE webhook_delivery_attempts.delivery_id = claimed.delivery_id
+                        )
+                    FROM claimed
+                    JOIN webhooks ON webhooks.webhook_id = claimed.webhook_id;
+                ",
+                &[&limit, &lease],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let webhook_id = row.get(1);
+                let secret = self
+                    .webhook_secret_key
+                    .as_ref()
+                    .ok_or_else(|| {
+                        Report::new(QueryError)
+                            .attach_printable("no key is configured to decrypt webhook secrets")
+                    })?
+                    .decrypt(webhook_id, row.get(3))
+                    .change_context(QueryError)
+                    .attach_printable(webhook_id)?;
+                Ok::<_, Report<QueryError>>(PendingWebhookDelivery {
+                    delivery_id: row.get(0),
+                    webhook_id,
+                    url: row.get(2),
+                    secret,
+                    payload: row.get(4),
+                    previous_attempts: usize::try_from(row.get::<_, i64>(5)).unwrap_or(usize::MAX),
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, attempt))]
+    async fn record_webhook_delivery_attempt(
+        &mut self,
+        delivery_id: WebhookDeliveryId,
+        attempt: WebhookDeliveryAttempt,
+        next_attempt_at: Option<OffsetDateTime>,
+    ) -> Result<(), UpdateError> {
+        let succeeded = attempt.succeeded();
+        let status_code = attempt
+            .status_code
+            .and_then(|status_code| i16::try_from(status_code).ok());
+
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+        transaction
+            .as_client()
+            .execute(
+                "
+                    INSERT INTO webhook_delivery_attempts (
+                        delivery_id,
+                        attempted_at,
+                        status_code,
+                        error
+                    ) VALUES ($1, $2, $3, $4);
+                ",
+                &[
+                    &delivery_id,
+                    &attempt.attempted_at,
+                    &status_code,
+                    &attempt.error,
+                ],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let (delivered_at, next_attempt_at) = if succeeded {
+            (Some(attempt.attempted_at), None)
+        } else {
+            (None, next_attempt_at)
+        };
+        transaction
+            .as_client()
+            .execute(
+                "
+                    UPDATE webhook_deliveries
+                    SET delivered_at = $2, next_attempt_at = $3
+                    WHERE delivery_id = $1;
+                ",
+                &[&delivery_id, &delivered_at, &next_attempt_at],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)
+    }
+}
@@ -1,11 +1,13 @@
 use std::{borrow::Cow, fmt, mem, str::FromStr};
 
 use derivative::Derivative;
-use error_stack::{bail, Context, Report, ResultExt};
+use error_stack::{bail, ensure, Context, Report, ResultExt};
 use graph_types::{
     knowledge::entity::{Entity, EntityId},
     Embedding,
 };
+use hash_status::StatusCode;
+use regex_syntax::ast::{self, Ast, RepetitionKind, RepetitionRange};
 use serde::Deserialize;
 use serde_json::{Number, Value};
 use temporal_versioning::Timestamp;
@@ -54,11 +56,30 @@ pub enum Filter<'p, R: QueryRecord + ?Sized> {
         FilterExpression<'p, R>,
         FilterExpression<'p, R>,
     ),
-    #[serde(skip)]
     In(FilterExpression<'p, R>, ParameterList<'p>),
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    StartsWithCaseInsensitive(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    EndsWithCaseInsensitive(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    ContainsSegmentCaseInsensitive(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches the left expression case-insensitively against the regular expression on the
+    /// right.
+    ///
+    /// Patterns are validated before they are used: They may not exceed
+    /// [`MAX_REGEX_LENGTH`] bytes, may not nest unbounded repetitions, e.g. `(a+)*`, and may not
+    /// use features without a guaranteed running time like backreferences or look-arounds.
+    /// Only syntax which is interpreted the same way by Postgres is accepted, so e.g. Unicode
+    /// classes like `\p{L}`, named groups, word boundaries, and flags other than a leading `(?i)`
+    /// are rejected.
+    Regex(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches the left expression case-sensitively against the regular expression on the right.
+    ///
+    /// The pattern is validated the same way as for [`Filter::Regex`].
+    RegexCaseSensitive(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches the string values of the left expression against the full-text search query on the
+    /// right, e.g. `"quick fox"` or `"\"exact phrase\" -excluded"`.
+    Matches(FilterExpression<'p, R>, FilterExpression<'p, R>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -195,18 +216,32 @@ where
                     (..) => {}
                 }
             }
-            Self::In(lhs, rhs) => {
-                if let FilterExpression::Parameter(parameter) = lhs {
-                    match rhs {
-                        ParameterList::Uuid(_) => {
-                            parameter.convert_to_parameter_type(ParameterType::Uuid)?;
-                        }
+            Self::In(lhs, rhs) => match lhs {
+                FilterExpression::Path(path) => {
+                    if matches!(rhs, ParameterList::EntityIds(_)) && !path.accepts_entity_ids() {
+                        // Strings shaped like entity IDs are only compared as such against paths
+                        // accepting them, any other path compares them as text.
+                        rhs.convert_to_parameter_type(&ParameterType::Text)?;
+                        rhs.convert_to_parameter_type(&path.expected_type())
+                            .attach(StatusCode::InvalidArgument)
+                            .attach_printable_lazy(|| {
+                                format!("entity IDs cannot be compared against `{path}`")
+                            })?;
+                    } else {
+                        rhs.convert_to_parameter_type(&path.expected_type())?;
                     }
                 }
-            }
+                FilterExpression::Parameter(parameter) => {
+                    parameter.convert_to_parameter_type(rhs.element_type())?;
+                }
+            },
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::StartsWithCaseInsensitive(lhs, rhs)
+            | Self::EndsWithCaseInsensitive(lhs, rhs)
+            | Self::ContainsSegmentCaseInsensitive(lhs, rhs)
+            | Self::Matches(lhs, rhs) => {
                 // TODO: We need to find a way to support lists in addition to strings as well
                 if let FilterExpression::Parameter(parameter) = lhs {
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
@@ -215,6 +250,14 @@ where
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
                 }
             }
+            Self::Regex(lhs, rhs) | Self::RegexCaseSensitive(lhs, rhs) => {
+                if let FilterExpression::Parameter(parameter) = lhs {
+                    parameter.convert_to_parameter_type(ParameterType::Text)?;
+                }
+                if let FilterExpression::Parameter(parameter) = rhs {
+                    parameter.convert_to_parameter_type(ParameterType::Regex)?;
+                }
+            }
         }
 
         Ok(())
@@ -254,9 +297,26 @@ pub enum Parameter<'p> {
     Timestamp(Timestamp<()>),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A list of values used in [`Filter::In`].
+///
+/// Lists of [`EntityId`]s can only be compared against the [`EntityQueryPath::Uuid`] of an
+/// entity or of one of its link endpoints. The draft part of the [`EntityId`]s is not taken into
+/// account.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
 pub enum ParameterList<'p> {
-    Uuid(&'p [Uuid]),
+    I32(Cow<'p, [i32]>),
+    F64(Cow<'p, [f64]>),
+    EntityIds(Cow<'p, [EntityId]>),
+    Text(Cow<'p, [String]>),
+    #[serde(skip)]
+    OntologyTypeVersion(Cow<'p, [OntologyTypeVersion]>),
+    #[serde(skip)]
+    Uuid(Cow<'p, [Uuid]>),
+    #[serde(skip)]
+    VersionedUrl(Cow<'p, [VersionedUrl]>),
+    #[serde(skip)]
+    Any(Cow<'p, [Value]>),
 }
 
 impl<'p> Parameter<'p> {
@@ -276,9 +336,164 @@ impl<'p> Parameter<'p> {
     }
 }
 
+impl ParameterList<'_> {
+    #[must_use]
+    pub fn to_owned(&self) -> ParameterList<'static> {
+        match self {
+            ParameterList::I32(numbers) => ParameterList::I32(Cow::Owned(numbers.to_vec())),
+            ParameterList::F64(numbers) => ParameterList::F64(Cow::Owned(numbers.to_vec())),
+            ParameterList::EntityIds(entity_ids) => {
+                ParameterList::EntityIds(Cow::Owned(entity_ids.to_vec()))
+            }
+            ParameterList::Text(texts) => ParameterList::Text(Cow::Owned(texts.to_vec())),
+            ParameterList::OntologyTypeVersion(versions) => {
+                ParameterList::OntologyTypeVersion(Cow::Owned(versions.to_vec()))
+            }
+            ParameterList::Uuid(uuids) => ParameterList::Uuid(Cow::Owned(uuids.to_vec())),
+            ParameterList::VersionedUrl(urls) => {
+                ParameterList::VersionedUrl(Cow::Owned(urls.to_vec()))
+            }
+            ParameterList::Any(values) => ParameterList::Any(Cow::Owned(values.to_vec())),
+        }
+    }
+
+    /// Returns `true` if this list contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::I32(numbers) => numbers.is_empty(),
+            Self::F64(numbers) => numbers.is_empty(),
+            Self::EntityIds(entity_ids) => entity_ids.is_empty(),
+            Self::Text(texts) => texts.is_empty(),
+            Self::OntologyTypeVersion(versions) => versions.is_empty(),
+            Self::Uuid(uuids) => uuids.is_empty(),
+            Self::VersionedUrl(urls) => urls.is_empty(),
+            Self::Any(values) => values.is_empty(),
+        }
+    }
+
+    /// Returns the type of a single element in this list.
+    #[must_use]
+    pub const fn element_type(&self) -> ParameterType {
+        match self {
+            Self::I32(_) => ParameterType::I32,
+            Self::F64(_) => ParameterType::F64,
+            Self::OntologyTypeVersion(_) => ParameterType::OntologyTypeVersion,
+            Self::EntityIds(_) | Self::Uuid(_) => ParameterType::Uuid,
+            Self::Text(_) => ParameterType::Text,
+            Self::VersionedUrl(_) => ParameterType::VersionedUrl,
+            Self::Any(_) => ParameterType::Any,
+        }
+    }
+
+    fn to_values(&self) -> Vec<Value> {
+        match self {
+            Self::I32(numbers) => numbers.iter().map(|&number| Value::from(number)).collect(),
+            Self::F64(numbers) => numbers.iter().map(|&number| Value::from(number)).collect(),
+            Self::EntityIds(entity_ids) => entity_ids
+                .iter()
+                .map(|entity_id| Value::String(entity_id.to_string()))
+                .collect(),
+            Self::Text(texts) => texts.iter().cloned().map(Value::String).collect(),
+            Self::OntologyTypeVersion(versions) => versions
+                .iter()
+                .map(|version| Value::from(version.inner()))
+                .collect(),
+            Self::Uuid(uuids) => uuids
+                .iter()
+                .map(|uuid| Value::String(uuid.to_string()))
+                .collect(),
+            Self::VersionedUrl(urls) => urls
+                .iter()
+                .map(|url| Value::String(url.to_string()))
+                .collect(),
+            Self::Any(values) => values.to_vec(),
+        }
+    }
+
+    fn convert_to_parameter_type(
+        &mut self,
+        expected: &ParameterType,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        let conversion_error = |list: &Self| ParameterConversionError {
+            actual: ActualParameterType::List(list.to_owned()),
+            expected: expected.clone(),
+        };
+
+        match (&*self, expected) {
+            // identity
+            (Self::I32(_), ParameterType::I32)
+            | (Self::F64(_), ParameterType::F64)
+            | (Self::Text(_), ParameterType::Text | ParameterType::BaseUrl)
+            | (Self::OntologyTypeVersion(_), ParameterType::OntologyTypeVersion)
+            | (Self::EntityIds(_) | Self::Uuid(_), ParameterType::Uuid)
+            | (Self::VersionedUrl(_), ParameterType::VersionedUrl)
+            | (Self::Any(_), ParameterType::Any) => {}
+
+            (Self::I32(numbers), ParameterType::OntologyTypeVersion) => {
+                *self = Self::OntologyTypeVersion(
+                    numbers
+                        .iter()
+                        .map(|&number| u32::try_from(number).map(OntologyTypeVersion::new))
+                        .collect::<Result<_, _>>()
+                        .change_context_lazy(|| conversion_error(self))?,
+                );
+            }
+            (Self::I32(numbers), ParameterType::F64) => {
+                *self = Self::F64(numbers.iter().map(|&number| f64::from(number)).collect());
+            }
+            (Self::EntityIds(entity_ids), ParameterType::Text) => {
+                *self = Self::Text(entity_ids.iter().map(ToString::to_string).collect());
+            }
+            (Self::Text(texts), ParameterType::Uuid) => {
+                *self = Self::Uuid(
+                    texts
+                        .iter()
+                        .map(|text| Uuid::from_str(text))
+                        .collect::<Result<_, _>>()
+                        .change_context_lazy(|| conversion_error(self))?,
+                );
+            }
+            (Self::Text(texts), ParameterType::VersionedUrl) => {
+                *self = Self::VersionedUrl(
+                    texts
+                        .iter()
+                        .map(|text| VersionedUrl::from_str(text))
+                        .collect::<Result<_, _>>()
+                        .change_context_lazy(|| conversion_error(self))?,
+                );
+            }
+            (_, ParameterType::Any) => {
+                *self = Self::Any(Cow::Owned(self.to_values()));
+            }
+
+            // An empty list is deserialized as a list of numbers, but can be compared against
+            // any type.
+            (list, _) if list.is_empty() => {
+                *self = match expected {
+                    ParameterType::F64 => Self::F64(Cow::Borrowed(&[])),
+                    ParameterType::Text | ParameterType::BaseUrl => Self::Text(Cow::Borrowed(&[])),
+                    ParameterType::OntologyTypeVersion => {
+                        Self::OntologyTypeVersion(Cow::Borrowed(&[]))
+                    }
+                    ParameterType::Uuid => Self::Uuid(Cow::Borrowed(&[])),
+                    ParameterType::VersionedUrl => Self::VersionedUrl(Cow::Borrowed(&[])),
+                    _ => bail!(conversion_error(list)),
+                };
+            }
+
+            // Fallback
+            (actual, _) => bail!(conversion_error(actual)),
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 enum ActualParameterType {
     Parameter(Parameter<'static>),
+    List(ParameterList<'static>),
     Value(serde_json::Value),
 }
 
@@ -321,6 +536,7 @@ impl fmt::Display for ParameterConversionError {
                 Parameter::Any(Value::Object(_)) => "object".to_owned(),
                 Parameter::Any(Value::Array(_)) => "array".to_owned(),
             },
+            ActualParameterType::List(list) => format!("list of {}", list.element_type()),
             ActualParameterType::Value(value) => match value {
                 Value::Null => "null".to_owned(),
                 Value::Bool(boolean) => boolean.to_string(),
@@ -337,6 +553,200 @@ impl fmt::Display for ParameterConversionError {
 
 impl Context for ParameterConversionError {}
 
+/// The maximum length in bytes of a pattern used in [`Filter::Regex`].
+pub const MAX_REGEX_LENGTH: usize = 1000;
+
+/// The upper bound of counted repetitions, e.g. `a{1,255}`, supported by Postgres.
+const MAX_REGEX_REPETITIONS: u32 = 255;
+
+#[derive(Debug)]
+struct InvalidRegexError;
+
+impl fmt::Display for InvalidRegexError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("pattern is not a supported regular expression")
+    }
+}
+
+impl Context for InvalidRegexError {}
+
+/// Checks that `pattern` is a regular expression which can be matched in a reasonable time.
+///
+/// Only the subset of the syntax shared by Rust and Postgres' advanced regular expressions is
+/// accepted, so a pattern passing this check is interpreted the same way by the database.
+fn validate_regex(pattern: &str) -> Result<(), Report<InvalidRegexError>> {
+    ensure!(
+        pattern.len() <= MAX_REGEX_LENGTH,
+        Report::new(InvalidRegexError)
+            .attach_printable(format!("pattern exceeds {MAX_REGEX_LENGTH} bytes"))
+    );
+
+    let ast = ast::parse::Parser::new()
+        .parse(pattern)
+        .change_context(InvalidRegexError)?;
+
+    // Postgres only supports embedded options at the very start of the pattern.
+    match &ast {
+        Ast::Flags(flags) => validate_regex_flags(&flags.flags),
+        Ast::Concat(concat) => match concat.asts.split_first() {
+            Some((Ast::Flags(flags), asts)) => {
+                validate_regex_flags(&flags.flags)?;
+                asts.iter()
+                    .try_for_each(|ast| validate_regex_ast(ast, false))
+            }
+            _ => validate_regex_ast(&ast, false),
+        },
+        _ => validate_regex_ast(&ast, false),
+    }
+}
+
+fn validate_regex_flags(flags: &ast::Flags) -> Result<(), Report<InvalidRegexError>> {
+    ensure!(
+        flags.items.iter().all(|item| matches!(
+            item.kind,
+            ast::FlagsItemKind::Flag(ast::Flag::CaseInsensitive)
+        )),
+        Report::new(InvalidRegexError).attach_printable("only the `i` flag is supported")
+    );
+    Ok(())
+}
+
+fn validate_regex_ast(
+    ast: &Ast,
+    inside_unbounded_repetition: bool,
+) -> Result<(), Report<InvalidRegexError>> {
+    match ast {
+        Ast::Repetition(repetition) => {
+            let (maximum, unbounded) = match &repetition.op.kind {
+                RepetitionKind::ZeroOrOne => (1, false),
+                RepetitionKind::ZeroOrMore | RepetitionKind::OneOrMore => (0, true),
+                RepetitionKind::Range(
+                    RepetitionRange::Exactly(maximum) | RepetitionRange::Bounded(_, maximum),
+                ) => (*maximum, false),
+                RepetitionKind::Range(RepetitionRange::AtLeast(minimum)) => (*minimum, true),
+            };
+
+            ensure!(
+                maximum <= MAX_REGEX_REPETITIONS,
+                Report::new(InvalidRegexError).attach_printable(format!(
+                    "repetitions are limited to {MAX_REGEX_REPETITIONS}"
+                ))
+            );
+            ensure!(
+                !(unbounded && inside_unbounded_repetition),
+                Report::new(InvalidRegexError)
+                    .attach_printable("unbounded repetitions must not be nested")
+            );
+
+            validate_regex_ast(&repetition.ast, inside_unbounded_repetition || unbounded)
+        }
+        Ast::Group(group) => {
+            match &group.kind {
+                ast::GroupKind::CaptureIndex(_) => {}
+                ast::GroupKind::NonCapturing(flags) => ensure!(
+                    flags.items.is_empty(),
+                    Report::new(InvalidRegexError)
+                        .attach_printable("flags are only supported at the start of the pattern")
+                ),
+                ast::GroupKind::CaptureName { .. } => {
+                    return Err(Report::new(InvalidRegexError)
+                        .attach_printable("named capture groups are not supported"));
+                }
+            }
+            validate_regex_ast(&group.ast, inside_unbounded_repetition)
+        }
+        Ast::Alternation(alternation) => alternation
+            .asts
+            .iter()
+            .try_for_each(|ast| validate_regex_ast(ast, inside_unbounded_repetition)),
+        Ast::Concat(concat) => concat
+            .asts
+            .iter()
+            .try_for_each(|ast| validate_regex_ast(ast, inside_unbounded_repetition)),
+        Ast::Flags(_) => Err(Report::new(InvalidRegexError)
+            .attach_printable("flags are only supported at the start of the pattern")),
+        Ast::Literal(literal) => validate_regex_literal(literal),
+        Ast::Assertion(assertion) => {
+            ensure!(
+                matches!(
+                    assertion.kind,
+                    ast::AssertionKind::StartLine
+                        | ast::AssertionKind::EndLine
+                        | ast::AssertionKind::StartText
+                ),
+                Report::new(InvalidRegexError)
+                    .attach_printable("only `^`, `$`, and `\\A` are supported as assertions")
+            );
+            Ok(())
+        }
+        Ast::ClassUnicode(_) => Err(Report::new(InvalidRegexError)
+            .attach_printable("Unicode character classes are not supported")),
+        Ast::ClassBracketed(class) => validate_regex_class_set(&class.kind),
+        Ast::Empty(_) | Ast::Dot(_) | Ast::ClassPerl(_) => Ok(()),
+    }
+}
+
+fn validate_regex_literal(literal: &ast::Literal) -> Result<(), Report<InvalidRegexError>> {
+    ensure!(
+        matches!(
+            literal.kind,
+            ast::LiteralKind::Verbatim
+                | ast::LiteralKind::Meta
+                | ast::LiteralKind::Superfluous
+                | ast::LiteralKind::Special(_)
+                | ast::LiteralKind::HexFixed(
+                    ast::HexLiteralKind::UnicodeShort | ast::HexLiteralKind::UnicodeLong
+                )
+        ),
+        Report::new(InvalidRegexError).attach_printable(
+            "only `\\u` and `\\U` escapes are supported for hexadecimal literals"
+        )
+    );
+    Ok(())
+}
+
+fn validate_regex_class_set(class_set: &ast::ClassSet) -> Result<(), Report<InvalidRegexError>> {
+    match class_set {
+        ast::ClassSet::Item(item) => validate_regex_class_set_item(item),
+        ast::ClassSet::BinaryOp(_) => Err(Report::new(InvalidRegexError)
+            .attach_printable("character class set operations are not supported")),
+    }
+}
+
+fn validate_regex_class_set_item(
+    item: &ast::ClassSetItem,
+) -> Result<(), Report<InvalidRegexError>> {
+    match item {
+        ast::ClassSetItem::Empty(_) | ast::ClassSetItem::Range(_) => Ok(()),
+        ast::ClassSetItem::Literal(literal) => validate_regex_literal(literal),
+        ast::ClassSetItem::Ascii(class) => {
+            ensure!(
+                !class.negated,
+                Report::new(InvalidRegexError)
+                    .attach_printable("negated ASCII classes are not supported")
+            );
+            Ok(())
+        }
+        // Postgres does not allow negated Perl classes like `\D` inside of brackets.
+        ast::ClassSetItem::Perl(class) => {
+            ensure!(
+                !class.negated,
+                Report::new(InvalidRegexError)
+                    .attach_printable("negated Perl classes are not supported inside of brackets")
+            );
+            Ok(())
+        }
+        ast::ClassSetItem::Unicode(_) => Err(Report::new(InvalidRegexError)
+            .attach_printable("Unicode character classes are not supported")),
+        ast::ClassSetItem::Bracketed(_) => Err(Report::new(InvalidRegexError)
+            .attach_printable("nested character classes are not supported")),
+        ast::ClassSetItem::Union(union) => union
+            .items
+            .iter()
+            .try_for_each(validate_regex_class_set_item),
+    }
+}
+
 impl Parameter<'_> {
     #[expect(
         clippy::too_many_lines,
@@ -421,6 +831,18 @@ impl Parameter<'_> {
             (Parameter::Any(Value::String(string)), ParameterType::Text) => {
                 *self = Parameter::Text(Cow::Owned(string.clone()));
             }
+            (Parameter::Text(pattern), ParameterType::Regex) => {
+                validate_regex(pattern)
+                    .change_context_lazy(|| ParameterConversionError {
+                        actual: self.to_owned().into(),
+                        expected: ParameterType::Regex,
+                    })
+                    .attach(StatusCode::InvalidArgument)?;
+            }
+            (Parameter::Any(Value::String(pattern)), ParameterType::Regex) => {
+                *self = Parameter::Text(Cow::Owned(pattern.clone()));
+                self.convert_to_parameter_type(ParameterType::Regex)?;
+            }
             (Parameter::Text(_base_url), ParameterType::BaseUrl) => {
                 // TODO: validate base url
                 //   see https://app.asana.com/0/1202805690238892/1203225514907875/f
@@ -616,4 +1038,218 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn in_versioned_urls() {
+        let url = VersionedUrl {
+            base_url: BaseUrl::new(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/".to_owned(),
+            )
+            .expect("invalid base url"),
+            version: OntologyTypeVersion::new(1),
+        };
+
+        let expected = json!({
+          "in": [
+            { "path": ["versionedUrl"] },
+            [url]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::In(
+                FilterExpression::Path(DataTypeQueryPath::VersionedUrl),
+                ParameterList::VersionedUrl(Cow::Owned(vec![url])),
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn in_entity_ids() {
+        let entity_id = EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: None,
+        };
+
+        let expected = json!({
+          "in": [
+            { "path": ["uuid"] },
+            [entity_id]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<Entity>::In(
+                FilterExpression::Path(EntityQueryPath::Uuid),
+                ParameterList::EntityIds(Cow::Owned(vec![entity_id])),
+            ),
+            &expected,
+        );
+
+        let invalid = json!({
+          "in": [
+            { "path": ["ownedById"] },
+            [entity_id]
+          ]
+        });
+        let mut filter =
+            Filter::<Entity>::deserialize(&invalid).expect("Could not deserialize filter");
+        let error = filter
+            .convert_parameters()
+            .expect_err("entity IDs should only be compared against entity UUIDs");
+        assert_eq!(
+            error.request_ref::<StatusCode>().next(),
+            Some(&StatusCode::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn in_entity_id_texts() {
+        let entity_id = EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: None,
+        };
+
+        let expected = json!({
+          "in": [
+            { "path": ["title"] },
+            [entity_id]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::In(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                ParameterList::Text(Cow::Owned(vec![entity_id.to_string()])),
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn in_empty_list() {
+        let expected = json!({
+          "in": [
+            { "path": ["uuid"] },
+            []
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<Entity>::In(
+                FilterExpression::Path(EntityQueryPath::Uuid),
+                ParameterList::Uuid(Cow::Borrowed(&[])),
+            ),
+            &expected,
+        );
+
+        let expected = json!({
+          "in": [
+            { "path": ["title"] },
+            []
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::In(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                ParameterList::Text(Cow::Borrowed(&[])),
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn in_numbers() {
+        let expected = json!({
+          "in": [
+            { "path": ["version"] },
+            [1, 2]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::In(
+                FilterExpression::Path(DataTypeQueryPath::Version),
+                ParameterList::OntologyTypeVersion(Cow::Owned(vec![
+                    OntologyTypeVersion::new(1),
+                    OntologyTypeVersion::new(2),
+                ])),
+            ),
+            &expected,
+        );
+
+        let invalid = json!({
+          "in": [
+            { "path": ["version"] },
+            ["1", "2"]
+          ]
+        });
+        let mut filter = Filter::<DataTypeWithMetadata>::deserialize(&invalid)
+            .expect("Could not deserialize filter");
+        _ = filter
+            .convert_parameters()
+            .expect_err("text should not be converted to a version");
+    }
+
+    #[test]
+    fn regex_validation() {
+        for pattern in [
+            "^text$",
+            "(?i)te?xt",
+            "[a-z]+-\\d{1,3}",
+            "(foo|bar)*baz",
+            "(a{2})+",
+            "(?:ab)+[[:alpha:]\\d_]",
+            "\\u00e4\\.\\A",
+        ] {
+            assert!(
+                validate_regex(pattern).is_ok(),
+                "`{pattern}` should be accepted"
+            );
+        }
+
+        for pattern in [
+            "(a+)+",
+            "(a*b?)*",
+            "(x{2,})*",
+            "a{1000}",
+            "(a)\\1",
+            "foo(?=bar)",
+            "(unclosed",
+            "\\p{L}+",
+            "[\\pL]",
+            "(?P<name>a)",
+            "\\bfoo\\b",
+            "foo\\z",
+            "a(?i)b",
+            "(?s).",
+            "(?i:a)",
+            "\\x41",
+            "[a-z&&[^x]]",
+            "[\\D]",
+            "[[:^alpha:]]",
+        ] {
+            assert!(
+                validate_regex(pattern).is_err(),
+                "`{pattern}` should be rejected"
+            );
+        }
+        assert!(validate_regex(&"a".repeat(MAX_REGEX_LENGTH + 1)).is_err());
+
+        let mut filter = Filter::<DataTypeWithMetadata>::Regex(
+            FilterExpression::Path(DataTypeQueryPath::Title),
+            FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("(a+)+$"))),
+        );
+        let error = filter
+            .convert_parameters()
+            .expect_err("nested repetitions should be rejected");
+        assert_eq!(
+            error.request_ref::<StatusCode>().next(),
+            Some(&StatusCode::InvalidArgument)
+        );
+    }
 }
@@ -9,13 +9,21 @@ use serde::{
 };
 
 pub use self::{
-    filter::{Filter, FilterExpression, Parameter, ParameterConversionError, ParameterList},
+    filter::{
+        Filter, FilterExpression, Parameter, ParameterConversionError, ParameterList,
+        MAX_REGEX_LENGTH,
+    },
     path::{JsonPath, PathToken},
 };
 
 pub trait QueryPath {
     /// Returns what type this resolved `Path` has.
     fn expected_type(&self) -> ParameterType;
+
+    /// Returns if this `Path` can be compared against a [`ParameterList::EntityIds`].
+    fn accepts_entity_ids(&self) -> bool {
+        false
+    }
 }
 
 /// Parses a query token of the form `token(key=value)`.
@@ -56,6 +64,7 @@ pub enum ParameterType {
     F64,
     OntologyTypeVersion,
     Text,
+    Regex,
     Vector(Box<Self>),
     Uuid,
     BaseUrl,
@@ -74,6 +83,7 @@ impl fmt::Display for ParameterType {
             Self::F64 => fmt.write_str("64 bit floating point number"),
             Self::OntologyTypeVersion => fmt.write_str("ontology type version"),
             Self::Text => fmt.write_str("text"),
+            Self::Regex => fmt.write_str("regular expression"),
             Self::Vector(inner) => write!(fmt, "{inner}[]"),
             Self::Uuid => fmt.write_str("UUID"),
             Self::BaseUrl => fmt.write_str("base URL"),
@@ -16,6 +16,7 @@ use graph_types::{
         PropertyTypeWithMetadata,
     },
 };
+use temporal_versioning::{DecisionTime, Timestamp};
 use tokio::sync::RwLock;
 use tokio_postgres::GenericClient;
 use type_system::{
@@ -397,9 +398,9 @@ where
     }
 }
 
-impl<S, A> EntityProvider for StoreProvider<'_, S, A>
+impl<C, A> EntityProvider for StoreProvider<'_, PostgresStore<C, A>, A>
 where
-    S: Read<Entity>,
+    C: AsClient,
     A: AuthorizationApi,
 {
     #[expect(refining_impl_trait)]
@@ -432,4 +433,71 @@ where
             .await?;
         Ok(self.cache.entities.grant(entity_id, entity).await)
     }
+
+    #[expect(refining_impl_trait)]
+    async fn count_outgoing_links(
+        &self,
+        entity_id: EntityId,
+        link_type_id: &VersionedUrl,
+    ) -> Result<usize, Report<QueryError>> {
+        let client = self.store.as_client().client();
+
+        // Concurrent transactions adding or removing links of the same type from the same entity
+        // have to be serialized, otherwise both could pass the cardinality check. The lock is
+        // released when the surrounding transaction ends.
+        client
+            .query(
+                "SELECT pg_advisory_xact_lock(hashtextextended($1::TEXT, 0));",
+                &[&format!(
+                    "{}:{}:{link_type_id}",
+                    entity_id.owned_by_id, entity_id.entity_uuid
+                )],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let link_type_id = EntityTypeId::from_url(link_type_id);
+        let count: i64 = client
+            .query_one(
+                "
+                    SELECT COUNT(*)
+                    FROM entity_has_left_entity
+                    WHERE left_web_id = $1
+                      AND left_entity_uuid = $2
+                      AND EXISTS (
+                          SELECT 1
+                          FROM entity_temporal_metadata
+                          JOIN entity_editions USING (entity_edition_id)
+                          JOIN entity_is_of_type USING (entity_edition_id)
+                          WHERE entity_temporal_metadata.web_id = entity_has_left_entity.web_id
+                            AND entity_temporal_metadata.entity_uuid
+                                = entity_has_left_entity.entity_uuid
+                            AND entity_temporal_metadata.draft_id IS NULL
+                            AND upper_inf(entity_temporal_metadata.transaction_time)
+                            AND entity_temporal_metadata.decision_time @> $4::timestamptz
+                            AND NOT entity_editions.archived
+                            AND (
+                                entity_is_of_type.entity_type_ontology_id = $3
+                                OR EXISTS (
+                                    SELECT 1 FROM closed_entity_type_inherits_from
+                                    WHERE source_entity_type_ontology_id
+                                          = entity_is_of_type.entity_type_ontology_id
+                                      AND target_entity_type_ontology_id = $3
+                                )
+                            )
+                      );
+                ",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    link_type_id.as_uuid(),
+                    &Timestamp::<DecisionTime>::now(),
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .get(0);
+
+        usize::try_from(count).change_context(QueryError)
+    }
 }
@@ -0,0 +1,719 @@
+use std::{error::Error, fmt, net::IpAddr, str::FromStr, sync::Arc, time::Duration};
+
+use authorization::NoAuthorization;
+use error_stack::{Context, Report, ResultExt};
+use futures::{stream, StreamExt};
+use graph_types::{account::AccountId, knowledge::entity::Entity, owned_by_id::OwnedById};
+use hex::FromHexError;
+use hmac::{Hmac, Mac};
+use postgres_types::{FromSql, ToSql};
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    header, redirect, Client,
+};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    error::Unspecified,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use time::OffsetDateTime;
+use type_system::url::VersionedUrl;
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::store::{
+    change::EntityChangeOperation, error::DeletionError, InsertionError, QueryError, StorePool,
+    UpdateError,
+};
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, FromSql, ToSql,
+)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(transparent)]
+#[postgres(transparent)]
+pub struct WebhookId(Uuid);
+
+impl WebhookId {
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    #[must_use]
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for WebhookId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, FromSql, ToSql,
+)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(transparent)]
+#[postgres(transparent)]
+pub struct WebhookDeliveryId(Uuid);
+
+impl WebhookDeliveryId {
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    #[must_use]
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for WebhookDeliveryId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateWebhookParams {
+    /// The web whose entities are observed.
+    pub owned_by_id: OwnedById,
+    /// The URL the payloads are posted to.
+    pub url: String,
+    /// The secret used to sign the payloads.
+    ///
+    /// The secret is stored encrypted and is never returned by the API.
+    pub secret: String,
+    /// If set, only changes to entities of this type are delivered.
+    #[serde(default)]
+    pub entity_type_id: Option<VersionedUrl>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[expect(
+    clippy::struct_field_names,
+    reason = "The ID is exposed as `webhookId` in the API"
+)]
+pub struct Webhook {
+    pub webhook_id: WebhookId,
+    pub owned_by_id: OwnedById,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_type_id: Option<VersionedUrl>,
+    pub created_by_id: AccountId,
+    #[serde(with = "codec::serde::time")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+    pub created_at: OffsetDateTime,
+}
+
+/// The body of the request sent to a webhook.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload<'e> {
+    pub delivery_id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub operation: EntityChangeOperation,
+    pub entity: &'e Entity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryAttempt {
+    #[serde(with = "codec::serde::time")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+    pub attempted_at: OffsetDateTime,
+    /// The status code returned by the webhook, if a response was received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    /// The reason the attempt failed, if no response was received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl WebhookDeliveryAttempt {
+    /// Returns if the webhook acknowledged the delivery with a success status code.
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.status_code
+            .is_some_and(|status_code| (200..300).contains(&status_code))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    pub delivery_id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub payload: serde_json::Value,
+    #[serde(with = "codec::serde::time")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = String))]
+    pub created_at: OffsetDateTime,
+    /// The time the webhook acknowledged the delivery.
+    #[serde(default, with = "codec::serde::time::option")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<String>))]
+    pub delivered_at: Option<OffsetDateTime>,
+    /// The time of the next attempt.
+    ///
+    /// This is `None` if the delivery succeeded or all attempts are exhausted.
+    #[serde(default, with = "codec::serde::time::option")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<String>))]
+    pub next_attempt_at: Option<OffsetDateTime>,
+    pub attempts: Vec<WebhookDeliveryAttempt>,
+}
+
+/// A delivery which is due and was claimed by a [`WebhookDispatcher`].
+#[derive(Debug, Clone)]
+pub struct PendingWebhookDelivery {
+    pub delivery_id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub url: String,
+    pub secret: String,
+    pub payload: serde_json::Value,
+    /// The number of attempts made before this one.
+    pub previous_attempts: usize,
+}
+
+/// Describes the API of a store implementation for webhooks.
+pub trait WebhookStore {
+    /// Subscribes a webhook to the entity changes in a web.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to manage the permissions of the web
+    /// - if the URL of the webhook is not valid
+    /// - if inserting the webhook failed
+    fn create_webhook(
+        &mut self,
+        actor_id: AccountId,
+        params: CreateWebhookParams,
+    ) -> impl Future<Output = Result<Webhook, Report<InsertionError>>> + Send;
+
+    /// Returns the webhooks subscribed to the entity changes in a web.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to manage the permissions of the web
+    /// - if reading the webhooks failed
+    fn get_webhooks(
+        &self,
+        actor_id: AccountId,
+        owned_by_id: OwnedById,
+    ) -> impl Future<Output = Result<Vec<Webhook>, Report<QueryError>>> + Send;
+
+    /// Removes a webhook including its delivery log.
+    ///
+    /// # Errors
+    ///
+    /// - if the webhook does not exist
+    /// - if the actor is not allowed to manage the permissions of the web of the webhook
+    /// - if removing the webhook failed
+    fn delete_webhook(
+        &mut self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> impl Future<Output = Result<(), Report<DeletionError>>> + Send;
+
+    /// Returns the delivery log of a webhook, starting with the most recent delivery.
+    ///
+    /// # Errors
+    ///
+    /// - if the webhook does not exist
+    /// - if the actor is not allowed to manage the permissions of the web of the webhook
+    /// - if reading the deliveries failed
+    fn get_webhook_deliveries(
+        &self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, Report<QueryError>>> + Send;
+
+    /// Claims up to `limit` deliveries which are due.
+    ///
+    /// A claimed delivery is not returned again before `lease` has passed, so multiple
+    /// dispatchers can share the same store. The outcome of the attempt is expected to be
+    /// reported using [`record_webhook_delivery_attempt`].
+    ///
+    /// # Errors
+    ///
+    /// - if claiming the deliveries failed
+    ///
+    /// [`record_webhook_delivery_attempt`]: Self::record_webhook_delivery_attempt
+    fn claim_webhook_deliveries(
+        &mut self,
+        limit: usize,
+        lease: Duration,
+    ) -> impl Future<Output = Result<Vec<PendingWebhookDelivery>, Report<QueryError>>> + Send;
+
+    /// Adds an attempt to the delivery log.
+    ///
+    /// If the attempt [`succeeded`], the delivery is marked as delivered. Otherwise, the delivery
+    /// is attempted again at `next_attempt_at`. If `next_attempt_at` is `None`, the delivery is
+    /// not attempted again.
+    ///
+    /// # Errors
+    ///
+    /// - if updating the delivery failed
+    ///
+    /// [`succeeded`]: WebhookDeliveryAttempt::succeeded
+    fn record_webhook_delivery_attempt(
+        &mut self,
+        delivery_id: WebhookDeliveryId,
+        attempt: WebhookDeliveryAttempt,
+        next_attempt_at: Option<OffsetDateTime>,
+    ) -> impl Future<Output = Result<(), Report<UpdateError>>> + Send;
+}
+
+/// Creates the signature of a webhook payload.
+///
+/// The signature is the hex-encoded HMAC-SHA256 of `"{timestamp}.{body}"` keyed with the secret of
+/// the webhook. It's sent in the `X-Hash-Signature` header, the timestamp in the
+/// `X-Hash-Timestamp` header.
+#[must_use]
+#[expect(clippy::missing_panics_doc, reason = "HMAC accepts keys of any size")]
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC should accept keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[derive(Debug)]
+pub struct WebhookSecretError;
+
+impl fmt::Display for WebhookSecretError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Could not encrypt or decrypt the webhook secret")
+    }
+}
+
+impl Context for WebhookSecretError {}
+
+/// The key used to encrypt the secrets of webhooks at rest.
+///
+/// The secrets are needed to sign the payloads, so they cannot be hashed. Instead, they are
+/// encrypted with AES-256-GCM using the ID of the webhook as associated data, so an encrypted
+/// secret cannot be moved to another webhook.
+#[derive(Clone)]
+pub struct WebhookSecretKey([u8; 32]);
+
+impl fmt::Debug for WebhookSecretKey {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("WebhookSecretKey(<redacted>)")
+    }
+}
+
+impl FromStr for WebhookSecretKey {
+    type Err = FromHexError;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0; 32];
+        hex::decode_to_slice(key, &mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl WebhookSecretKey {
+    fn aead_key(&self) -> Result<LessSafeKey, Report<WebhookSecretError>> {
+        Ok(LessSafeKey::new(
+            UnboundKey::new(&AES_256_GCM, &self.0)
+                .map_err(|Unspecified| Report::new(WebhookSecretError))?,
+        ))
+    }
+
+    /// Encrypts the secret of a webhook.
+    ///
+    /// The returned bytes consist of the nonce followed by the ciphertext and the tag.
+    pub(crate) fn encrypt(
+        &self,
+        webhook_id: WebhookId,
+        secret: &str,
+    ) -> Result<Vec<u8>, Report<WebhookSecretError>> {
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|Unspecified| Report::new(WebhookSecretError))?;
+
+        let mut ciphertext = secret.as_bytes().to_vec();
+        self.aead_key()?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(webhook_id.into_uuid().as_bytes()),
+                &mut ciphertext,
+            )
+            .map_err(|Unspecified| Report::new(WebhookSecretError))?;
+
+        let mut encrypted = nonce.to_vec();
+        encrypted.extend(ciphertext);
+        Ok(encrypted)
+    }
+
+    /// Decrypts a secret encrypted by [`encrypt`].
+    ///
+    /// [`encrypt`]: Self::encrypt
+    pub(crate) fn decrypt(
+        &self,
+        webhook_id: WebhookId,
+        encrypted: &[u8],
+    ) -> Result<String, Report<WebhookSecretError>> {
+        if encrypted.len() < NONCE_LEN {
+            return Err(Report::new(WebhookSecretError)
+                .attach_printable("the encrypted secret is too short"));
+        }
+        let (nonce, ciphertext) = encrypted.split_at(NONCE_LEN);
+        let mut ciphertext = ciphertext.to_vec();
+        let secret = self
+            .aead_key()?
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(nonce)
+                    .map_err(|Unspecified| Report::new(WebhookSecretError))?,
+                Aad::from(webhook_id.into_uuid().as_bytes()),
+                &mut ciphertext,
+            )
+            .map_err(|Unspecified| {
+                Report::new(WebhookSecretError)
+                    .attach_printable("the secret was not encrypted with this key")
+            })?;
+        String::from_utf8(secret.to_vec()).change_context(WebhookSecretError)
+    }
+}
+
+#[derive(Debug)]
+pub struct WebhookDispatchError;
+
+impl fmt::Display for WebhookDispatchError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Could not dispatch webhooks")
+    }
+}
+
+impl Context for WebhookDispatchError {}
+
+#[derive(Debug, Copy, Clone)]
+pub struct WebhookDispatcherConfig {
+    /// The maximum number of deliveries claimed at once.
+    pub batch_size: usize,
+    /// The maximum number of requests in flight.
+    pub concurrency: usize,
+    /// The maximum number of attempts per delivery.
+    pub max_attempts: usize,
+    /// The delay before the first retry. The delay is doubled with every further attempt.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_backoff: Duration,
+    /// The time after which a request to a webhook is aborted.
+    pub request_timeout: Duration,
+    /// Allows requests to addresses which are not publicly reachable.
+    ///
+    /// Webhooks are created by users, so by default the dispatcher refuses to send requests to
+    /// e.g. the loopback interface, link-local addresses like the metadata endpoint of cloud
+    /// providers, or private networks. This should only be enabled if all users are trusted.
+    pub allow_private_destinations: bool,
+}
+
+impl Default for WebhookDispatcherConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            concurrency: 16,
+            max_attempts: 8,
+            initial_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(60 * 60),
+            request_timeout: Duration::from_secs(10),
+            allow_private_destinations: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ForbiddenWebhookDestination(IpAddr);
+
+impl fmt::Display for ForbiddenWebhookDestination {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "`{}` is not a publicly reachable address", self.0)
+    }
+}
+
+impl Error for ForbiddenWebhookDestination {}
+
+fn is_public_address(address: IpAddr) -> bool {
+    let address = address.to_canonical();
+    address.is_global() && !address.is_multicast()
+}
+
+/// Resolves the hosts of webhooks and rejects them if any of their addresses is not publicly
+/// reachable.
+#[derive(Debug)]
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .collect::<Vec<_>>();
+            if let Some(address) = addresses
+                .iter()
+                .find(|address| !is_public_address(address.ip()))
+            {
+                return Err(ForbiddenWebhookDestination(address.ip()).into());
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Sends the pending webhook deliveries of a store.
+#[derive(Debug, Clone)]
+pub struct WebhookDispatcher {
+    client: Client,
+    config: WebhookDispatcherConfig,
+}
+
+impl WebhookDispatcher {
+    /// Creates a dispatcher using the provided `config`.
+    ///
+    /// # Errors
+    ///
+    /// - if the HTTP client could not be created
+    pub fn new(config: WebhookDispatcherConfig) -> Result<Self, Report<WebhookDispatchError>> {
+        // Redirects are not followed as they could lead to a destination which is not allowed.
+        let mut client = Client::builder()
+            .timeout(config.request_timeout)
+            .redirect(redirect::Policy::none());
+        if !config.allow_private_destinations {
+            client = client.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        Ok(Self {
+            client: client.build().change_context(WebhookDispatchError)?,
+            config,
+        })
+    }
+
+    /// Returns the time of the next attempt after `attempts` failed attempts.
+    fn next_attempt_at(&self, attempts: usize) -> Option<OffsetDateTime> {
+        if attempts >= self.config.max_attempts {
+            return None;
+        }
+        let exponent = u32::try_from(attempts.saturating_sub(1)).unwrap_or(u32::MAX);
+        let backoff = self
+            .config
+            .initial_backoff
+            .checked_mul(2_u32.checked_pow(exponent).unwrap_or(u32::MAX))
+            .map_or(self.config.max_backoff, |backoff| {
+                backoff.min(self.config.max_backoff)
+            });
+        Some(OffsetDateTime::now_utc() + backoff)
+    }
+
+    async fn send(
+        client: &Client,
+        allow_private_destinations: bool,
+        delivery: &PendingWebhookDelivery,
+        timestamp: i64,
+    ) -> Result<reqwest::StatusCode, Box<dyn Error + Send + Sync>> {
+        let body = delivery.payload.to_string();
+        let request = client
+            .post(&delivery.url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header("X-Hash-Webhook-Id", delivery.webhook_id.to_string())
+            .header("X-Hash-Delivery-Id", delivery.delivery_id.to_string())
+            .header("X-Hash-Timestamp", timestamp.to_string())
+            .header(
+                "X-Hash-Signature",
+                sign_webhook_payload(&delivery.secret, timestamp, body.as_bytes()),
+            )
+            .body(body)
+            .build()?;
+
+        // IP addresses in the URL are not resolved, so the resolver does not check them.
+        if !allow_private_destinations
+            && let Some(host) = request.url().host_str()
+            && let Ok(address) = host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+            && !is_public_address(address)
+        {
+            return Err(ForbiddenWebhookDestination(address).into());
+        }
+
+        Ok(client.execute(request).await?.status())
+    }
+
+    async fn attempt(
+        client: Client,
+        allow_private_destinations: bool,
+        delivery: PendingWebhookDelivery,
+    ) -> (PendingWebhookDelivery, WebhookDeliveryAttempt) {
+        let attempted_at = OffsetDateTime::now_utc();
+        let response = Self::send(
+            &client,
+            allow_private_destinations,
+            &delivery,
+            attempted_at.unix_timestamp(),
+        )
+        .await;
+
+        let attempt = match response {
+            Ok(status_code) => WebhookDeliveryAttempt {
+                attempted_at,
+                status_code: Some(status_code.as_u16()),
+                error: None,
+            },
+            Err(error) => {
+                // The underlying cause, e.g. a forbidden destination, is only known by the sources.
+                let mut message = error.to_string();
+                let mut source = error.source();
+                while let Some(cause) = source {
+                    message = format!("{message}: {cause}");
+                    source = cause.source();
+                }
+                WebhookDeliveryAttempt {
+                    attempted_at,
+                    status_code: None,
+                    error: Some(message),
+                }
+            }
+        };
+        (delivery, attempt)
+    }
+
+    /// Attempts all deliveries of the store which are due and returns the number of attempts.
+    ///
+    /// Failed deliveries are retried with an exponential backoff until the configured maximum
+    /// number of attempts is reached.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the deliveries from or writing the attempts to the store failed
+    pub async fn dispatch<S>(&self, store: &mut S) -> Result<usize, Report<WebhookDispatchError>>
+    where
+        S: WebhookStore + Send,
+    {
+        // A delivery is only claimed for as long as all its attempts could take at most.
+        let lease = self.config.request_timeout.saturating_mul(
+            u32::try_from(
+                self.config
+                    .batch_size
+                    .div_ceil(self.config.concurrency.max(1)),
+            )
+            .unwrap_or(u32::MAX)
+            .saturating_add(1),
+        );
+        let deliveries = store
+            .claim_webhook_deliveries(self.config.batch_size, lease)
+            .await
+            .change_context(WebhookDispatchError)?;
+
+        let client = self.client.clone();
+        let allow_private_destinations = self.config.allow_private_destinations;
+        let attempts = stream::iter(deliveries)
+            .map(move |delivery| {
+                Self::attempt(client.clone(), allow_private_destinations, delivery)
+            })
+            .buffer_unordered(self.config.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        let num_attempts = attempts.len();
+        for (delivery, attempt) in attempts {
+            if !attempt.succeeded() {
+                tracing::warn!(
+                    delivery_id = %delivery.delivery_id,
+                    webhook_id = %delivery.webhook_id,
+                    status_code = attempt.status_code,
+                    error = attempt.error,
+                    "Webhook delivery failed"
+                );
+            }
+            let next_attempt_at = self.next_attempt_at(delivery.previous_attempts + 1);
+            store
+                .record_webhook_delivery_attempt(delivery.delivery_id, attempt, next_attempt_at)
+                .await
+                .change_context(WebhookDispatchError)?;
+        }
+
+        Ok(num_attempts)
+    }
+
+    /// Dispatches the webhook deliveries of the stores in `pool` every `interval`.
+    pub async fn run<P>(self, pool: Arc<P>, interval: Duration) -> !
+    where
+        P: StorePool + Send + Sync,
+    {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+
+            let result = async {
+                let mut store = pool
+                    .acquire(NoAuthorization, None)
+                    .await
+                    .change_context(WebhookDispatchError)?;
+                // Drain the due deliveries before waiting for the next tick.
+                while self.dispatch(&mut store).await? == self.config.batch_size {}
+                Ok::<_, Report<WebhookDispatchError>>(())
+            }
+            .await;
+
+            if let Err(report) = result {
+                tracing::error!(error = ?report, "Could not dispatch webhooks");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn encrypt_secret() {
+        let key = KEY
+            .parse::<WebhookSecretKey>()
+            .expect("key should be valid");
+        let webhook_id = WebhookId::new(Uuid::new_v4());
+
+        let encrypted = key
+            .encrypt(webhook_id, "secret")
+            .expect("secret should be encrypted");
+        assert!(!encrypted.windows(6).any(|window| window == b"secret"));
+        assert_eq!(
+            key.decrypt(webhook_id, &encrypted)
+                .expect("secret should be decrypted"),
+            "secret"
+        );
+
+        // The secret is bound to the webhook
+        _ = key
+            .decrypt(WebhookId::new(Uuid::new_v4()), &encrypted)
+            .expect_err("secret of another webhook should not be decrypted");
+    }
+
+    #[test]
+    fn invalid_key() {
+        "0001"
+            .parse::<WebhookSecretKey>()
+            .expect_err("key is too short");
+        KEY.replace('0', "x")
+            .parse::<WebhookSecretKey>()
+            .expect_err("key is not hex-encoded");
+    }
+}
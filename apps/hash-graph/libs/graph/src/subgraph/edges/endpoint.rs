@@ -14,14 +14,17 @@ use crate::subgraph::{
 pub trait EdgeEndpointSet: IntoIterator<Item = Self::EdgeEndpoint> {
     type EdgeEndpoint: EdgeEndpoint;
 
-    fn insert(&mut self, target_id: Self::EdgeEndpoint);
+    /// Inserts the endpoint into the set.
+    ///
+    /// Returns `true` if the endpoint was not already present.
+    fn insert(&mut self, target_id: Self::EdgeEndpoint) -> bool;
 }
 
 impl<S: BuildHasher, E: EdgeEndpoint + Eq + Hash> EdgeEndpointSet for HashSet<E, S> {
     type EdgeEndpoint = E;
 
-    fn insert(&mut self, edge_target_id: Self::EdgeEndpoint) {
-        Self::insert(self, edge_target_id);
+    fn insert(&mut self, edge_target_id: Self::EdgeEndpoint) -> bool {
+        Self::insert(self, edge_target_id)
     }
 }
 
@@ -89,10 +92,10 @@ impl IntoIterator for EntityIdWithIntervalSet {
 impl EdgeEndpointSet for EntityIdWithIntervalSet {
     type EdgeEndpoint = EntityIdWithInterval;
 
-    fn insert(&mut self, edge_target_id: Self::EdgeEndpoint) {
+    fn insert(&mut self, edge_target_id: Self::EdgeEndpoint) -> bool {
         self.inner
             .entry(edge_target_id.entity_id)
             .or_default()
-            .insert(edge_target_id.interval);
+            .insert(edge_target_id.interval)
     }
 }
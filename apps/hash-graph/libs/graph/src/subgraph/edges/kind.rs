@@ -97,6 +97,28 @@ impl EdgeKind<PropertyTypeVertexId, DataTypeVertexId> for OntologyEdgeKind {
     }
 }
 
+impl EdgeKind<PropertyTypeVertexId, EntityTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<EntityTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<PropertyTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.property_type_to_entity_type
+    }
+}
+
+impl EdgeKind<DataTypeVertexId, PropertyTypeVertexId> for OntologyEdgeKind {
+    type EdgeSet = HashSet<PropertyTypeVertexId>;
+
+    fn subgraph_entry_mut<'a>(
+        &self,
+        edges: &'a mut Edges,
+    ) -> &'a mut AdjacencyList<DataTypeVertexId, Self, Self::EdgeSet> {
+        &mut edges.data_type_to_property_type
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -169,9 +191,11 @@ impl EdgeResolveDepths {
 #[serde(deny_unknown_fields)]
 pub struct OutgoingEdgeResolveDepth {
     pub outgoing: u8,
-    #[serde(default, skip)]
-    #[doc(hidden)]
-    /// This is not used yet, but will be used in the future to support incoming edges.
+    /// The depth of incoming edges to resolve.
+    ///
+    /// Incoming edges are only resolved for edges between ontology types, e.g. to find the entity
+    /// types which inherit from an entity type. It's currently ignored for `isOfType`.
+    #[serde(default)]
     pub incoming: u8,
 }
 
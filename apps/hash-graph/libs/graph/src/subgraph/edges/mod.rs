@@ -35,6 +35,7 @@ where
 {
     #[expect(clippy::type_complexity)]
     edges: HashMap<V::BaseId, BTreeMap<V::RevisionId, HashMap<EdgeData<K>, E>>>,
+    len: usize,
 }
 
 impl<V, K, E> AdjacencyList<V, K, E>
@@ -43,6 +44,17 @@ where
     V::BaseId: Clone,
     E: EdgeEndpointSet,
 {
+    /// Returns the number of edges in the adjacency list.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub fn insert(
         &mut self,
         vertex_id: &V,
@@ -56,7 +68,8 @@ where
         E: Default,
     {
         let vertex_base_id = vertex_id.base_id();
-        self.edges
+        let inserted = self
+            .edges
             .raw_entry_mut()
             .from_key(vertex_base_id)
             .or_insert_with(|| (vertex_base_id.clone(), BTreeMap::new()))
@@ -69,6 +82,9 @@ where
             })
             .or_default()
             .insert(right_endpoint);
+        if inserted {
+            self.len += 1;
+        }
     }
 
     pub fn into_flattened<O>(
@@ -116,6 +132,7 @@ where
     fn default() -> Self {
         Self {
             edges: HashMap::new(),
+            len: 0,
         }
     }
 }
@@ -131,6 +148,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AdjacencyList")
             .field("edges", &self.edges)
+            .field("len", &self.len)
             .finish()
     }
 }
@@ -149,4 +167,28 @@ pub struct Edges {
         AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<PropertyTypeVertexId>>,
     pub property_type_to_data_type:
         AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<DataTypeVertexId>>,
+    pub property_type_to_entity_type:
+        AdjacencyList<PropertyTypeVertexId, OntologyEdgeKind, HashSet<EntityTypeVertexId>>,
+    pub data_type_to_property_type:
+        AdjacencyList<DataTypeVertexId, OntologyEdgeKind, HashSet<PropertyTypeVertexId>>,
+}
+
+impl Edges {
+    /// Returns the total number of edges.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entity_to_entity.len()
+            + self.entity_to_entity_type.len()
+            + self.entity_type_to_entity_type.len()
+            + self.entity_type_to_property_type.len()
+            + self.property_type_to_property_type.len()
+            + self.property_type_to_data_type.len()
+            + self.property_type_to_entity_type.len()
+            + self.data_type_to_property_type.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
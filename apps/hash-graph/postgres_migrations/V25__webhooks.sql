@@ -0,0 +1,38 @@
+CREATE TABLE "webhooks" (
+    "webhook_id"                UUID        NOT NULL PRIMARY KEY,
+    "web_id"                    UUID        NOT NULL REFERENCES "webs",
+    "url"                       TEXT        NOT NULL,
+    -- The secret is encrypted with the key configured in the Graph, see `WebhookSecretKey`
+    "encrypted_secret"          BYTEA       NOT NULL,
+    "entity_type_base_url"      TEXT REFERENCES "base_urls",
+    "entity_type_version"       INT8,
+    "created_by_id"             UUID        NOT NULL REFERENCES "accounts",
+    "created_at"                TIMESTAMPTZ NOT NULL,
+    CHECK (("entity_type_base_url" IS NULL) = ("entity_type_version" IS NULL))
+);
+
+CREATE INDEX "webhooks_web_id_idx" ON "webhooks" ("web_id");
+
+CREATE TABLE "webhook_deliveries" (
+    "delivery_id"     UUID        NOT NULL PRIMARY KEY,
+    "webhook_id"      UUID        NOT NULL REFERENCES "webhooks" ON DELETE CASCADE,
+    "payload"         JSONB       NOT NULL,
+    "created_at"      TIMESTAMPTZ NOT NULL,
+    -- `NULL` if the delivery succeeded or no further attempts will be made
+    "next_attempt_at" TIMESTAMPTZ,
+    "delivered_at"    TIMESTAMPTZ
+);
+
+CREATE INDEX "webhook_deliveries_next_attempt_at_idx"
+    ON "webhook_deliveries" ("next_attempt_at")
+    WHERE "next_attempt_at" IS NOT NULL;
+
+CREATE TABLE "webhook_delivery_attempts" (
+    "delivery_id"  UUID        NOT NULL REFERENCES "webhook_deliveries" ON DELETE CASCADE,
+    "attempted_at" TIMESTAMPTZ NOT NULL,
+    "status_code"  INT2,
+    "error"        TEXT
+);
+
+CREATE INDEX "webhook_delivery_attempts_delivery_id_idx"
+    ON "webhook_delivery_attempts" ("delivery_id");
@@ -0,0 +1,5 @@
+-- The expression has to match the one emitted for the `matches` filter and the relevance sorting,
+-- otherwise the index is not used.
+CREATE INDEX "entity_editions_properties_search_idx"
+    ON "entity_editions"
+    USING GIN (jsonb_to_tsvector('english', "properties", '["string"]'));
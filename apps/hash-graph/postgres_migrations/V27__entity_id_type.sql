@@ -0,0 +1,5 @@
+-- Used to compare entities against a list of entity IDs in a single array parameter.
+CREATE TYPE "entity_id" AS (
+    "web_id" UUID,
+    "entity_uuid" UUID
+);
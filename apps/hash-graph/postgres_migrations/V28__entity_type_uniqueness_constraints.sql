@@ -0,0 +1,7 @@
+-- The property at `property_path` has to be unique across the entities of a web which are of the
+-- entity type or of an entity type inheriting from it. The path is stored as JSON pointer.
+CREATE TABLE "entity_type_uniqueness_constraints" (
+    "entity_type_base_url" TEXT NOT NULL REFERENCES "base_urls",
+    "property_path"        TEXT NOT NULL,
+    PRIMARY KEY ("entity_type_base_url", "property_path")
+);
@@ -0,0 +1,19 @@
+DROP VIEW entity_properties;
+CREATE VIEW entity_properties AS
+    SELECT
+        entity_edition_id,
+        array_agg(property_path) AS property_paths,
+        array_agg(confidence) AS confidences,
+        array_agg(provenance) AS provenances,
+        jsonb_object_agg(property_path, confidence)
+            FILTER (WHERE confidence IS NOT NULL) AS confidences_by_path
+    FROM entity_property
+    GROUP BY entity_edition_id;
+
+CREATE VIEW entity_property_sources AS
+    SELECT
+        entity_edition_id,
+        property_path,
+        source
+    FROM entity_property
+    CROSS JOIN LATERAL jsonb_array_elements(provenance -> 'sources') AS source;
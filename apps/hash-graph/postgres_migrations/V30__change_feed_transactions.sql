@@ -0,0 +1,95 @@
+-- The change feed orders records by the ID of the transaction which wrote them. Transaction IDs
+-- are assigned before a transaction commits, so a record written by a transaction with a lower ID
+-- might become visible after records written by a transaction with a higher ID. To not skip these
+-- records, the change feed only reads records written by transactions with an ID lower than the
+-- oldest transaction still running, i.e. `pg_snapshot_xmin(pg_current_snapshot())`.
+
+-- Only the first temporal metadata row of an entity edition is considered a change.
+CREATE TABLE "entity_edition_changes" (
+    "entity_edition_id" UUID                     NOT NULL PRIMARY KEY
+        REFERENCES "entity_editions" ON DELETE CASCADE,
+    "web_id"            UUID                     NOT NULL,
+    "entity_uuid"       UUID                     NOT NULL,
+    "draft_id"          UUID,
+    "transaction_id"    XID8                     NOT NULL DEFAULT pg_current_xact_id(),
+    "transaction_time"  TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX "entity_edition_changes_order_idx"
+    ON "entity_edition_changes" ("transaction_id", "transaction_time", "entity_edition_id");
+
+-- Supports looking up the change preceding an entity edition of the same entity.
+CREATE INDEX "entity_edition_changes_entity_idx"
+    ON "entity_edition_changes" ("web_id", "entity_uuid", "transaction_id", "transaction_time");
+
+-- Records written before the change feed used transaction IDs are attributed to this migration.
+INSERT INTO "entity_edition_changes" (
+    "entity_edition_id", "web_id", "entity_uuid", "draft_id", "transaction_time"
+)
+SELECT DISTINCT ON ("entity_edition_id")
+    "entity_edition_id",
+    "web_id",
+    "entity_uuid",
+    "draft_id",
+    lower("transaction_time")
+FROM "entity_temporal_metadata"
+WHERE NOT isempty("transaction_time")
+ORDER BY "entity_edition_id", lower("transaction_time");
+
+CREATE FUNCTION record_entity_edition_changes() RETURNS TRIGGER AS
+$$
+BEGIN
+    INSERT INTO entity_edition_changes (
+        entity_edition_id, web_id, entity_uuid, draft_id, transaction_time
+    )
+    SELECT DISTINCT ON (entity_edition_id)
+        entity_edition_id,
+        web_id,
+        entity_uuid,
+        draft_id,
+        lower(transaction_time)
+    FROM inserted_rows
+    WHERE NOT isempty(transaction_time)
+    ORDER BY entity_edition_id, lower(transaction_time)
+    ON CONFLICT DO NOTHING;
+    RETURN NULL;
+END
+$$ VOLATILE LANGUAGE plpgsql;
+
+CREATE TRIGGER record_entity_edition_changes_trigger
+    AFTER INSERT ON "entity_temporal_metadata"
+    REFERENCING NEW TABLE AS inserted_rows
+    FOR EACH STATEMENT EXECUTE PROCEDURE "record_entity_edition_changes"();
+
+-- Only the first temporal metadata row of an ontology type is considered a change.
+CREATE TABLE "ontology_changes" (
+    "ontology_id"      UUID                     NOT NULL PRIMARY KEY
+        REFERENCES "ontology_ids" ON DELETE CASCADE,
+    "transaction_id"   XID8                     NOT NULL DEFAULT pg_current_xact_id(),
+    "transaction_time" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX "ontology_changes_order_idx"
+    ON "ontology_changes" ("transaction_id", "transaction_time", "ontology_id");
+
+INSERT INTO "ontology_changes" ("ontology_id", "transaction_time")
+SELECT "ontology_id", min(lower("transaction_time"))
+FROM "ontology_temporal_metadata"
+GROUP BY "ontology_id";
+
+CREATE FUNCTION record_ontology_changes() RETURNS TRIGGER AS
+$$
+BEGIN
+    INSERT INTO ontology_changes (ontology_id, transaction_time)
+    SELECT ontology_id, min(lower(transaction_time))
+    FROM inserted_rows
+    GROUP BY ontology_id
+    ON CONFLICT DO NOTHING;
+    RETURN NULL;
+END
+$$ VOLATILE LANGUAGE plpgsql;
+
+CREATE TRIGGER record_ontology_changes_trigger
+    AFTER INSERT ON "ontology_temporal_metadata"
+    REFERENCING NEW TABLE AS inserted_rows
+    FOR EACH STATEMENT EXECUTE PROCEDURE "record_ontology_changes"();
@@ -16,7 +16,10 @@ use utoipa::{
     ToSchema,
 };
 
-use crate::account::{CreatedById, EditionArchivedById, EditionCreatedById};
+use crate::{
+    account::{CreatedById, EditionArchivedById, EditionCreatedById},
+    knowledge::entity::{EntityEditionId, EntityId},
+};
 
 /// The type of source material which was used to produce a value.
 // This enumeration is expected to grow over time, thus it's marked as non-exhaustive.
@@ -242,6 +245,19 @@ pub struct EntityEditionProvenance {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived_by_id: Option<EditionArchivedById>,
+    /// The entity this entity was merged into.
+    ///
+    /// This is only set on the edition which archived the entity as part of a merge. For a link
+    /// pointing at a merged duplicate, this is the link replacing it.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_into: Option<EntityId>,
+    /// The edition whose contents this edition restored.
+    ///
+    /// This is only set on editions created by reverting the entity.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverted_to: Option<EntityEditionId>,
     #[serde(flatten)]
     pub provided: ProvidedEntityEditionProvenance,
 }
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use serde::Serialize;
 
-use crate::knowledge::{Property, PropertyPath};
+use crate::knowledge::{Property, PropertyMetadata, PropertyPath};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -43,3 +43,52 @@ impl PropertyDiff<'_> {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op")]
+pub enum PropertyMetadataDiff<'e> {
+    Added {
+        path: PropertyPath<'e>,
+        added: Cow<'e, PropertyMetadata>,
+    },
+    Removed {
+        path: PropertyPath<'e>,
+        removed: Cow<'e, PropertyMetadata>,
+    },
+    Changed {
+        path: PropertyPath<'e>,
+        old: Cow<'e, PropertyMetadata>,
+        new: Cow<'e, PropertyMetadata>,
+    },
+}
+
+impl PropertyMetadataDiff<'_> {
+    #[must_use]
+    pub const fn path(&self) -> &PropertyPath<'_> {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn into_owned(self) -> PropertyMetadataDiff<'static> {
+        match self {
+            Self::Added { path, added } => PropertyMetadataDiff::Added {
+                path: path.into_owned(),
+                added: Cow::Owned(added.into_owned()),
+            },
+            Self::Removed { path, removed } => PropertyMetadataDiff::Removed {
+                path: path.into_owned(),
+                removed: Cow::Owned(removed.into_owned()),
+            },
+            Self::Changed { path, old, new } => PropertyMetadataDiff::Changed {
+                path: path.into_owned(),
+                old: Cow::Owned(old.into_owned()),
+                new: Cow::Owned(new.into_owned()),
+            },
+        }
+    }
+}
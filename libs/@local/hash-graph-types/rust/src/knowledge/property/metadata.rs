@@ -17,7 +17,8 @@ use utoipa::{
 };
 
 use crate::knowledge::{
-    property::provenance::PropertyProvenance, Confidence, PropertyPatchOperation, PropertyPath,
+    property::provenance::PropertyProvenance, Confidence, PropertyMetadataDiff,
+    PropertyPatchOperation, PropertyPath,
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
@@ -119,10 +120,49 @@ impl<'p> PropertyMetadataMap<'p> {
         self.len() == 0
     }
 
+    #[must_use]
+    pub fn get(&self, path: &PropertyPath<'p>) -> Option<&PropertyMetadata> {
+        self.values.get(path)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&PropertyPath<'p>, &PropertyMetadata)> {
         self.values.iter()
     }
 
+    /// Returns the changes of the metadata from `self` to `other`.
+    ///
+    /// The differences are ordered by their path.
+    #[must_use]
+    pub fn diff<'a>(&'a self, other: &'a Self) -> Vec<PropertyMetadataDiff<'a>> {
+        let mut diffs = Vec::new();
+        for (path, metadata) in &self.values {
+            match other.values.get(path) {
+                None => diffs.push(PropertyMetadataDiff::Removed {
+                    path: path.clone(),
+                    removed: Cow::Borrowed(metadata),
+                }),
+                Some(other_metadata) if other_metadata != metadata => {
+                    diffs.push(PropertyMetadataDiff::Changed {
+                        path: path.clone(),
+                        old: Cow::Borrowed(metadata),
+                        new: Cow::Borrowed(other_metadata),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for (path, metadata) in &other.values {
+            if !self.values.contains_key(path) {
+                diffs.push(PropertyMetadataDiff::Added {
+                    path: path.clone(),
+                    added: Cow::Borrowed(metadata),
+                });
+            }
+        }
+        diffs.sort_by(|lhs, rhs| lhs.path().as_ref().cmp(rhs.path().as_ref()));
+        diffs
+    }
+
     fn insert(
         &mut self,
         path: &PropertyPath<'p>,
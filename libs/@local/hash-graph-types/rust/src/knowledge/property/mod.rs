@@ -13,7 +13,7 @@ use thiserror::Error;
 use type_system::{url::BaseUrl, JsonSchemaValueType};
 
 pub use self::{
-    diff::PropertyDiff,
+    diff::{PropertyDiff, PropertyMetadataDiff},
     metadata::{PropertyMetadata, PropertyMetadataMap},
     object::PropertyObject,
     patch::PropertyPatchOperation,
@@ -49,6 +49,28 @@ pub enum EntityValidationError {
     InvalidLinkTargetId { target_types: Vec<VersionedUrl> },
     #[error("The property path is invalid: `{path:?}`")]
     InvalidPropertyPath { path: PropertyPath<'static> },
+    #[error("the validator was unable to read the outgoing links of the entity `{id}`")]
+    LinkRetrieval { id: EntityId },
+    #[error(
+        "The entity `{id}` has {actual} outgoing links of type `{link_type}` but at most \
+         {max_items} are allowed"
+    )]
+    TooManyLinks {
+        id: EntityId,
+        link_type: VersionedUrl,
+        max_items: usize,
+        actual: usize,
+    },
+    #[error(
+        "The entity `{id}` has {actual} outgoing links of type `{link_type}` but at least \
+         {min_items} are required"
+    )]
+    TooFewLinks {
+        id: EntityId,
+        link_type: VersionedUrl,
+        min_items: usize,
+        actual: usize,
+    },
 }
 
 impl<P> Schema<PropertyObject, P> for ClosedEntityType
@@ -190,6 +212,51 @@ where
     }
 }
 
+/// Reads the entity and resolves its closed entity type.
+async fn provide_closed_entity_type<P>(
+    provider: &P,
+    entity_id: EntityId,
+) -> Result<ClosedEntityType, Report<EntityValidationError>>
+where
+    P: EntityProvider + OntologyTypeProvider<ClosedEntityType> + Sync,
+{
+    let entity = provider
+        .provide_entity(entity_id)
+        .await
+        .change_context_lazy(|| EntityValidationError::EntityRetrieval { id: entity_id })?;
+
+    stream::iter(&entity.borrow().metadata.entity_type_ids)
+        .then(|entity_type| async {
+            Ok::<_, Report<EntityValidationError>>(
+                provider
+                    .provide_type(entity_type)
+                    .await
+                    .change_context_lazy(|| EntityValidationError::EntityTypeRetrieval {
+                        ids: entity.borrow().metadata.entity_type_ids.clone(),
+                    })?
+                    .borrow()
+                    .clone(),
+            )
+        })
+        .try_collect::<ClosedEntityType>()
+        .await
+}
+
+/// Counts the outgoing links of `entity_id` which are of the type `link_type_id`.
+async fn count_outgoing_links<P>(
+    provider: &P,
+    entity_id: EntityId,
+    link_type_id: &VersionedUrl,
+) -> Result<usize, Report<EntityValidationError>>
+where
+    P: EntityProvider + Sync,
+{
+    provider
+        .count_outgoing_links(entity_id, link_type_id)
+        .await
+        .change_context_lazy(|| EntityValidationError::LinkRetrieval { id: entity_id })
+}
+
 impl<P> Schema<LinkData, P> for ClosedEntityType
 where
     P: EntityProvider + EntityTypeProvider + Sync,
@@ -201,56 +268,15 @@ where
     async fn validate_value<'a>(
         &'a self,
         link_data: &'a LinkData,
-        _: ValidateEntityComponents,
+        components: ValidateEntityComponents,
         provider: &'a P,
     ) -> Result<(), Report<EntityValidationError>> {
         let mut status: Result<(), Report<EntityValidationError>> = Ok(());
 
-        let left_entity = provider
-            .provide_entity(link_data.left_entity_id)
-            .await
-            .change_context_lazy(|| EntityValidationError::EntityRetrieval {
-                id: link_data.left_entity_id,
-            })?;
-
-        let left_entity_type = stream::iter(&left_entity.borrow().metadata.entity_type_ids)
-            .then(|entity_type| async {
-                Ok::<_, Report<EntityValidationError>>(
-                    provider
-                        .provide_type(entity_type)
-                        .await
-                        .change_context_lazy(|| EntityValidationError::EntityTypeRetrieval {
-                            ids: left_entity.borrow().metadata.entity_type_ids.clone(),
-                        })?
-                        .borrow()
-                        .clone(),
-                )
-            })
-            .try_collect::<Self>()
-            .await?;
-
-        let right_entity = provider
-            .provide_entity(link_data.right_entity_id)
-            .await
-            .change_context_lazy(|| EntityValidationError::EntityRetrieval {
-                id: link_data.right_entity_id,
-            })?;
-
-        let right_entity_type = stream::iter(&right_entity.borrow().metadata.entity_type_ids)
-            .then(|entity_type| async {
-                Ok::<_, Report<EntityValidationError>>(
-                    provider
-                        .provide_type(entity_type)
-                        .await
-                        .change_context_lazy(|| EntityValidationError::EntityTypeRetrieval {
-                            ids: right_entity.borrow().metadata.entity_type_ids.clone(),
-                        })?
-                        .borrow()
-                        .clone(),
-                )
-            })
-            .try_collect::<Self>()
-            .await?;
+        let left_entity_type =
+            provide_closed_entity_type(provider, link_data.left_entity_id).await?;
+        let right_entity_type =
+            provide_closed_entity_type(provider, link_data.right_entity_id).await?;
 
         // We track that at least one link type was found to avoid reporting an error if no
         // link type was found.
@@ -264,6 +290,25 @@ where
             // At least one link type was found
             found_link_target = true;
 
+            if components.link_cardinality {
+                if let Some(max_items) = maybe_allowed_targets.array().max_items() {
+                    let actual =
+                        count_outgoing_links(provider, link_data.left_entity_id, link_type_id)
+                            .await?;
+                    if actual > max_items.get() {
+                        extend_report!(
+                            status,
+                            EntityValidationError::TooManyLinks {
+                                id: link_data.left_entity_id,
+                                link_type: link_type_id.clone(),
+                                max_items: max_items.get(),
+                                actual,
+                            }
+                        );
+                    }
+                }
+            }
+
             let Some(allowed_targets) = maybe_allowed_targets.array().items() else {
                 continue;
             };
@@ -304,6 +349,59 @@ where
     }
 }
 
+/// Validates that the left entity of a removed link still has enough outgoing links.
+///
+/// `link_type` is the closed entity type of the removed link entity. The removed link must not be
+/// counted by the provider anymore.
+///
+/// # Errors
+///
+/// - [`TooFewLinks`] if the entity type of the left entity requires more links
+///
+/// [`TooFewLinks`]: EntityValidationError::TooFewLinks
+pub async fn validate_link_removal<P>(
+    link_type: &ClosedEntityType,
+    link_data: &LinkData,
+    components: ValidateEntityComponents,
+    provider: &P,
+) -> Result<(), Report<EntityValidationError>>
+where
+    P: EntityProvider + OntologyTypeProvider<ClosedEntityType> + Sync,
+{
+    if !components.link_cardinality {
+        return Ok(());
+    }
+
+    let mut status: Result<(), Report<EntityValidationError>> = Ok(());
+
+    let left_entity_type = provide_closed_entity_type(provider, link_data.left_entity_id).await?;
+    for link_type_id in link_type.schemas.keys() {
+        let Some(min_items) = left_entity_type
+            .links
+            .links()
+            .get(link_type_id)
+            .and_then(|allowed_targets| allowed_targets.array().min_items())
+        else {
+            continue;
+        };
+
+        let actual = count_outgoing_links(provider, link_data.left_entity_id, link_type_id).await?;
+        if actual < min_items {
+            extend_report!(
+                status,
+                EntityValidationError::TooFewLinks {
+                    id: link_data.left_entity_id,
+                    link_type: link_type_id.clone(),
+                    min_items,
+                    actual,
+                }
+            );
+        }
+    }
+
+    status
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tests::validate_entity, ValidateEntityComponents};
@@ -5,7 +5,7 @@ pub mod error;
 
 pub use self::{
     data_type::{DataTypeConstraint, DataValidationError},
-    entity_type::EntityValidationError,
+    entity_type::{validate_link_removal, EntityValidationError},
     property_type::PropertyValidationError,
 };
 
@@ -69,6 +69,10 @@ const fn default_true() -> bool {
 #[derive(Debug, Copy, Clone, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag toggles an independent validation step"
+)]
 pub struct ValidateEntityComponents {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default = "default_true")]
@@ -79,6 +83,11 @@ pub struct ValidateEntityComponents {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default = "default_true")]
     pub num_items: bool,
+    /// Checks the number of outgoing links against the `minItems` and `maxItems` constraints of
+    /// the entity type of the left entity.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default = "default_true")]
+    pub link_cardinality: bool,
 }
 
 impl ValidateEntityComponents {
@@ -88,6 +97,7 @@ impl ValidateEntityComponents {
             link_data: true,
             required_properties: true,
             num_items: true,
+            link_cardinality: true,
         }
     }
 
@@ -96,6 +106,7 @@ impl ValidateEntityComponents {
         Self {
             num_items: false,
             required_properties: false,
+            link_cardinality: false,
             ..Self::full()
         }
     }
@@ -150,6 +161,15 @@ pub trait EntityProvider {
         &self,
         entity_id: EntityId,
     ) -> impl Future<Output = Result<impl Borrow<Entity> + Send + Sync, Report<impl Context>>> + Send;
+
+    /// Returns the number of outgoing links of the entity which are of the given link type.
+    ///
+    /// Archived links and drafts are not counted.
+    fn count_outgoing_links(
+        &self,
+        entity_id: EntityId,
+        link_type_id: &VersionedUrl,
+    ) -> impl Future<Output = Result<usize, Report<impl Context>>> + Send;
 }
 
 #[cfg(test)]
@@ -228,6 +248,26 @@ mod tests {
                 .get(&entity_id)
                 .ok_or_else(|| Report::new(InvalidEntity { id: entity_id }))
         }
+
+        #[expect(refining_impl_trait)]
+        async fn count_outgoing_links(
+            &self,
+            entity_id: EntityId,
+            link_type_id: &VersionedUrl,
+        ) -> Result<usize, Report<InvalidEntity>> {
+            Ok(self
+                .entities
+                .values()
+                .filter(|entity| {
+                    !entity.metadata.archived
+                        && entity.metadata.entity_type_ids.contains(link_type_id)
+                        && entity
+                            .link_data
+                            .as_ref()
+                            .is_some_and(|link_data| link_data.left_entity_id == entity_id)
+                })
+                .count())
+        }
     }
 
     impl EntityTypeProvider for Provider {
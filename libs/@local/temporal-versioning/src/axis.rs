@@ -24,7 +24,9 @@ pub enum TransactionTime {
     TransactionTime,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
 pub enum TimeAxis {
     DecisionTime,
     TransactionTime,
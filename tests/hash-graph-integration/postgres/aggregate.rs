@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+
+use authorization::AuthorizationApi;
+use graph::{
+    knowledge::EntityQueryPath,
+    ontology::EntityTypeQueryPath,
+    store::{
+        knowledge::{
+            AggregateEntitiesParams, CreateEntityParams, EntityAggregation, EntityAggregationGroup,
+        },
+        query::{Filter, JsonPath, PathToken},
+        AggregationFunction, EntityStore,
+    },
+    subgraph::{
+        edges::SharedEdgeKind,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{entity::ProvidedEntityEditionProvenance, PropertyMetadataMap},
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn insert<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::TEXT_V1,
+            ],
+            [entity_type::PERSON_V1, entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_entity_type = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_entity_type = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let entities = [
+        (entity::PERSON_ALICE_V1, &person_entity_type),
+        (entity::PERSON_BOB_V1, &person_entity_type),
+        (entity::PERSON_CHARLES_V1, &person_entity_type),
+        (entity::PAGE_V1, &page_entity_type),
+    ];
+
+    for (properties, type_id) in entities {
+        api.create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![type_id.clone()],
+                properties: serde_json::from_str(properties).expect("could not parse entity"),
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    api
+}
+
+fn age_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/age/"),
+    )])))
+}
+
+async fn aggregate<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    group_by: Vec<EntityQueryPath<'static>>,
+    aggregations: Vec<EntityAggregation<'static>>,
+) -> Vec<EntityAggregationGroup> {
+    api.aggregate_entities(
+        api.account_id,
+        AggregateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+            group_by,
+            aggregations,
+        },
+    )
+    .await
+    .expect("could not aggregate entities")
+}
+
+#[tokio::test]
+async fn without_groups() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    assert_eq!(
+        aggregate(
+            &api,
+            Vec::new(),
+            vec![
+                EntityAggregation {
+                    function: AggregationFunction::Count,
+                    path: None,
+                },
+                EntityAggregation {
+                    function: AggregationFunction::Maximum,
+                    path: Some(age_path()),
+                },
+            ],
+        )
+        .await,
+        [EntityAggregationGroup {
+            keys: Vec::new(),
+            values: vec![json!(4), json!(42)],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn group_by_entity_type() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    assert_eq!(
+        aggregate(
+            &api,
+            vec![EntityQueryPath::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path: EntityTypeQueryPath::BaseUrl,
+                inheritance_depth: Some(0),
+            }],
+            vec![
+                EntityAggregation {
+                    function: AggregationFunction::Count,
+                    path: None,
+                },
+                // Alice has no age, so she is only counted here but not taken into account below
+                EntityAggregation {
+                    function: AggregationFunction::Count,
+                    path: Some(age_path()),
+                },
+                EntityAggregation {
+                    function: AggregationFunction::Sum,
+                    path: Some(age_path()),
+                },
+                EntityAggregation {
+                    function: AggregationFunction::Average,
+                    path: Some(age_path()),
+                },
+            ],
+        )
+        .await,
+        [
+            EntityAggregationGroup {
+                keys: vec![json!(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/"
+                )],
+                values: vec![json!(1), json!(0), json!(null), json!(null)],
+            },
+            EntityAggregationGroup {
+                keys: vec![json!(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/"
+                )],
+                values: vec![json!(3), json!(2), json!(84), json!(42)],
+            },
+        ]
+    );
+}
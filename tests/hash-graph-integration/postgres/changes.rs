@@ -0,0 +1,253 @@
+use std::{iter::once, str::FromStr};
+
+use graph::store::{
+    change::{Change, ChangeEvent, ChangeStore, EntityChangeOperation, ReadChangesParams},
+    knowledge::{CreateEntityParams, PatchEntityParams},
+    EntityStore,
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::ProvidedEntityEditionProvenance, Property, PropertyMetadataMap,
+        PropertyPatchOperation, PropertyPathElement, PropertyProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, VersionedUrl};
+
+use crate::DatabaseTestWrapper;
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn read_changes() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id =
+        VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+            .expect("couldn't construct entity type id");
+
+    let alice = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_type_id.clone()],
+                properties: serde_json::from_str(entity::PERSON_ALICE_V1)
+                    .expect("could not parse entity"),
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+
+    let updated_alice = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: alice.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: once(PropertyPathElement::from(
+                        BaseUrl::new(
+                            "https://blockprotocol.org/@alice/types/property-type/age/".to_owned(),
+                        )
+                        .expect("couldn't construct Base URL"),
+                    ))
+                    .collect(),
+                    value: Property::Value(json!(43)),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                entity_type_ids: vec![],
+                archived: None,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let archived_alice = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: alice.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![],
+                entity_type_ids: vec![],
+                archived: Some(true),
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not archive entity");
+
+    let response = api
+        .read_changes(
+            api.account_id,
+            ReadChangesParams {
+                after: None,
+                limit: 10_000,
+            },
+        )
+        .await
+        .expect("could not read changes");
+    assert!(!response.has_more);
+    assert_eq!(
+        response.cursor,
+        response.changes.last().map(|event| event.cursor)
+    );
+    assert!(
+        response
+            .changes
+            .iter()
+            .zip(response.changes.iter().skip(1))
+            .all(|(previous, next)| previous.cursor < next.cursor)
+    );
+    assert!(response.changes.iter().any(|event| event.change
+        == Change::EntityType {
+            entity_type_id: person_type_id.clone()
+        }));
+
+    let entity_changes = response
+        .changes
+        .iter()
+        .filter_map(|event| match &event.change {
+            Change::Entity {
+                operation,
+                entity_id,
+                edition_id,
+                entity_type_ids,
+            } if *entity_id == alice.record_id.entity_id => {
+                assert_eq!(*entity_type_ids, [person_type_id.clone()]);
+                Some((*operation, *edition_id))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entity_changes,
+        [
+            (EntityChangeOperation::Create, alice.record_id.edition_id),
+            (
+                EntityChangeOperation::Update,
+                updated_alice.record_id.edition_id
+            ),
+            (
+                EntityChangeOperation::Archive,
+                archived_alice.record_id.edition_id
+            ),
+        ]
+    );
+
+    // Reading the feed page by page has to yield the same changes
+    let mut paginated_changes = Vec::<ChangeEvent>::new();
+    let mut cursor = None;
+    loop {
+        let page = api
+            .read_changes(
+                api.account_id,
+                ReadChangesParams {
+                    after: cursor,
+                    limit: 2,
+                },
+            )
+            .await
+            .expect("could not read changes");
+        paginated_changes.extend(page.changes);
+        cursor = page.cursor;
+        if !page.has_more {
+            break;
+        }
+    }
+    assert_eq!(paginated_changes, response.changes);
+
+    // Once caught up, the cursor is kept so later changes can be read from it
+    let caught_up = api
+        .read_changes(
+            api.account_id,
+            ReadChangesParams {
+                after: response.cursor,
+                limit: 10,
+            },
+        )
+        .await
+        .expect("could not read changes");
+    assert!(caught_up.changes.is_empty());
+    assert!(!caught_up.has_more);
+    assert_eq!(caught_up.cursor, response.cursor);
+
+    let unarchived_alice = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: alice.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![],
+                entity_type_ids: vec![],
+                archived: Some(false),
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not unarchive entity");
+
+    let next = api
+        .read_changes(
+            api.account_id,
+            ReadChangesParams {
+                after: caught_up.cursor,
+                limit: 10,
+            },
+        )
+        .await
+        .expect("could not read changes");
+    assert_eq!(
+        next.changes
+            .iter()
+            .map(|event| event.change.clone())
+            .collect::<Vec<_>>(),
+        [Change::Entity {
+            operation: EntityChangeOperation::Unarchive,
+            entity_id: alice.record_id.entity_id,
+            edition_id: unarchived_alice.record_id.edition_id,
+            entity_type_ids: vec![person_type_id],
+        }]
+    );
+}
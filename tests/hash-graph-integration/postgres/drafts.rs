@@ -1,12 +1,17 @@
 use authorization::AuthorizationApi;
 use graph::store::{
-    knowledge::{CreateEntityParams, PatchEntityParams},
+    error::{EntityIsLinked, LinkedEntityIsDraft},
+    knowledge::{
+        CreateEntityParams, DiscardDraftsParams, ListDraftsParams, PatchEntityParams,
+        PublishDraftsParams,
+    },
     EntityStore,
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
     knowledge::{
         entity::{EntityId, ProvidedEntityEditionProvenance},
+        link::LinkData,
         Property, PropertyMetadataMap, PropertyObject, PropertyPatchOperation, PropertyPath,
         PropertyProvenance,
     },
@@ -122,6 +127,7 @@ async fn initial_draft() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(bob()),
@@ -165,6 +171,7 @@ async fn initial_draft() {
             api.account_id,
             PatchEntityParams {
                 entity_id: updated_entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(charles()),
@@ -273,6 +280,7 @@ async fn no_initial_draft() {
                 api.account_id,
                 PatchEntityParams {
                     entity_id: entity.record_id.entity_id,
+                    expected_edition_ids: Vec::new(),
                     properties: vec![PropertyPatchOperation::Replace {
                         path: PropertyPath::default(),
                         value: Property::Object(bob()),
@@ -321,6 +329,7 @@ async fn no_initial_draft() {
                 api.account_id,
                 PatchEntityParams {
                     entity_id: updated_entity.record_id.entity_id,
+                    expected_edition_ids: Vec::new(),
                     properties: vec![PropertyPatchOperation::Replace {
                         path: PropertyPath::default(),
                         value: Property::Object(charles()),
@@ -415,6 +424,7 @@ async fn multiple_drafts() {
                 api.account_id,
                 PatchEntityParams {
                     entity_id: entity.record_id.entity_id,
+                    expected_edition_ids: Vec::new(),
                     properties: vec![PropertyPatchOperation::Replace {
                         path: PropertyPath::default(),
                         value: Property::Object(bob()),
@@ -466,6 +476,7 @@ async fn multiple_drafts() {
                 api.account_id,
                 PatchEntityParams {
                     entity_id: draft,
+                    expected_edition_ids: Vec::new(),
                     properties: vec![PropertyPatchOperation::Replace {
                         path: PropertyPath::default(),
                         value: Property::Object(charles()),
@@ -506,3 +517,160 @@ async fn multiple_drafts() {
         );
     }
 }
+
+async fn create_draft<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_type_id: VersionedUrl,
+    properties: PropertyObject,
+    link_data: Option<LinkData>,
+) -> EntityId {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![entity_type_id],
+            properties,
+            confidence: None,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data,
+            draft: true,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+    .record_id
+    .entity_id
+}
+
+fn friend_of_link(left_entity_id: EntityId, right_entity_id: EntityId) -> LinkData {
+    LinkData {
+        left_entity_id,
+        right_entity_id,
+        left_entity_confidence: None,
+        left_entity_provenance: PropertyProvenance::default(),
+        right_entity_confidence: None,
+        right_entity_provenance: PropertyProvenance::default(),
+    }
+}
+
+fn friend_of_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+#[tokio::test]
+async fn publish_linked_drafts() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice_id = create_draft(&mut api, person_entity_type_id(), alice(), None).await;
+    let bob_id = create_draft(&mut api, person_entity_type_id(), bob(), None).await;
+    let link_id = create_draft(
+        &mut api,
+        friend_of_entity_type_id(),
+        PropertyObject::empty(),
+        Some(friend_of_link(alice_id, bob_id)),
+    )
+    .await;
+
+    let drafts = api
+        .list_drafts(api.account_id, ListDraftsParams::default())
+        .await
+        .expect("could not list drafts");
+    assert_eq!(drafts.len(), 3);
+
+    let error = api
+        .publish_drafts(
+            api.account_id,
+            PublishDraftsParams {
+                entity_ids: vec![link_id],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("a link to drafts should not be published");
+    assert!(error.contains::<LinkedEntityIsDraft>());
+
+    let published = api
+        .publish_drafts(
+            api.account_id,
+            PublishDraftsParams {
+                entity_ids: vec![link_id, alice_id, bob_id],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not publish drafts");
+    assert_eq!(published.len(), 3);
+    assert!(
+        published
+            .iter()
+            .all(|metadata| metadata.record_id.entity_id.draft_id.is_none())
+    );
+    assert_eq!(
+        published[0].record_id.entity_id.entity_uuid,
+        link_id.entity_uuid
+    );
+
+    let drafts = api
+        .list_drafts(api.account_id, ListDraftsParams::default())
+        .await
+        .expect("could not list drafts");
+    assert!(drafts.is_empty());
+}
+
+#[tokio::test]
+async fn discard_drafts() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice_id = create_draft(&mut api, person_entity_type_id(), alice(), None).await;
+    let bob_id = create_draft(&mut api, person_entity_type_id(), bob(), None).await;
+    let link_id = create_draft(
+        &mut api,
+        friend_of_entity_type_id(),
+        PropertyObject::empty(),
+        Some(friend_of_link(alice_id, bob_id)),
+    )
+    .await;
+
+    let error = api
+        .discard_drafts(
+            api.account_id,
+            DiscardDraftsParams {
+                entity_ids: vec![alice_id],
+            },
+        )
+        .await
+        .expect_err("a linked draft should not be discarded on its own");
+    assert!(error.contains::<EntityIsLinked>());
+    assert!(check_entity_exists(&api, alice_id).await);
+
+    api.discard_drafts(
+        api.account_id,
+        DiscardDraftsParams {
+            entity_ids: vec![alice_id, link_id],
+        },
+    )
+    .await
+    .expect("could not discard drafts");
+    assert!(!check_entity_exists(&api, alice_id).await);
+    assert!(!check_entity_exists(&api, link_id).await);
+    assert!(check_entity_exists(&api, bob_id).await);
+
+    let drafts = api
+        .list_drafts(api.account_id, ListDraftsParams::default())
+        .await
+        .expect("could not list drafts");
+    assert_eq!(drafts.len(), 1);
+    assert_eq!(drafts[0].metadata.record_id.entity_id, bob_id);
+}
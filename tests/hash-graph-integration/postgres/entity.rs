@@ -1,10 +1,18 @@
+use std::borrow::Cow;
+
+use authorization::AuthorizationApi;
+use futures::TryStreamExt;
 use graph::{
+    knowledge::EntityQueryPath,
     store::{
+        error::EntityDoesNotExist,
         knowledge::{
-            CountEntitiesParams, CreateEntityParams, GetEntitiesParams, PatchEntityParams,
+            CorrectEntityParams, CountEntitiesParams, CreateEntityParams, DiffWebParams,
+            EntityRevertTarget, GetEntitiesParams, GetEntityHistoryParams, PatchEntityParams,
+            RevertEntityParams, StreamEntitiesParams,
         },
-        query::Filter,
-        EntityQuerySorting, EntityStore,
+        query::{Filter, FilterExpression, ParameterList},
+        EntityQuerySorting, EntityQuerySortingRecord, EntityStore, Ordering,
     },
     subgraph::temporal_axes::{
         PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
@@ -13,15 +21,19 @@ use graph::{
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
     knowledge::{
-        entity::ProvidedEntityEditionProvenance, Property, PropertyMetadataMap, PropertyObject,
-        PropertyPatchOperation, PropertyPath, PropertyProvenance,
+        entity::{EntityId, EntityMetadata, EntityUuid, ProvidedEntityEditionProvenance},
+        Property, PropertyMetadataMap, PropertyObject, PropertyPatchOperation, PropertyPath,
+        PropertyProvenance,
     },
     owned_by_id::OwnedById,
 };
-use temporal_versioning::{ClosedTemporalBound, LimitedTemporalBound, TemporalBound};
+use temporal_versioning::{
+    ClosedTemporalBound, DecisionTime, LimitedTemporalBound, TemporalBound, Timestamp,
+};
 use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+use uuid::Uuid;
 
-use crate::DatabaseTestWrapper;
+use crate::{DatabaseApi, DatabaseTestWrapper};
 
 #[tokio::test]
 async fn insert() {
@@ -90,6 +102,7 @@ async fn insert() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -161,6 +174,7 @@ async fn query() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -176,6 +190,172 @@ async fn query() {
     assert_eq!(queried_organizations[0].properties, organization);
 }
 
+#[tokio::test]
+async fn query_by_entity_ids() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let mut entity_ids = Vec::new();
+    for _ in 0..2 {
+        let metadata = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![VersionedUrl {
+                        base_url: BaseUrl::new(
+                            "https://blockprotocol.org/@alice/types/entity-type/organization/"
+                                .to_owned(),
+                        )
+                        .expect("couldn't construct Base URL"),
+                        version: OntologyTypeVersion::new(1),
+                    }],
+                    properties: organization.clone(),
+                    confidence: None,
+                    property_metadata: PropertyMetadataMap::default(),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(metadata.record_id.entity_id);
+    }
+
+    // The second ID refers to a web the entity is not part of
+    let requested_ids = [
+        entity_ids[0],
+        EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            ..entity_ids[1]
+        },
+    ];
+
+    let queried_organizations = api
+        .get_entities(
+            api.account_id,
+            GetEntitiesParams {
+                filter: Filter::In(
+                    FilterExpression::Path(EntityQueryPath::Uuid),
+                    ParameterList::EntityIds(Cow::Borrowed(&requested_ids)),
+                ),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    relevance: None,
+                    cursor: None,
+                },
+                limit: None,
+                include_count: false,
+                include_drafts: false,
+            },
+        )
+        .await
+        .expect("could not get entities")
+        .entities;
+
+    assert_eq!(queried_organizations.len(), 1);
+    assert_eq!(
+        queried_organizations[0].metadata.record_id.entity_id,
+        entity_ids[0]
+    );
+}
+
+#[tokio::test]
+async fn stream() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let mut entity_ids = Vec::new();
+    for _ in 0..3 {
+        let metadata = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![VersionedUrl {
+                        base_url: BaseUrl::new(
+                            "https://blockprotocol.org/@alice/types/entity-type/organization/"
+                                .to_owned(),
+                        )
+                        .expect("couldn't construct Base URL"),
+                        version: OntologyTypeVersion::new(1),
+                    }],
+                    properties: organization.clone(),
+                    confidence: None,
+                    property_metadata: PropertyMetadataMap::default(),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(metadata.record_id.entity_id);
+    }
+    entity_ids.sort_by_key(|entity_id| entity_id.entity_uuid);
+
+    let streamed_ids = api
+        .stream_entities(
+            api.account_id,
+            StreamEntitiesParams {
+                filter: Filter::All(Vec::new()),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: vec![EntityQuerySortingRecord {
+                        path: EntityQueryPath::Uuid,
+                        ordering: Ordering::Ascending,
+                        nulls: None,
+                    }],
+                    relevance: None,
+                    cursor: None,
+                },
+                include_drafts: false,
+            },
+        )
+        .await
+        .expect("could not start streaming entities")
+        .map_ok(|entity| entity.metadata.record_id.entity_id)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("could not stream entities");
+
+    assert_eq!(streamed_ids, entity_ids);
+}
+
 #[tokio::test]
 #[expect(clippy::too_many_lines)]
 async fn update() {
@@ -225,6 +405,7 @@ async fn update() {
             api.account_id,
             PatchEntityParams {
                 entity_id: v1_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     value: Property::Object(page_v2.clone()),
@@ -272,6 +453,7 @@ async fn update() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -304,6 +486,7 @@ async fn update() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -333,6 +516,7 @@ async fn update() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -346,3 +530,461 @@ async fn update() {
     let entity_v2 = response_v2.entities.pop().expect("no entity found");
     assert_eq!(entity_v2.properties.properties(), page_v2.properties());
 }
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn history() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_metadata = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }],
+                properties: page_v1,
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+
+    let v2_metadata = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: v1_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    value: Property::Object(page_v2),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                entity_type_ids: vec![],
+                archived: None,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let history_params = |after, limit| GetEntityHistoryParams {
+        entity_id: v1_metadata.record_id.entity_id,
+        temporal_axes: QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(Some(TemporalBound::Unbounded), None),
+        },
+        after,
+        limit,
+    };
+
+    let history = api
+        .get_entity_history(api.account_id, history_params(None, None))
+        .await
+        .expect("could not read entity history");
+    assert_eq!(history.editions.len(), 2);
+    assert!(history.cursor.is_none());
+    assert_eq!(
+        history.editions[0].edition_id,
+        v1_metadata.record_id.edition_id
+    );
+    assert_eq!(
+        history.editions[1].edition_id,
+        v2_metadata.record_id.edition_id
+    );
+    assert_eq!(history.editions[1].actor_id.as_account_id(), api.account_id);
+    assert!(!history.editions[0].property_diff.is_empty());
+    assert!(!history.editions[1].property_diff.is_empty());
+
+    let first_page = api
+        .get_entity_history(api.account_id, history_params(None, Some(1)))
+        .await
+        .expect("could not read entity history");
+    assert_eq!(first_page.editions.len(), 1);
+    assert_eq!(
+        first_page.editions[0].edition_id,
+        v1_metadata.record_id.edition_id
+    );
+    let cursor = first_page.cursor.expect("expected a cursor");
+
+    let second_page = api
+        .get_entity_history(api.account_id, history_params(Some(cursor), Some(1)))
+        .await
+        .expect("could not read entity history");
+    assert_eq!(second_page.editions.len(), 1);
+    assert_eq!(
+        second_page.editions[0].edition_id,
+        v2_metadata.record_id.edition_id
+    );
+    assert_eq!(
+        second_page.editions[0].property_diff,
+        history.editions[1].property_diff
+    );
+    assert!(second_page.cursor.is_none());
+
+    _ = api
+        .get_entity_history(
+            api.account_id,
+            GetEntityHistoryParams {
+                entity_id: EntityId {
+                    owned_by_id: v1_metadata.record_id.entity_id.owned_by_id,
+                    entity_uuid: EntityUuid::new(Uuid::new_v4()),
+                    draft_id: None,
+                },
+                ..history_params(None, None)
+            },
+        )
+        .await
+        .expect_err("history of a non-existing entity should not be readable");
+}
+
+async fn create_page<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    properties: PropertyObject,
+    decision_time: Option<Timestamp<DecisionTime>>,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time,
+            entity_type_ids: vec![VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }],
+            properties,
+            confidence: None,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+#[tokio::test]
+async fn diff_web() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let archived_metadata = create_page(&mut api, page_v1.clone(), None).await;
+    let modified_metadata = create_page(&mut api, page_v1.clone(), None).await;
+    let ClosedTemporalBound::Inclusive(first_transaction_time) = *modified_metadata
+        .temporal_versioning
+        .transaction_time
+        .start();
+
+    let created_metadata = create_page(&mut api, page_v1, None).await;
+    for (entity_id, properties, archived) in [
+        (
+            modified_metadata.record_id.entity_id,
+            vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                value: Property::Object(page_v2),
+                confidence: None,
+                provenance: PropertyProvenance::default(),
+            }],
+            None,
+        ),
+        (archived_metadata.record_id.entity_id, vec![], Some(true)),
+    ] {
+        api.patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id,
+                expected_edition_ids: Vec::new(),
+                properties,
+                entity_type_ids: vec![],
+                archived,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not update entity");
+    }
+
+    let diff_params = |after, limit| DiffWebParams {
+        owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        first_decision_time: None,
+        first_transaction_time: Some(first_transaction_time),
+        second_decision_time: None,
+        second_transaction_time: None,
+        entity_type_ids: None,
+        after,
+        limit,
+    };
+    let diff = api
+        .diff_web(api.account_id, diff_params(None, None))
+        .await
+        .expect("could not diff web");
+
+    assert_eq!(diff.created, [created_metadata.record_id.entity_id]);
+    assert_eq!(diff.archived, [archived_metadata.record_id.entity_id]);
+    assert_eq!(diff.modified.len(), 1);
+    assert_eq!(
+        diff.modified[0].entity_id,
+        modified_metadata.record_id.entity_id
+    );
+    assert!(!diff.modified[0].properties.is_empty());
+    assert!(diff.cursor.is_none());
+
+    let mut reported = 0;
+    let mut after = None;
+    loop {
+        let page = api
+            .diff_web(api.account_id, diff_params(after, Some(1)))
+            .await
+            .expect("could not diff web");
+        reported += page.created.len() + page.archived.len() + page.modified.len();
+        match page.cursor {
+            Some(cursor) => after = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(reported, 3);
+}
+
+#[tokio::test]
+async fn correct_decision_time_interval() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let decision_time = |timestamp: &str| -> Timestamp<DecisionTime> {
+        timestamp.parse().expect("could not parse timestamp")
+    };
+
+    let metadata = create_page(
+        &mut api,
+        page_v1.clone(),
+        Some(decision_time("2000-01-01T00:00:00Z")),
+    )
+    .await;
+    let entity_id = metadata.record_id.entity_id;
+
+    let corrected = api
+        .correct_entity(
+            api.account_id,
+            CorrectEntityParams {
+                entity_id,
+                decision_time_start: decision_time("2000-01-03T00:00:00Z"),
+                decision_time_end: decision_time("2000-01-05T00:00:00Z"),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    value: Property::Object(page_v2.clone()),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not correct entity");
+    assert_eq!(corrected.len(), 1);
+    assert_ne!(
+        corrected[0].record_id.edition_id,
+        metadata.record_id.edition_id
+    );
+
+    for (timestamp, expected_properties) in [
+        ("2000-01-02T00:00:00Z", &page_v1),
+        ("2000-01-03T00:00:00Z", &page_v2),
+        ("2000-01-04T00:00:00Z", &page_v2),
+        ("2000-01-05T00:00:00Z", &page_v1),
+        ("2000-01-06T00:00:00Z", &page_v1),
+    ] {
+        let entity = api
+            .get_entity_by_id(
+                api.account_id,
+                entity_id,
+                None,
+                Some(decision_time(timestamp)),
+            )
+            .await
+            .expect("could not read entity");
+        assert_eq!(
+            entity.properties.properties(),
+            expected_properties.properties(),
+            "unexpected properties at {timestamp}"
+        );
+    }
+
+    let error = api
+        .correct_entity(
+            api.account_id,
+            CorrectEntityParams {
+                entity_id,
+                decision_time_start: decision_time("2000-01-05T00:00:00Z"),
+                decision_time_end: decision_time("2000-01-03T00:00:00Z"),
+                properties: Vec::new(),
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("an empty interval should not be corrected");
+    assert!(!error.contains::<EntityDoesNotExist>());
+}
+
+#[tokio::test]
+async fn revert() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let v1_metadata = create_page(&mut api, page_v1.clone(), None).await;
+    let entity_id = v1_metadata.record_id.entity_id;
+
+    let v2_metadata = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    value: Property::Object(page_v2.clone()),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                entity_type_ids: vec![],
+                archived: None,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let reverted_metadata = api
+        .revert_entity(
+            api.account_id,
+            RevertEntityParams {
+                entity_id,
+                target: EntityRevertTarget::EditionId(v1_metadata.record_id.edition_id),
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not revert entity");
+    assert_ne!(
+        reverted_metadata.record_id.edition_id,
+        v1_metadata.record_id.edition_id
+    );
+    assert_eq!(
+        reverted_metadata.provenance.edition.reverted_to,
+        Some(v1_metadata.record_id.edition_id)
+    );
+    assert_eq!(
+        reverted_metadata.entity_type_ids,
+        v1_metadata.entity_type_ids
+    );
+
+    let reverted_entity = api
+        .get_entity_by_id(api.account_id, entity_id, None, None)
+        .await
+        .expect("could not read entity");
+    assert_eq!(
+        reverted_entity.properties.properties(),
+        page_v1.properties()
+    );
+
+    let ClosedTemporalBound::Inclusive(v2_transaction_time) =
+        *v2_metadata.temporal_versioning.transaction_time.start();
+    let reverted_again = api
+        .revert_entity(
+            api.account_id,
+            RevertEntityParams {
+                entity_id,
+                target: EntityRevertTarget::TransactionTime(v2_transaction_time),
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not revert entity");
+    assert_eq!(
+        reverted_again.provenance.edition.reverted_to,
+        Some(v2_metadata.record_id.edition_id)
+    );
+
+    let entity = api
+        .get_entity_by_id(api.account_id, entity_id, None, None)
+        .await
+        .expect("could not read entity");
+    assert_eq!(entity.properties.properties(), page_v2.properties());
+}
@@ -0,0 +1,222 @@
+use authorization::NoAuthorization;
+use graph::{
+    store::{
+        knowledge::{
+            CountEntitiesParams, CreateEntityParams, EraseEntitiesParams, LinkErasureBehavior,
+        },
+        query::Filter,
+        EntityStore,
+    },
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityMetadata, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        PropertyMetadataMap, PropertyObject, PropertyProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use temporal_versioning::TemporalBound;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn person_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn friend_of_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+async fn seed(
+    database: &mut DatabaseTestWrapper<NoAuthorization>,
+) -> DatabaseApi<'_, &mut NoAuthorization> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+async fn create_person(
+    api: &mut DatabaseApi<'_, &mut NoAuthorization>,
+    person: &'static str,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![person_type_id()],
+            properties: serde_json::from_str(person).expect("could not parse entity"),
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft: false,
+            relationships: [],
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+async fn create_friend_of(
+    api: &mut DatabaseApi<'_, &mut NoAuthorization>,
+    left: &EntityMetadata,
+    right: &EntityMetadata,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![friend_of_type_id()],
+            properties: PropertyObject::empty(),
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: Some(LinkData {
+                left_entity_id: left.record_id.entity_id,
+                right_entity_id: right.record_id.entity_id,
+                left_entity_confidence: None,
+                left_entity_provenance: PropertyProvenance::default(),
+                right_entity_confidence: None,
+                right_entity_provenance: PropertyProvenance::default(),
+            }),
+            draft: false,
+            relationships: [],
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create link")
+}
+
+async fn count_entities(
+    api: &DatabaseApi<'_, &mut NoAuthorization>,
+    metadata: &EntityMetadata,
+) -> usize {
+    api.count_entities(
+        api.account_id,
+        CountEntitiesParams {
+            filter: Filter::for_entity_by_entity_id(metadata.record_id.entity_id),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(Some(TemporalBound::Unbounded), None),
+            },
+            include_drafts: true,
+        },
+    )
+    .await
+    .expect("could not count entities")
+}
+
+#[tokio::test]
+async fn cascade() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let friend_of = create_friend_of(&mut api, &alice, &bob).await;
+
+    let response = api
+        .erase_entities(
+            api.account_id,
+            EraseEntitiesParams {
+                entity_ids: vec![alice.record_id.entity_id],
+                link_behavior: LinkErasureBehavior::Cascade,
+            },
+        )
+        .await
+        .expect("could not erase entity");
+
+    assert_eq!(response.erased_entity_ids.len(), 2);
+    assert!(
+        response
+            .erased_entity_ids
+            .contains(&alice.record_id.entity_id)
+    );
+    assert!(
+        response
+            .erased_entity_ids
+            .contains(&friend_of.record_id.entity_id)
+    );
+
+    assert_eq!(count_entities(&api, &alice).await, 0);
+    assert_eq!(count_entities(&api, &friend_of).await, 0);
+    assert_eq!(count_entities(&api, &bob).await, 1);
+}
+
+#[tokio::test]
+async fn restrict() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let friend_of = create_friend_of(&mut api, &alice, &bob).await;
+
+    _ = api
+        .erase_entities(
+            api.account_id,
+            EraseEntitiesParams {
+                entity_ids: vec![alice.record_id.entity_id],
+                link_behavior: LinkErasureBehavior::Restrict,
+            },
+        )
+        .await
+        .expect_err("could erase a linked entity");
+
+    assert_eq!(count_entities(&api, &alice).await, 1);
+    assert_eq!(count_entities(&api, &friend_of).await, 1);
+
+    let response = api
+        .erase_entities(
+            api.account_id,
+            EraseEntitiesParams {
+                entity_ids: vec![alice.record_id.entity_id, friend_of.record_id.entity_id],
+                link_behavior: LinkErasureBehavior::Restrict,
+            },
+        )
+        .await
+        .expect("could not erase entities");
+
+    assert_eq!(response.erased_entity_ids.len(), 2);
+    assert_eq!(count_entities(&api, &alice).await, 0);
+    assert_eq!(count_entities(&api, &friend_of).await, 0);
+    assert_eq!(count_entities(&api, &bob).await, 1);
+}
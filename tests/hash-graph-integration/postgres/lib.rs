@@ -9,16 +9,27 @@
     reason = "This should be enabled but it's currently too noisy"
 )]
 
+mod aggregate;
+mod changes;
 mod data_type;
 mod drafts;
 mod entity;
 mod entity_type;
+mod erase;
 mod links;
+mod merge;
+mod migration;
 mod multi_type;
 mod partial_updates;
 mod property_metadata;
 mod property_type;
+mod search;
 mod sorting;
+mod traversal;
+mod uniqueness;
+mod webhook;
+
+use std::time::Duration;
 
 use authorization::{
     schema::{
@@ -32,25 +43,40 @@ use authorization::{
     AuthorizationApi, NoAuthorization,
 };
 use error_stack::Result;
+use futures::Stream;
 use graph::{
     load_env,
     store::{
         account::{InsertAccountIdParams, InsertWebIdParams},
+        change::{ChangeStore, ReadChangesParams, ReadChangesResponse},
+        error::DeletionError,
         knowledge::{
-            CountEntitiesParams, CreateEntityParams, GetEntitiesParams, GetEntitiesResponse,
-            GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-            UpdateEntityEmbeddingsParams, ValidateEntityError, ValidateEntityParams,
+            AggregateEntitiesParams, CorrectEntityParams, CountEntitiesParams, CreateEntityParams,
+            DiffWebParams, DiffWebResult, DiscardDraftsParams, EntityAggregationGroup,
+            EraseEntitiesParams, EraseEntitiesResponse, FindEntityPathsParams,
+            FindEntityPathsResponse, GetEntitiesParams, GetEntitiesResponse,
+            GetEntityHistoryParams, GetEntityHistoryResponse, GetEntitySubgraphParams,
+            GetEntitySubgraphResponse, MergeEntitiesParams, MigrateEntitiesParams,
+            MigrateEntitiesResponse, PatchEntityParams, PublishDraftsParams, RevertEntityParams,
+            StreamEntitiesParams, UpdateEntityEmbeddingsParams, ValidateEntityError,
+            ValidateEntityParams,
         },
         ontology::{
             ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
             CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
-            GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
-            GetDataTypesResponse, GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse,
-            GetEntityTypesParams, GetEntityTypesResponse, GetPropertyTypeSubgraphParams,
-            GetPropertyTypeSubgraphResponse, GetPropertyTypesParams, GetPropertyTypesResponse,
-            UnarchiveDataTypeParams, UnarchiveEntityTypeParams, UnarchivePropertyTypeParams,
-            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams,
-            UpdateEntityTypesParams, UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            EntityTypeUniquenessConstraint, GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse,
+            GetDataTypesParams, GetDataTypesResponse, GetEntityTypeSubgraphParams,
+            GetEntityTypeSubgraphResponse, GetEntityTypeUniquenessConstraintsParams,
+            GetEntityTypeUniquenessConstraintsResponse, GetEntityTypesParams,
+            GetEntityTypesResponse, GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse,
+            GetPropertyTypesParams, GetPropertyTypesResponse, UnarchiveDataTypeParams,
+            UnarchiveEntityTypeParams, UnarchivePropertyTypeParams, UpdateDataTypeEmbeddingParams,
+            UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+        },
+        webhook::{
+            CreateWebhookParams, PendingWebhookDelivery, Webhook, WebhookDelivery,
+            WebhookDeliveryAttempt, WebhookDeliveryId, WebhookId, WebhookStore,
         },
         AccountStore, ConflictBehavior, DataTypeStore, DatabaseConnectionInfo, DatabaseType,
         EntityStore, EntityTypeStore, InsertionError, PostgresStore, PostgresStorePool,
@@ -70,6 +96,7 @@ use graph_types::{
 };
 use hash_tracing::logging::env_filter;
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use time::OffsetDateTime;
 use tokio_postgres::{NoTls, Transaction};
 use type_system::{DataType, EntityType, PropertyType};
 use uuid::Uuid;
@@ -161,7 +188,12 @@ impl DatabaseTestWrapper<NoAuthorization> {
 
         let pool = PostgresStorePool::new(&connection_info, NoTls)
             .await
-            .expect("could not connect to database");
+            .expect("could not connect to database")
+            .with_webhook_secret_key(
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+                    .parse()
+                    .expect("webhook secret key should be valid"),
+            );
 
         let connection = pool
             .acquire_owned(NoAuthorization, None)
@@ -473,6 +505,36 @@ impl<A: AuthorizationApi> EntityTypeStore for DatabaseApi<'_, A> {
             .update_entity_type_embeddings(actor_id, params)
             .await
     }
+
+    async fn add_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), InsertionError> {
+        self.store
+            .add_entity_type_uniqueness_constraint(actor_id, params)
+            .await
+    }
+
+    async fn get_entity_type_uniqueness_constraints(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityTypeUniquenessConstraintsParams,
+    ) -> Result<GetEntityTypeUniquenessConstraintsResponse, QueryError> {
+        self.store
+            .get_entity_type_uniqueness_constraints(actor_id, params)
+            .await
+    }
+
+    async fn remove_entity_type_uniqueness_constraint(
+        &mut self,
+        actor_id: AccountId,
+        params: EntityTypeUniquenessConstraint,
+    ) -> Result<(), DeletionError> {
+        self.store
+            .remove_entity_type_uniqueness_constraint(actor_id, params)
+            .await
+    }
 }
 
 impl<A> EntityStore for DatabaseApi<'_, A>
@@ -536,9 +598,17 @@ where
         Ok(response)
     }
 
-    async fn get_entity_subgraph(
+    async fn stream_entities(
         &self,
         actor_id: AccountId,
+        params: StreamEntitiesParams<'_>,
+    ) -> Result<impl Stream<Item = Result<Entity, QueryError>> + Send, QueryError> {
+        self.store.stream_entities(actor_id, params).await
+    }
+
+    async fn get_entity_subgraph(
+        &mut self,
+        actor_id: AccountId,
         mut params: GetEntitySubgraphParams<'_>,
     ) -> Result<GetEntitySubgraphResponse<'static>, QueryError> {
         let include_count = params.include_count;
@@ -578,6 +648,22 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<Vec<EntityAggregationGroup>, QueryError> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: AccountId,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, QueryError> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: AccountId,
@@ -590,12 +676,60 @@ where
             .await
     }
 
-    async fn patch_entity(
+    async fn diff_web(
+        &self,
+        actor_id: AccountId,
+        params: DiffWebParams,
+    ) -> Result<DiffWebResult<'static>, QueryError> {
+        self.store.diff_web(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, QueryError> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
+    async fn patch_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<PatchEntityParams>,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store.patch_entities(actor_id, params).await
+    }
+
+    async fn revert_entity(
         &mut self,
         actor_id: AccountId,
-        params: PatchEntityParams,
+        params: RevertEntityParams,
     ) -> Result<EntityMetadata, UpdateError> {
-        self.store.patch_entity(actor_id, params).await
+        self.store.revert_entity(actor_id, params).await
+    }
+
+    async fn correct_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: CorrectEntityParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store.correct_entity(actor_id, params).await
+    }
+
+    async fn publish_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: PublishDraftsParams,
+    ) -> Result<Vec<EntityMetadata>, UpdateError> {
+        self.store.publish_drafts(actor_id, params).await
+    }
+
+    async fn discard_drafts(
+        &mut self,
+        actor_id: AccountId,
+        params: DiscardDraftsParams,
+    ) -> Result<(), DeletionError> {
+        self.store.discard_drafts(actor_id, params).await
     }
 
     async fn update_entity_embeddings(
@@ -605,6 +739,95 @@ where
     ) -> Result<(), UpdateError> {
         self.store.update_entity_embeddings(actor_id, params).await
     }
+
+    async fn erase_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: EraseEntitiesParams,
+    ) -> Result<EraseEntitiesResponse, DeletionError> {
+        self.store.erase_entities(actor_id, params).await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn migrate_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntitiesParams,
+    ) -> Result<MigrateEntitiesResponse, UpdateError> {
+        self.store.migrate_entities(actor_id, params).await
+    }
+}
+
+impl<A: AuthorizationApi> ChangeStore for DatabaseApi<'_, A> {
+    async fn read_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, QueryError> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
+impl<A: AuthorizationApi> WebhookStore for DatabaseApi<'_, A> {
+    async fn create_webhook(
+        &mut self,
+        actor_id: AccountId,
+        params: CreateWebhookParams,
+    ) -> Result<Webhook, InsertionError> {
+        self.store.create_webhook(actor_id, params).await
+    }
+
+    async fn get_webhooks(
+        &self,
+        actor_id: AccountId,
+        owned_by_id: OwnedById,
+    ) -> Result<Vec<Webhook>, QueryError> {
+        self.store.get_webhooks(actor_id, owned_by_id).await
+    }
+
+    async fn delete_webhook(
+        &mut self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<(), DeletionError> {
+        self.store.delete_webhook(actor_id, webhook_id).await
+    }
+
+    async fn get_webhook_deliveries(
+        &self,
+        actor_id: AccountId,
+        webhook_id: WebhookId,
+    ) -> Result<Vec<WebhookDelivery>, QueryError> {
+        self.store
+            .get_webhook_deliveries(actor_id, webhook_id)
+            .await
+    }
+
+    async fn claim_webhook_deliveries(
+        &mut self,
+        limit: usize,
+        lease: Duration,
+    ) -> Result<Vec<PendingWebhookDelivery>, QueryError> {
+        self.store.claim_webhook_deliveries(limit, lease).await
+    }
+
+    async fn record_webhook_delivery_attempt(
+        &mut self,
+        delivery_id: WebhookDeliveryId,
+        attempt: WebhookDeliveryAttempt,
+        next_attempt_at: Option<OffsetDateTime>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .record_webhook_delivery_attempt(delivery_id, attempt, next_attempt_at)
+            .await
+    }
 }
 
 #[tokio::test]
@@ -197,6 +197,7 @@ async fn insert() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -404,6 +405,7 @@ async fn get_entity_links() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -595,6 +597,7 @@ async fn remove_link() {
         api.account_id,
         PatchEntityParams {
             entity_id: link_entity_metadata.record_id.entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             archived: Some(true),
             draft: None,
@@ -639,3 +642,166 @@ async fn remove_link() {
         > 0;
     assert!(!has_link);
 }
+
+const MONOGAMOUS_PERSON_V1: &str = r#"
+{
+  "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+  "kind": "entityType",
+  "$id": "https://blockprotocol.org/@alice/types/entity-type/monogamous-person/v/1",
+  "type": "object",
+  "title": "Monogamous Person",
+  "properties": {
+    "https://blockprotocol.org/@alice/types/property-type/name/": {
+      "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+    }
+  },
+  "links": {
+    "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1": {
+      "type": "array",
+      "items": {},
+      "minItems": 1,
+      "maxItems": 1
+    }
+  }
+}
+"#;
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn link_cardinality() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                MONOGAMOUS_PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/monogamous-person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let friend_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let alice_metadata = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_type_id.clone()],
+                properties: alice,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+
+    let bob_metadata = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_type_id.clone()],
+                properties: bob,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+    let friend_of = |draft| CreateEntityParams {
+        owned_by_id,
+        entity_uuid: None,
+        decision_time: None,
+        entity_type_ids: vec![friend_link_type_id.clone()],
+        properties: PropertyObject::empty(),
+        property_metadata: PropertyMetadataMap::default(),
+        link_data: Some(LinkData {
+            left_entity_id: alice_metadata.record_id.entity_id,
+            right_entity_id: bob_metadata.record_id.entity_id,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        }),
+        draft,
+        relationships: [],
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    };
+
+    let link_entity_metadata = api
+        .create_entity(api.account_id, friend_of(false))
+        .await
+        .expect("could not create link");
+
+    _ = api
+        .create_entity(api.account_id, friend_of(false))
+        .await
+        .expect_err("could create more links than allowed");
+
+    api.create_entity(api.account_id, friend_of(true))
+        .await
+        .expect("could not create draft link");
+
+    _ = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: link_entity_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                decision_time: None,
+                archived: Some(true),
+                draft: None,
+                entity_type_ids: vec![],
+                properties: vec![],
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could remove a required link");
+}
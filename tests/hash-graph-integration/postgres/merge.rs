@@ -0,0 +1,325 @@
+use authorization::AuthorizationApi;
+use graph::{
+    store::{
+        knowledge::{
+            CreateEntityParams, GetEntitiesParams, MergeEntitiesParams, PropertyMergeStrategy,
+        },
+        query::Filter,
+        EntityQuerySorting, EntityStore,
+    },
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityMetadata, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        PropertyMetadataMap, PropertyObject, PropertyProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn friend_of_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn name_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn age_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+async fn create_person<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, &mut A>,
+    person: &'static str,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![person_type_id()],
+            properties: serde_json::from_str(person).expect("could not parse entity"),
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft: false,
+            relationships: [],
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+async fn create_friend_of<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, &mut A>,
+    left: &EntityMetadata,
+    right: &EntityMetadata,
+    draft: bool,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![friend_of_type_id()],
+            properties: PropertyObject::empty(),
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: Some(LinkData {
+                left_entity_id: left.record_id.entity_id,
+                right_entity_id: right.record_id.entity_id,
+                left_entity_confidence: None,
+                left_entity_provenance: PropertyProvenance::default(),
+                right_entity_confidence: None,
+                right_entity_provenance: PropertyProvenance::default(),
+            }),
+            draft,
+            relationships: [],
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create link")
+}
+
+async fn merge<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, &mut A>,
+    target: &EntityMetadata,
+    duplicate: &EntityMetadata,
+) -> EntityMetadata {
+    api.merge_entities(
+        api.account_id,
+        MergeEntitiesParams {
+            target_entity_id: target.record_id.entity_id,
+            duplicate_entity_ids: vec![duplicate.record_id.entity_id],
+            conflict_strategy: PropertyMergeStrategy::PreferTarget,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not merge entities")
+}
+
+#[tokio::test]
+async fn merge_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let charles = create_person(&mut api, entity::PERSON_CHARLES_V1).await;
+
+    let friend_of = create_friend_of(&mut api, &charles, &bob, false).await;
+
+    let merged = merge(&mut api, &alice, &bob).await;
+    assert_eq!(merged.record_id.entity_id, alice.record_id.entity_id);
+
+    let merged_entity = api
+        .get_entity_by_id(api.account_id, alice.record_id.entity_id, None, None)
+        .await
+        .expect("could not read merged entity");
+    let properties = merged_entity.properties.properties();
+    assert_eq!(properties[&name_property_type_id()], json!("Alice"));
+    assert_eq!(properties[&age_property_type_id()], json!(42));
+
+    let duplicate = api
+        .get_entity_by_id(api.account_id, bob.record_id.entity_id, None, None)
+        .await
+        .expect("could not read duplicate");
+    assert!(duplicate.metadata.archived);
+    assert_eq!(
+        duplicate.metadata.provenance.edition.merged_into,
+        Some(alice.record_id.entity_id)
+    );
+
+    let link = api
+        .get_entity_by_id(api.account_id, friend_of.record_id.entity_id, None, None)
+        .await
+        .expect("could not read link");
+    assert!(link.metadata.archived);
+    let link_data = link.link_data.expect("link entity should have link data");
+    assert_eq!(link_data.right_entity_id, bob.record_id.entity_id);
+
+    let replacing_link_id = link
+        .metadata
+        .provenance
+        .edition
+        .merged_into
+        .expect("archived link should record the link replacing it");
+    let replacing_link = api
+        .get_entity_by_id(api.account_id, replacing_link_id, None, None)
+        .await
+        .expect("could not read replacing link");
+    assert!(!replacing_link.metadata.archived);
+    assert_eq!(
+        replacing_link.metadata.entity_type_ids,
+        [friend_of_type_id()]
+    );
+    let link_data = replacing_link
+        .link_data
+        .expect("link entity should have link data");
+    assert_eq!(link_data.left_entity_id, charles.record_id.entity_id);
+    assert_eq!(link_data.right_entity_id, alice.record_id.entity_id);
+}
+
+#[tokio::test]
+async fn merge_into_itself() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+
+    _ = api
+        .merge_entities(
+            api.account_id,
+            MergeEntitiesParams {
+                target_entity_id: alice.record_id.entity_id,
+                duplicate_entity_ids: vec![alice.record_id.entity_id],
+                conflict_strategy: PropertyMergeStrategy::PreferTarget,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could merge an entity into itself");
+}
+
+#[tokio::test]
+async fn merge_link_between_merged_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let friend_of = create_friend_of(&mut api, &bob, &alice, false).await;
+
+    merge(&mut api, &alice, &bob).await;
+
+    // The link would connect the merged entity to itself, so it is not replaced
+    let link = api
+        .get_entity_by_id(api.account_id, friend_of.record_id.entity_id, None, None)
+        .await
+        .expect("could not read link");
+    assert!(link.metadata.archived);
+    assert_eq!(link.metadata.provenance.edition.merged_into, None);
+}
+
+#[tokio::test]
+async fn merge_draft_link() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let charles = create_person(&mut api, entity::PERSON_CHARLES_V1).await;
+    let friend_of = create_friend_of(&mut api, &charles, &bob, true).await;
+    assert!(friend_of.record_id.entity_id.draft_id.is_some());
+
+    merge(&mut api, &alice, &bob).await;
+
+    let link = api
+        .get_entity_by_id(api.account_id, friend_of.record_id.entity_id, None, None)
+        .await
+        .expect("could not read link");
+    assert!(link.metadata.archived);
+    let replacing_link_id = link
+        .metadata
+        .provenance
+        .edition
+        .merged_into
+        .expect("archived link should record the link replacing it");
+
+    let replacing_links = api
+        .get_entities(
+            api.account_id,
+            GetEntitiesParams {
+                filter: Filter::for_entity_by_entity_id(replacing_link_id),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    relevance: None,
+                    cursor: None,
+                },
+                limit: None,
+                include_count: false,
+                include_drafts: true,
+            },
+        )
+        .await
+        .expect("could not read replacing link")
+        .entities;
+    assert_eq!(replacing_links.len(), 1);
+    let replacing_link = &replacing_links[0];
+    // The draft is replaced by a draft
+    assert!(
+        replacing_link
+            .metadata
+            .record_id
+            .entity_id
+            .draft_id
+            .is_some()
+    );
+    let link_data = replacing_link
+        .link_data
+        .as_ref()
+        .expect("link entity should have link data");
+    assert_eq!(link_data.left_entity_id, charles.record_id.entity_id);
+    assert_eq!(link_data.right_entity_id, alice.record_id.entity_id);
+}
@@ -0,0 +1,204 @@
+use std::{iter::once, str::FromStr};
+
+use authorization::AuthorizationApi;
+use graph::store::{
+    knowledge::{CreateEntityParams, MigrateEntitiesParams, PropertyMigration},
+    ontology::UpdateEntityTypesParams,
+    EntityStore, EntityTypeStore,
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityMetadata, ProvidedEntityEditionProvenance},
+        Property, PropertyMetadataMap, PropertyPath, PropertyPathElement,
+    },
+    ontology::ProvidedOntologyEditionProvenance,
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    EntityType,
+};
+
+use crate::{entity_type_relationships, DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::TEXT_V1, property_type::NAME_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+                entity_type::PAGE_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    api.update_entity_type(
+        api.account_id,
+        UpdateEntityTypesParams {
+            schema: serde_json::from_str::<EntityType>(entity_type::PAGE_V2)
+                .expect("could not parse entity type representation"),
+            label_property: None,
+            icon: None,
+            relationships: entity_type_relationships(),
+            provenance: ProvidedOntologyEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not update entity type");
+
+    api
+}
+
+fn page_v1_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/page/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn page_v2_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/page/v/2")
+        .expect("couldn't construct entity type id")
+}
+
+fn text_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/text/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn name_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+async fn create_page<A: AuthorizationApi>(api: &mut DatabaseApi<'_, &mut A>) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![page_v1_type_id()],
+            properties: serde_json::from_str(entity::PAGE_V1).expect("could not parse entity"),
+            confidence: None,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+#[tokio::test]
+async fn migrate() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let page = create_page(&mut api).await;
+
+    let text_path: PropertyPath =
+        once(PropertyPathElement::from(text_property_type_id())).collect();
+    let response = api
+        .migrate_entities(
+            api.account_id,
+            MigrateEntitiesParams {
+                source_entity_type_id: page_v1_type_id(),
+                target_entity_type_id: page_v2_type_id(),
+                property_migrations: vec![
+                    PropertyMigration::Drop {
+                        path: text_path.clone(),
+                    },
+                    PropertyMigration::SetDefault {
+                        path: text_path,
+                        value: Property::Value(json!("Migrated page")),
+                    },
+                ],
+                include_drafts: false,
+                dry_run: false,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not migrate entities");
+
+    assert_eq!(response.migrated_entity_ids, [page.record_id.entity_id]);
+    assert!(response.failures.is_empty());
+
+    let migrated_page = api
+        .get_entity_by_id(api.account_id, page.record_id.entity_id, None, None)
+        .await
+        .expect("could not read migrated entity");
+    assert_eq!(migrated_page.metadata.entity_type_ids, [page_v2_type_id()]);
+    assert_eq!(
+        migrated_page.properties.properties()[&text_property_type_id()],
+        Property::Value(json!("Migrated page"))
+    );
+}
+
+#[tokio::test]
+async fn dry_run() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let page = create_page(&mut api).await;
+
+    let response = api
+        .migrate_entities(
+            api.account_id,
+            MigrateEntitiesParams {
+                source_entity_type_id: page_v1_type_id(),
+                target_entity_type_id: page_v2_type_id(),
+                // `name` is not a property of the page type, so the migration has to fail
+                property_migrations: vec![PropertyMigration::Rename {
+                    from: text_property_type_id(),
+                    to: name_property_type_id(),
+                }],
+                include_drafts: false,
+                dry_run: true,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not migrate entities");
+
+    assert!(response.migrated_entity_ids.is_empty());
+    assert_eq!(response.failures.len(), 1);
+    assert_eq!(response.failures[0].entity_id, page.record_id.entity_id);
+
+    let response = api
+        .migrate_entities(
+            api.account_id,
+            MigrateEntitiesParams {
+                source_entity_type_id: page_v1_type_id(),
+                target_entity_type_id: page_v2_type_id(),
+                property_migrations: Vec::new(),
+                include_drafts: false,
+                dry_run: true,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not migrate entities");
+
+    assert_eq!(response.migrated_entity_ids, [page.record_id.entity_id]);
+    assert!(response.failures.is_empty());
+
+    let unchanged_page = api
+        .get_entity_by_id(api.account_id, page.record_id.entity_id, None, None)
+        .await
+        .expect("could not read entity");
+    assert_eq!(unchanged_page.metadata.record_id, page.record_id);
+    assert_eq!(unchanged_page.metadata.entity_type_ids, [page_v1_type_id()]);
+}
@@ -131,6 +131,7 @@ async fn initial_person() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -156,6 +157,7 @@ async fn initial_person() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![person_entity_type_id(), org_entity_type_id()],
                 properties: vec![],
@@ -184,6 +186,7 @@ async fn initial_person() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -206,6 +209,7 @@ async fn initial_person() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -270,6 +274,7 @@ async fn create_multi() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -292,6 +297,7 @@ async fn create_multi() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -318,6 +324,7 @@ async fn create_multi() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![person_entity_type_id()],
                 properties: vec![],
@@ -346,6 +353,7 @@ async fn create_multi() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
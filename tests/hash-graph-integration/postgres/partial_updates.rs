@@ -3,6 +3,7 @@ use std::{collections::HashSet, iter::once, str::FromStr};
 use authorization::AuthorizationApi;
 use graph::{
     store::{
+        error::{BatchIndex, EntityEditionMismatch},
         knowledge::{CreateEntityParams, GetEntitiesParams, PatchEntityParams},
         query::Filter,
         EntityQuerySorting, EntityStore,
@@ -21,6 +22,7 @@ use graph_types::{
 };
 use pretty_assertions::assert_eq;
 use serde_json::json;
+use temporal_versioning::ClosedTemporalBound;
 use type_system::url::{BaseUrl, VersionedUrl};
 
 use crate::{DatabaseApi, DatabaseTestWrapper};
@@ -112,6 +114,7 @@ async fn properties_add() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Add {
@@ -140,6 +143,7 @@ async fn properties_add() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -189,6 +193,7 @@ async fn properties_remove() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Remove {
@@ -214,6 +219,7 @@ async fn properties_remove() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -261,6 +267,7 @@ async fn properties_replace() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![PropertyPatchOperation::Replace {
@@ -289,6 +296,7 @@ async fn properties_replace() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -339,6 +347,7 @@ async fn properties_move() {
             api.account_id,
             PatchEntityParams {
                 entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![],
                 properties: vec![PropertyPatchOperation::Move {
@@ -365,6 +374,7 @@ async fn properties_move() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![
@@ -406,6 +416,7 @@ async fn properties_move() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -457,6 +468,7 @@ async fn properties_copy() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![
@@ -502,6 +514,7 @@ async fn properties_copy() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -555,6 +568,7 @@ async fn type_ids() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![],
             properties: vec![],
@@ -578,6 +592,7 @@ async fn type_ids() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -600,6 +615,7 @@ async fn type_ids() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![person_entity_type_id(), org_entity_type_id()],
             properties: vec![],
@@ -623,6 +639,7 @@ async fn type_ids() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -649,6 +666,7 @@ async fn type_ids() {
         api.account_id,
         PatchEntityParams {
             entity_id,
+            expected_edition_ids: Vec::new(),
             decision_time: None,
             entity_type_ids: vec![person_entity_type_id()],
             properties: vec![],
@@ -672,6 +690,7 @@ async fn type_ids() {
                 },
                 sorting: EntityQuerySorting {
                     paths: Vec::new(),
+                    relevance: None,
                     cursor: None,
                 },
                 limit: None,
@@ -687,3 +706,203 @@ async fn type_ids() {
 
     assert_eq!(entity.metadata.entity_type_ids, [person_entity_type_id()],);
 }
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn bulk() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entities = api
+        .create_entities(
+            api.account_id,
+            vec![
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![person_entity_type_id()],
+                    properties: alice(),
+                    confidence: None,
+                    property_metadata: PropertyMetadataMap::default(),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![person_entity_type_id()],
+                    properties: alice(),
+                    confidence: None,
+                    property_metadata: PropertyMetadataMap::default(),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+            ],
+        )
+        .await
+        .expect("could not create entities");
+    let entity_ids = entities
+        .iter()
+        .map(|entity| entity.record_id.entity_id)
+        .collect::<Vec<_>>();
+
+    let add_age = |entity_id, age| PatchEntityParams {
+        entity_id,
+        expected_edition_ids: Vec::new(),
+        decision_time: None,
+        entity_type_ids: vec![],
+        properties: vec![PropertyPatchOperation::Add {
+            path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+            value: Property::Value(age),
+            confidence: None,
+            provenance: PropertyProvenance::default(),
+        }],
+        draft: None,
+        archived: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    };
+
+    let patched = api
+        .patch_entities(
+            api.account_id,
+            vec![
+                add_age(entity_ids[0], json!(30)),
+                add_age(entity_ids[1], json!(40)),
+            ],
+        )
+        .await
+        .expect("could not patch entities");
+    assert_eq!(patched.len(), 2);
+
+    // The second patch is invalid, so the first patch must not be applied either.
+    let report = api
+        .patch_entities(
+            api.account_id,
+            vec![
+                add_age(entity_ids[0], json!(31)),
+                add_age(entity_ids[1], json!("forty-one")),
+            ],
+        )
+        .await
+        .expect_err("could patch entity with invalid property");
+    assert_eq!(
+        report.request_ref::<BatchIndex>().next(),
+        Some(&BatchIndex(1))
+    );
+
+    for (entity_id, expected_age) in entity_ids.into_iter().zip([30, 40]) {
+        let entities = api
+            .get_entities(
+                api.account_id,
+                GetEntitiesParams {
+                    filter: Filter::for_entity_by_entity_id(entity_id),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    },
+                    sorting: EntityQuerySorting {
+                        paths: Vec::new(),
+                        relevance: None,
+                        cursor: None,
+                    },
+                    limit: None,
+                    include_count: false,
+                    include_drafts: false,
+                },
+            )
+            .await
+            .expect("could not get entity")
+            .entities;
+        assert_eq!(entities.len(), 1, "unexpected number of entities found");
+        let entity = entities.into_iter().next().unwrap();
+
+        let properties = entity.properties.properties();
+        assert_eq!(properties[&age_property_type_id()], json!(expected_age));
+    }
+}
+
+#[tokio::test]
+async fn expected_edition() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_entity_type_id()],
+                properties: alice(),
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.record_id.entity_id;
+    let first_edition_id = entity.record_id.edition_id;
+
+    let ClosedTemporalBound::Inclusive(first_decision_time) =
+        *entity.temporal_versioning.decision_time.start();
+
+    let add_age = |expected_edition_ids, decision_time, age| PatchEntityParams {
+        entity_id,
+        expected_edition_ids,
+        decision_time,
+        entity_type_ids: vec![],
+        properties: vec![PropertyPatchOperation::Add {
+            path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+            value: Property::Value(json!(age)),
+            confidence: None,
+            provenance: PropertyProvenance::default(),
+        }],
+        draft: None,
+        archived: None,
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    };
+
+    let second_edition_id = api
+        .patch_entity(api.account_id, add_age(vec![first_edition_id], None, 30))
+        .await
+        .expect("could not patch entity")
+        .record_id
+        .edition_id;
+    assert_ne!(first_edition_id, second_edition_id);
+
+    let report = api
+        .patch_entity(api.account_id, add_age(vec![first_edition_id], None, 31))
+        .await
+        .expect_err("could patch entity based on an outdated edition");
+    assert!(report.contains::<EntityEditionMismatch>());
+
+    // The first edition is still valid at its decision time but it's not the latest edition.
+    let report = api
+        .patch_entity(
+            api.account_id,
+            add_age(vec![first_edition_id], Some(first_decision_time), 31),
+        )
+        .await
+        .expect_err("could patch entity based on an outdated edition");
+    assert!(report.contains::<EntityEditionMismatch>());
+
+    api.patch_entity(
+        api.account_id,
+        add_age(vec![first_edition_id, second_edition_id], None, 31),
+    )
+    .await
+    .expect("could not patch entity");
+}
@@ -1,21 +1,34 @@
 use std::{borrow::Cow, collections::HashMap, iter::once, str::FromStr};
 
 use authorization::AuthorizationApi;
-use graph::store::{
-    knowledge::{CreateEntityParams, PatchEntityParams},
-    EntityStore,
+use graph::{
+    knowledge::EntityQueryPath,
+    store::{
+        knowledge::{
+            CreateEntityParams, DiffEntityParams, EntityConfidenceDiff, GetEntitiesParams,
+            PatchEntityParams,
+        },
+        query::{Filter, FilterExpression, Parameter},
+        EntityQuerySorting, EntityQuerySortingRecord, EntityStore, NullOrdering, Ordering,
+    },
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
 use graph_types::{
     knowledge::{
         entity::{Location, ProvidedEntityEditionProvenance, SourceProvenance, SourceType},
-        Confidence, Property, PropertyMetadata, PropertyMetadataMap, PropertyObject,
-        PropertyPatchOperation, PropertyPath, PropertyPathElement, PropertyProvenance,
+        Confidence, Property, PropertyMetadata, PropertyMetadataDiff, PropertyMetadataMap,
+        PropertyObject, PropertyPatchOperation, PropertyPath, PropertyPathElement,
+        PropertyProvenance,
     },
     owned_by_id::OwnedById,
 };
 use pretty_assertions::assert_eq;
+use serde::Deserialize;
 use serde_json::json;
+use temporal_versioning::ClosedTemporalBound;
 use type_system::url::{BaseUrl, VersionedUrl};
 
 use crate::{DatabaseApi, DatabaseTestWrapper};
@@ -182,6 +195,7 @@ async fn initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: Vec::new(),
                 entity_type_ids: vec![],
                 archived: None,
@@ -201,6 +215,7 @@ async fn initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: Vec::new(),
                 entity_type_ids: vec![],
                 archived: None,
@@ -255,6 +270,7 @@ async fn no_initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: Vec::new(),
                 entity_type_ids: vec![],
                 archived: None,
@@ -274,6 +290,7 @@ async fn no_initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: Vec::new(),
                 entity_type_ids: vec![],
                 archived: None,
@@ -296,6 +313,7 @@ async fn no_initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: vec![PropertyPatchOperation::Replace {
                     path: once(PropertyPathElement::from(name_property_type_id())).collect(),
                     value: Property::Value(json!("Alice")),
@@ -324,6 +342,7 @@ async fn no_initial_metadata() {
             api.account_id,
             PatchEntityParams {
                 entity_id: entity.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
                 properties: Vec::new(),
                 entity_type_ids: vec![],
                 archived: None,
@@ -379,6 +398,7 @@ async fn properties_add() {
             api.account_id,
             PatchEntityParams {
                 entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![],
                 properties: vec![PropertyPatchOperation::Add {
@@ -443,6 +463,7 @@ async fn properties_remove() {
             api.account_id,
             PatchEntityParams {
                 entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![],
                 properties: vec![
@@ -488,6 +509,7 @@ async fn properties_remove() {
             api.account_id,
             PatchEntityParams {
                 entity_id,
+                expected_edition_ids: Vec::new(),
                 decision_time: None,
                 entity_type_ids: vec![],
                 properties: vec![PropertyPatchOperation::Remove {
@@ -504,3 +526,325 @@ async fn properties_remove() {
 
     assert!(updated_entity.properties.is_empty());
 }
+
+#[tokio::test]
+async fn diff() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![person_entity_type_id()],
+                properties: alice(),
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.record_id.entity_id;
+
+    let path: PropertyPath = once(PropertyPathElement::from(age_property_type_id())).collect();
+    let updated_entity = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id,
+                expected_edition_ids: Vec::new(),
+                decision_time: None,
+                entity_type_ids: vec![],
+                properties: vec![PropertyPatchOperation::Add {
+                    path: path.clone(),
+                    value: Property::Value(json!(30)),
+                    confidence: Some(confidence(0.5)),
+                    provenance: property_provenance_a(),
+                }],
+                draft: None,
+                archived: Some(true),
+                confidence: Some(confidence(0.8)),
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not patch entity");
+
+    let ClosedTemporalBound::Inclusive(first_transaction_time) =
+        *entity.temporal_versioning.transaction_time.start();
+    let ClosedTemporalBound::Inclusive(second_transaction_time) =
+        *updated_entity.temporal_versioning.transaction_time.start();
+    let diff = api
+        .diff_entity(
+            api.account_id,
+            DiffEntityParams {
+                first_entity_id: entity_id,
+                first_decision_time: None,
+                first_transaction_time: Some(first_transaction_time),
+                second_entity_id: entity_id,
+                second_decision_time: None,
+                second_transaction_time: Some(second_transaction_time),
+            },
+        )
+        .await
+        .expect("could not diff entity");
+
+    assert_eq!(diff.properties.len(), 1);
+    assert_eq!(
+        diff.property_metadata,
+        [PropertyMetadataDiff::Added {
+            path,
+            added: Cow::Owned(PropertyMetadata {
+                confidence: Some(confidence(0.5)),
+                provenance: property_provenance_a(),
+            }),
+        }]
+    );
+    assert!(diff.entity_type_ids.is_empty());
+    assert_eq!(diff.archived, Some(true));
+    assert_eq!(diff.draft, None);
+    assert_eq!(
+        diff.confidence,
+        Some(EntityConfidenceDiff {
+            old: None,
+            new: Some(confidence(0.8)),
+        })
+    );
+    assert_eq!(diff.link_data, None);
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn query_property_metadata() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let mut entity_ids = Vec::new();
+    for (name_confidence, provenance) in [
+        (Some(0.8), property_provenance_a()),
+        (Some(0.2), property_provenance_b()),
+        (None, property_provenance_b()),
+    ] {
+        let path = PropertyPath::from_iter([PropertyPathElement::from(name_property_type_id())]);
+        let metadata = PropertyMetadataMap::new(HashMap::from([(
+            path,
+            PropertyMetadata {
+                confidence: name_confidence.map(confidence),
+                provenance,
+            },
+        )]));
+        let entity = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![person_entity_type_id()],
+                    properties: alice(),
+                    confidence: None,
+                    property_metadata: metadata,
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(entity.record_id.entity_id);
+    }
+
+    let name_confidence_path =
+        EntityQueryPath::deserialize(json!(["propertyConfidence", name_property_type_id()]))
+            .expect("could not parse query path");
+    let source_uri_path =
+        EntityQueryPath::deserialize(json!(["propertySource", "location", "uri"]))
+            .expect("could not parse query path");
+
+    let get_entity_ids = |filter, paths| GetEntitiesParams {
+        filter,
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        sorting: EntityQuerySorting {
+            paths,
+            relevance: None,
+            cursor: None,
+        },
+        limit: None,
+        include_drafts: false,
+        include_count: false,
+    };
+
+    let uncertain_entities = api
+        .get_entities(
+            api.account_id,
+            get_entity_ids(
+                Filter::Less(
+                    FilterExpression::Path(name_confidence_path.clone()),
+                    FilterExpression::Parameter(Parameter::F64(0.5)),
+                ),
+                Vec::new(),
+            ),
+        )
+        .await
+        .expect("could not get entities")
+        .entities;
+    assert_eq!(
+        uncertain_entities
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>(),
+        [entity_ids[1]]
+    );
+
+    let sourced_entities = api
+        .get_entities(
+            api.account_id,
+            get_entity_ids(
+                Filter::Equal(
+                    Some(FilterExpression::Path(source_uri_path)),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        "https://alice.com/",
+                    )))),
+                ),
+                Vec::new(),
+            ),
+        )
+        .await
+        .expect("could not get entities")
+        .entities;
+    assert_eq!(
+        sourced_entities
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>(),
+        [entity_ids[0]]
+    );
+
+    let review_queue = api
+        .get_entities(
+            api.account_id,
+            get_entity_ids(
+                Filter::All(Vec::new()),
+                vec![EntityQuerySortingRecord {
+                    path: name_confidence_path,
+                    ordering: Ordering::Ascending,
+                    nulls: Some(NullOrdering::Last),
+                }],
+            ),
+        )
+        .await
+        .expect("could not get entities")
+        .entities;
+    assert_eq!(
+        review_queue
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>(),
+        [entity_ids[1], entity_ids[0], entity_ids[2]]
+    );
+}
+
+#[tokio::test]
+async fn query_shared_property_source() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let name_path = PropertyPath::from_iter([PropertyPathElement::from(name_property_type_id())]);
+    let age_path = PropertyPath::from_iter([PropertyPathElement::from(age_property_type_id())]);
+    let properties: PropertyObject = serde_json::from_value(json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": "Alice",
+        "https://blockprotocol.org/@alice/types/property-type/age/": 42,
+    }))
+    .expect("could not parse properties");
+
+    let mut entity_ids = Vec::new();
+    for provenance in [property_provenance_a(), property_provenance_b()] {
+        let metadata = PropertyMetadataMap::new(HashMap::from([
+            (
+                name_path.clone(),
+                PropertyMetadata {
+                    confidence: None,
+                    provenance: provenance.clone(),
+                },
+            ),
+            (
+                age_path.clone(),
+                PropertyMetadata {
+                    confidence: None,
+                    provenance,
+                },
+            ),
+        ]));
+        let entity = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: vec![person_entity_type_id()],
+                    properties: properties.clone(),
+                    confidence: None,
+                    property_metadata: metadata,
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: ProvidedEntityEditionProvenance::default(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(entity.record_id.entity_id);
+    }
+
+    let source_uri_path =
+        EntityQueryPath::deserialize(json!(["propertySource", "location", "uri"]))
+            .expect("could not parse query path");
+    let sourced_entities = api
+        .get_entities(
+            api.account_id,
+            GetEntitiesParams {
+                filter: Filter::Equal(
+                    Some(FilterExpression::Path(source_uri_path)),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        "https://alice.com/",
+                    )))),
+                ),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    relevance: None,
+                    cursor: None,
+                },
+                limit: None,
+                include_drafts: false,
+                include_count: true,
+            },
+        )
+        .await
+        .expect("could not get entities");
+
+    assert_eq!(
+        sourced_entities
+            .entities
+            .iter()
+            .map(|entity| entity.metadata.record_id.entity_id)
+            .collect::<Vec<_>>(),
+        [entity_ids[0]]
+    );
+    assert_eq!(sourced_entities.count, Some(1));
+}
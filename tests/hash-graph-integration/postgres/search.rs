@@ -0,0 +1,290 @@
+use std::borrow::Cow;
+
+use authorization::AuthorizationApi;
+use graph::{
+    knowledge::EntityQueryPath,
+    store::{
+        knowledge::{CreateEntityParams, GetEntitiesParams, GetEntitiesResponse},
+        query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+        EntityQuerySorting, EntityQuerySortingRecord, EntityStore, Ordering,
+    },
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{Entity, ProvidedEntityEditionProvenance},
+        PropertyMetadataMap, PropertyObject,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn page(text: &str) -> PropertyObject {
+    serde_json::from_value(json!({
+        "https://blockprotocol.org/@alice/types/property-type/text/": text
+    }))
+    .expect("could not parse entity")
+}
+
+async fn insert<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::TEXT_V1,
+            ],
+            [entity_type::PERSON_V1, entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_entity_type = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_entity_type = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let entities = [
+        (
+            serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity"),
+            &person_entity_type,
+        ),
+        (
+            serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity"),
+            &person_entity_type,
+        ),
+        (page("A page about the garden"), &page_entity_type),
+        (
+            page("Gardening: how to plan a garden and which gardens to visit"),
+            &page_entity_type,
+        ),
+    ];
+
+    for (properties, type_id) in entities {
+        api.create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: vec![type_id.clone()],
+                properties,
+                confidence: None,
+                property_metadata: PropertyMetadataMap::default(),
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    api
+}
+
+const fn matches(query: &str) -> Filter<'_, Entity> {
+    Filter::Matches(
+        FilterExpression::Path(EntityQueryPath::Properties(None)),
+        FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(query))),
+    )
+}
+
+async fn search<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    filter: Filter<'_, Entity>,
+    relevance: Option<&str>,
+    limit: Option<usize>,
+) -> Vec<PropertyObject> {
+    let mut cursor = None;
+    let mut entities = Vec::new();
+
+    loop {
+        let GetEntitiesResponse {
+            entities: new_entities,
+            cursor: new_cursor,
+            ..
+        } = api
+            .get_entities(
+                api.account_id,
+                GetEntitiesParams {
+                    filter: filter.clone(),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    },
+                    sorting: EntityQuerySorting {
+                        paths: vec![EntityQuerySortingRecord {
+                            path: EntityQueryPath::Uuid,
+                            ordering: Ordering::Ascending,
+                            nulls: None,
+                        }],
+                        relevance: relevance.map(|query| Cow::Owned(query.to_owned())),
+                        cursor: cursor.take(),
+                    },
+                    limit,
+                    include_count: false,
+                    include_drafts: false,
+                },
+            )
+            .await
+            .expect("could not get entities");
+
+        let num_entities = new_entities.len();
+        entities.extend(new_entities.into_iter().map(|entity| entity.properties));
+
+        match (limit, new_cursor) {
+            (Some(limit), Some(new_cursor)) if num_entities == limit => {
+                cursor = Some(new_cursor);
+            }
+            _ => break,
+        }
+    }
+
+    entities
+}
+
+#[tokio::test]
+async fn matches_words() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    assert_eq!(
+        search(&api, matches("alice"), None, None).await,
+        [
+            serde_json::from_str::<PropertyObject>(entity::PERSON_ALICE_V1)
+                .expect("could not parse entity")
+        ]
+    );
+    assert!(
+        search(&api, matches("alice bob"), None, None)
+            .await
+            .is_empty()
+    );
+    assert_eq!(
+        search(&api, matches("alice or bob"), None, None)
+            .await
+            .len(),
+        2
+    );
+    // Words are stemmed, so `pages` matches `page`
+    assert_eq!(
+        search(&api, matches("pages"), None, None).await,
+        [page("A page about the garden")]
+    );
+    assert_eq!(
+        search(&api, matches("garden -page"), None, None).await,
+        [page(
+            "Gardening: how to plan a garden and which gardens to visit"
+        )]
+    );
+    // Property keys are not part of the searched text
+    assert!(
+        search(&api, matches("blockprotocol"), None, None)
+            .await
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn sort_by_relevance() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    let expected = [
+        page("Gardening: how to plan a garden and which gardens to visit"),
+        page("A page about the garden"),
+    ];
+
+    assert_eq!(
+        search(&api, matches("garden"), Some("garden"), None).await,
+        expected
+    );
+    for limit in 1..=expected.len() {
+        assert_eq!(
+            search(&api, matches("garden"), Some("garden"), Some(limit)).await,
+            expected
+        );
+    }
+}
+
+fn page_text_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/text/"),
+    )])))
+}
+
+#[tokio::test]
+async fn case_insensitive() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    assert_eq!(
+        search(
+            &api,
+            Filter::StartsWithCaseInsensitive(
+                FilterExpression::Path(page_text_path()),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("gardening:"))),
+            ),
+            None,
+            None
+        )
+        .await,
+        [page(
+            "Gardening: how to plan a garden and which gardens to visit"
+        )]
+    );
+    assert_eq!(
+        search(
+            &api,
+            Filter::ContainsSegmentCaseInsensitive(
+                FilterExpression::Path(page_text_path()),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("PAGE"))),
+            ),
+            None,
+            None
+        )
+        .await,
+        [page("A page about the garden")]
+    );
+}
+
+#[tokio::test]
+async fn regex() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = insert(&mut database).await;
+
+    let mut filter = Filter::Regex(
+        FilterExpression::Path(page_text_path()),
+        FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("^a .* GARDEN$"))),
+    );
+    filter
+        .convert_parameters()
+        .expect("pattern should be valid");
+
+    assert_eq!(
+        search(&api, filter, None, None).await,
+        [page("A page about the garden")]
+    );
+}
@@ -72,6 +72,7 @@ async fn test_root_sorting<A: AuthorizationApi>(
                     },
                     sorting: EntityQuerySorting {
                         paths: sorting_paths.clone(),
+                        relevance: None,
                         cursor: cursor.take(),
                     },
                     limit: Some(chunk_size),
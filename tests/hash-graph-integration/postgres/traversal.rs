@@ -0,0 +1,578 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use authorization::AuthorizationApi;
+use graph::{
+    knowledge::EntityQueryPath,
+    ontology::EntityTypeQueryPath,
+    store::{
+        error::SubgraphBudgetExceeded,
+        knowledge::{
+            CreateEntityParams, EntityTraversalFilters, FindEntityPathsParams,
+            GetEntitySubgraphParams, SubgraphBudget, SubgraphBudgetExceededBehavior,
+        },
+        ontology::{GetDataTypeSubgraphParams, GetEntityTypeSubgraphParams},
+        query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+        DataTypeStore, EntityQuerySorting, EntityStore, EntityTypeStore,
+    },
+    subgraph::{
+        edges::{EdgeResolveDepths, GraphResolveDepths, OutgoingEdgeResolveDepth, SharedEdgeKind},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityId, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        PropertyMetadataMap, PropertyObject, PropertyProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn type_id(name: &str) -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(format!(
+            "https://blockprotocol.org/@alice/types/entity-type/{name}/"
+        ))
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_type_id: VersionedUrl,
+    properties: PropertyObject,
+    link: Option<(EntityId, EntityId)>,
+) -> EntityId {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![entity_type_id],
+            properties,
+            confidence: None,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: link.map(|(left_entity_id, right_entity_id)| LinkData {
+                left_entity_id,
+                right_entity_id,
+                left_entity_confidence: None,
+                left_entity_provenance: PropertyProvenance::default(),
+                right_entity_confidence: None,
+                right_entity_provenance: PropertyProvenance::default(),
+            }),
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+    .record_id
+    .entity_id
+}
+
+struct Graph {
+    alice: EntityId,
+    bob: EntityId,
+    charles: EntityId,
+    friend_of: EntityId,
+    acquaintance_of: EntityId,
+}
+
+/// Creates the graph `Bob <-friend-of- Alice -acquaintance-of-> Charles`.
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> (DatabaseApi<'_, &mut A>, Graph) {
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person = |properties: &str| -> PropertyObject {
+        serde_json::from_str(properties).expect("could not parse entity")
+    };
+
+    let alice = create_entity(
+        &mut api,
+        type_id("person"),
+        person(entity::PERSON_ALICE_V1),
+        None,
+    )
+    .await;
+    let bob = create_entity(
+        &mut api,
+        type_id("person"),
+        person(entity::PERSON_BOB_V1),
+        None,
+    )
+    .await;
+    let charles = create_entity(
+        &mut api,
+        type_id("person"),
+        person(entity::PERSON_CHARLES_V1),
+        None,
+    )
+    .await;
+    let friend_of = create_entity(
+        &mut api,
+        type_id("friend-of"),
+        PropertyObject::empty(),
+        Some((alice, bob)),
+    )
+    .await;
+    let acquaintance_of = create_entity(
+        &mut api,
+        type_id("acquaintance-of"),
+        PropertyObject::empty(),
+        Some((alice, charles)),
+    )
+    .await;
+
+    (
+        api,
+        Graph {
+            alice,
+            bob,
+            charles,
+            friend_of,
+            acquaintance_of,
+        },
+    )
+}
+
+fn subgraph_params(
+    root: EntityId,
+    traversal_filters: EntityTraversalFilters<'_>,
+    budget: SubgraphBudget,
+) -> GetEntitySubgraphParams<'_> {
+    GetEntitySubgraphParams {
+        filter: Filter::for_entity_by_entity_id(root),
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        graph_resolve_depths: GraphResolveDepths {
+            has_left_entity: EdgeResolveDepths {
+                incoming: 1,
+                outgoing: 0,
+            },
+            has_right_entity: EdgeResolveDepths {
+                incoming: 0,
+                outgoing: 1,
+            },
+            ..GraphResolveDepths::default()
+        },
+        traversal_filters,
+        budget,
+        sorting: EntityQuerySorting {
+            paths: Vec::new(),
+            relevance: None,
+            cursor: None,
+        },
+        limit: None,
+        include_drafts: false,
+        include_count: false,
+    }
+}
+
+async fn traverse<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    root: EntityId,
+    traversal_filters: EntityTraversalFilters<'_>,
+) -> HashSet<EntityId> {
+    api.get_entity_subgraph(
+        api.account_id,
+        subgraph_params(root, traversal_filters, SubgraphBudget::default()),
+    )
+    .await
+    .expect("could not read subgraph")
+    .subgraph
+    .vertices
+    .entities
+    .into_keys()
+    .map(|vertex_id| vertex_id.base_id)
+    .collect()
+}
+
+async fn find_paths<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    source_entity_id: EntityId,
+    target_entity_id: EntityId,
+    max_depth: u8,
+    link_entity_type_ids: Option<Vec<VersionedUrl>>,
+) -> HashSet<EntityId> {
+    api.find_entity_paths(
+        api.account_id,
+        FindEntityPathsParams {
+            source_entity_id,
+            target_entity_id,
+            max_depth,
+            link_entity_type_ids,
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+        },
+    )
+    .await
+    .expect("could not find paths")
+    .subgraph
+    .vertices
+    .entities
+    .into_keys()
+    .map(|vertex_id| vertex_id.base_id)
+    .collect()
+}
+
+#[tokio::test]
+async fn unfiltered() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    assert_eq!(
+        traverse(&mut api, graph.alice, EntityTraversalFilters::default()).await,
+        HashSet::from([
+            graph.alice,
+            graph.bob,
+            graph.charles,
+            graph.friend_of,
+            graph.acquaintance_of,
+        ])
+    );
+}
+
+#[tokio::test]
+async fn filter_link_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    let friend_of_type_id = type_id("friend-of");
+    assert_eq!(
+        traverse(
+            &mut api,
+            graph.alice,
+            EntityTraversalFilters {
+                link_entities: Some(Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::EntityTypeEdge {
+                        edge_kind: SharedEdgeKind::IsOfType,
+                        path: EntityTypeQueryPath::BaseUrl,
+                        inheritance_depth: Some(0),
+                    })),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        friend_of_type_id.base_url.as_str(),
+                    )))),
+                )),
+                target_entities: None,
+            }
+        )
+        .await,
+        HashSet::from([graph.alice, graph.bob, graph.friend_of])
+    );
+}
+
+#[tokio::test]
+async fn filter_target_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    assert_eq!(
+        traverse(
+            &mut api,
+            graph.alice,
+            EntityTraversalFilters {
+                link_entities: None,
+                target_entities: Some(Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::Properties(Some(
+                        JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+                            "https://blockprotocol.org/@alice/types/property-type/name/"
+                        ))])
+                    )))),
+                    Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                        "Charles"
+                    )))),
+                )),
+            }
+        )
+        .await,
+        // The link entities are still traversed, but only Charles is followed
+        HashSet::from([
+            graph.alice,
+            graph.charles,
+            graph.friend_of,
+            graph.acquaintance_of,
+        ])
+    );
+}
+
+#[tokio::test]
+async fn incoming_data_type_edges() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = seed(&mut database).await;
+
+    let text_data_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@blockprotocol/types/data-type/text/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let subgraph = api
+        .get_data_type_subgraph(
+            api.account_id,
+            GetDataTypeSubgraphParams {
+                filter: Filter::for_versioned_url(&text_data_type_id),
+                graph_resolve_depths: GraphResolveDepths {
+                    constrains_values_on: OutgoingEdgeResolveDepth {
+                        outgoing: 0,
+                        incoming: 1,
+                    },
+                    constrains_properties_on: OutgoingEdgeResolveDepth {
+                        outgoing: 0,
+                        incoming: 1,
+                    },
+                    ..GraphResolveDepths::default()
+                },
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                after: None,
+                limit: None,
+                include_drafts: false,
+            },
+        )
+        .await
+        .expect("could not read subgraph")
+        .subgraph;
+
+    // `Age` is constrained on `Number`, so only `Name` and the entity types using it are returned
+    assert_eq!(
+        subgraph
+            .vertices
+            .property_types
+            .into_keys()
+            .map(|vertex_id| vertex_id.base_id.to_string())
+            .collect::<HashSet<_>>(),
+        HashSet::from(["https://blockprotocol.org/@alice/types/property-type/name/".to_owned()])
+    );
+    assert_eq!(
+        subgraph
+            .vertices
+            .entity_types
+            .into_keys()
+            .map(|vertex_id| vertex_id.base_id)
+            .collect::<HashSet<_>>(),
+        HashSet::from([type_id("person").base_url])
+    );
+}
+
+#[tokio::test]
+async fn incoming_inherits_from_edges() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, _) = seed(&mut database).await;
+
+    let link_entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@blockprotocol/types/entity-type/link/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    assert_eq!(
+        api.get_entity_type_subgraph(
+            api.account_id,
+            GetEntityTypeSubgraphParams {
+                filter: Filter::for_versioned_url(&link_entity_type_id),
+                graph_resolve_depths: GraphResolveDepths {
+                    inherits_from: OutgoingEdgeResolveDepth {
+                        outgoing: 0,
+                        incoming: 1,
+                    },
+                    ..GraphResolveDepths::default()
+                },
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                after: None,
+                limit: None,
+                include_drafts: false,
+            },
+        )
+        .await
+        .expect("could not read subgraph")
+        .subgraph
+        .vertices
+        .entity_types
+        .into_keys()
+        .map(|vertex_id| vertex_id.base_id)
+        .collect::<HashSet<_>>(),
+        HashSet::from([
+            link_entity_type_id.base_url.clone(),
+            type_id("friend-of").base_url,
+            type_id("acquaintance-of").base_url,
+        ])
+    );
+}
+
+#[tokio::test]
+async fn shortest_path() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, graph) = seed(&mut database).await;
+
+    // Links are followed in both directions
+    assert_eq!(
+        find_paths(&api, graph.bob, graph.charles, 2, None).await,
+        HashSet::from([
+            graph.bob,
+            graph.friend_of,
+            graph.alice,
+            graph.acquaintance_of,
+            graph.charles,
+        ])
+    );
+}
+
+#[tokio::test]
+async fn shortest_path_exceeding_max_depth() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, graph) = seed(&mut database).await;
+
+    assert_eq!(
+        find_paths(&api, graph.bob, graph.charles, 1, None).await,
+        HashSet::from([graph.bob])
+    );
+}
+
+#[tokio::test]
+async fn shortest_path_with_link_entity_types() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (api, graph) = seed(&mut database).await;
+
+    assert_eq!(
+        find_paths(
+            &api,
+            graph.alice,
+            graph.bob,
+            2,
+            Some(vec![type_id("friend-of")])
+        )
+        .await,
+        HashSet::from([graph.alice, graph.friend_of, graph.bob])
+    );
+    assert_eq!(
+        find_paths(
+            &api,
+            graph.bob,
+            graph.charles,
+            2,
+            Some(vec![type_id("friend-of")])
+        )
+        .await,
+        HashSet::from([graph.bob])
+    );
+}
+
+#[tokio::test]
+async fn truncate_exceeded_budget() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    let response = api
+        .get_entity_subgraph(
+            api.account_id,
+            subgraph_params(
+                graph.alice,
+                EntityTraversalFilters::default(),
+                SubgraphBudget {
+                    max_edges: Some(1),
+                    on_exceeded: SubgraphBudgetExceededBehavior::Truncate,
+                    ..SubgraphBudget::default()
+                },
+            ),
+        )
+        .await
+        .expect("could not read subgraph");
+
+    assert!(response.incomplete);
+    assert_eq!(
+        response
+            .subgraph
+            .vertices
+            .entities
+            .into_keys()
+            .map(|vertex_id| vertex_id.base_id)
+            .collect::<HashSet<_>>(),
+        HashSet::from([graph.alice, graph.friend_of, graph.acquaintance_of])
+    );
+}
+
+#[tokio::test]
+async fn error_on_exceeded_budget() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    let error = api
+        .get_entity_subgraph(
+            api.account_id,
+            subgraph_params(
+                graph.alice,
+                EntityTraversalFilters::default(),
+                SubgraphBudget {
+                    max_vertices: Some(2),
+                    ..SubgraphBudget::default()
+                },
+            ),
+        )
+        .await
+        .expect_err("subgraph should exceed the budget");
+    assert!(error.contains::<SubgraphBudgetExceeded>());
+}
+
+#[tokio::test]
+async fn budget_not_exceeded() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let (mut api, graph) = seed(&mut database).await;
+
+    let response = api
+        .get_entity_subgraph(
+            api.account_id,
+            subgraph_params(
+                graph.alice,
+                EntityTraversalFilters::default(),
+                SubgraphBudget {
+                    max_vertices: Some(5),
+                    max_edges: Some(4),
+                    statement_timeout_ms: Some(10_000),
+                    ..SubgraphBudget::default()
+                },
+            ),
+        )
+        .await
+        .expect("could not read subgraph");
+
+    assert!(!response.incomplete);
+    assert_eq!(response.subgraph.vertices.entities.len(), 5);
+}
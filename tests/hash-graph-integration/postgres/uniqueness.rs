@@ -0,0 +1,321 @@
+use authorization::AuthorizationApi;
+use error_stack::Report;
+use graph::store::{
+    error::PropertyIsNotUnique,
+    knowledge::{
+        CorrectEntityParams, CreateEntityParams, EntityRevertTarget, MergeEntitiesParams,
+        PatchEntityParams, PropertyMergeStrategy, PublishDraftsParams, RevertEntityParams,
+    },
+    ontology::{EntityTypeUniquenessConstraint, GetEntityTypeUniquenessConstraintsParams},
+    EntityStore, EntityTypeStore, InsertionError,
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityMetadata, ProvidedEntityEditionProvenance},
+        Property, PropertyMetadataMap, PropertyObject, PropertyPatchOperation, PropertyPath,
+        PropertyProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use temporal_versioning::ClosedTemporalBound;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn name_is_unique() -> EntityTypeUniquenessConstraint {
+    EntityTypeUniquenessConstraint {
+        entity_type_id: VersionedUrl {
+            base_url: BaseUrl::new(
+                "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+            )
+            .expect("couldn't construct Base URL"),
+            version: OntologyTypeVersion::new(1),
+        },
+        property_path: PropertyPath::from_json_pointer(
+            "/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1name~1",
+        )
+        .expect("couldn't construct property path"),
+    }
+}
+
+fn alice() -> PropertyObject {
+    serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity")
+}
+
+fn bob() -> PropertyObject {
+    serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity")
+}
+
+async fn create_person<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    properties: PropertyObject,
+    draft: bool,
+) -> Result<EntityMetadata, Report<InsertionError>> {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![name_is_unique().entity_type_id],
+            properties,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft,
+            relationships: [],
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn enforce_unique_property() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    api.add_entity_type_uniqueness_constraint(api.account_id, name_is_unique())
+        .await
+        .expect("could not add uniqueness constraint");
+    let constraints = api
+        .get_entity_type_uniqueness_constraints(
+            api.account_id,
+            GetEntityTypeUniquenessConstraintsParams {
+                entity_type_id: name_is_unique().entity_type_id,
+            },
+        )
+        .await
+        .expect("could not read uniqueness constraints");
+    assert_eq!(constraints.property_paths, [name_is_unique().property_path]);
+
+    let alice_metadata = create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity");
+    let error = create_person(&mut api, alice(), false)
+        .await
+        .expect_err("could create an entity with a duplicate name");
+    let conflict = error
+        .downcast_ref::<PropertyIsNotUnique>()
+        .expect("the error should be caused by the uniqueness constraint");
+    assert_eq!(
+        conflict.conflicting_entity_id,
+        alice_metadata.record_id.entity_id
+    );
+
+    let draft_metadata = create_person(&mut api, alice(), true)
+        .await
+        .expect("drafts should not be constrained");
+    let error = api
+        .publish_drafts(
+            api.account_id,
+            PublishDraftsParams {
+                entity_ids: vec![draft_metadata.record_id.entity_id],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could publish an entity with a duplicate name");
+    assert!(error.contains::<PropertyIsNotUnique>());
+
+    let bob_metadata = create_person(&mut api, bob(), false)
+        .await
+        .expect("could not create entity");
+    let error = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: bob_metadata.record_id.entity_id,
+                expected_edition_ids: Vec::new(),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    value: Property::Object(alice()),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                entity_type_ids: vec![],
+                archived: None,
+                draft: None,
+                decision_time: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could update an entity to a duplicate name");
+    assert!(error.contains::<PropertyIsNotUnique>());
+
+    api.remove_entity_type_uniqueness_constraint(api.account_id, name_is_unique())
+        .await
+        .expect("could not remove uniqueness constraint");
+    create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity after removing the constraint");
+}
+
+#[tokio::test]
+async fn reject_constraint_violated_by_existing_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity");
+    create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity");
+
+    let error = api
+        .add_entity_type_uniqueness_constraint(api.account_id, name_is_unique())
+        .await
+        .expect_err("could add a constraint which is already violated");
+    assert!(error.contains::<PropertyIsNotUnique>());
+}
+
+#[tokio::test]
+async fn merge_unique_property() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    api.add_entity_type_uniqueness_constraint(api.account_id, name_is_unique())
+        .await
+        .expect("could not add uniqueness constraint");
+
+    let target = create_person(
+        &mut api,
+        serde_json::from_value(json!({
+            "https://blockprotocol.org/@alice/types/property-type/age/": 42
+        }))
+        .expect("could not parse entity"),
+        false,
+    )
+    .await
+    .expect("could not create entity");
+    let duplicate = create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity");
+
+    api.merge_entities(
+        api.account_id,
+        MergeEntitiesParams {
+            target_entity_id: target.record_id.entity_id,
+            duplicate_entity_ids: vec![duplicate.record_id.entity_id],
+            conflict_strategy: PropertyMergeStrategy::PreferTarget,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not merge an entity holding a unique value");
+
+    let error = create_person(&mut api, alice(), false)
+        .await
+        .expect_err("could create an entity with a duplicate name");
+    let conflict = error
+        .downcast_ref::<PropertyIsNotUnique>()
+        .expect("the error should be caused by the uniqueness constraint");
+    assert_eq!(conflict.conflicting_entity_id, target.record_id.entity_id);
+}
+
+#[tokio::test]
+async fn revert_and_correct_to_duplicate_property() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    api.add_entity_type_uniqueness_constraint(api.account_id, name_is_unique())
+        .await
+        .expect("could not add uniqueness constraint");
+
+    let alice_metadata = create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity");
+    let entity_id = alice_metadata.record_id.entity_id;
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id,
+            expected_edition_ids: Vec::new(),
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                value: Property::Object(bob()),
+                confidence: None,
+                provenance: PropertyProvenance::default(),
+            }],
+            entity_type_ids: vec![],
+            archived: None,
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not update entity");
+    create_person(&mut api, alice(), false)
+        .await
+        .expect("could not create entity after the name was released");
+
+    let error = api
+        .revert_entity(
+            api.account_id,
+            RevertEntityParams {
+                entity_id,
+                target: EntityRevertTarget::EditionId(alice_metadata.record_id.edition_id),
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could revert an entity to a duplicate name");
+    assert!(error.contains::<PropertyIsNotUnique>());
+
+    let ClosedTemporalBound::Inclusive(decision_time_start) =
+        *alice_metadata.temporal_versioning.decision_time.start();
+    let error = api
+        .correct_entity(
+            api.account_id,
+            CorrectEntityParams {
+                entity_id,
+                decision_time_start,
+                decision_time_end: "2100-01-01T00:00:00Z"
+                    .parse()
+                    .expect("could not parse timestamp"),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    value: Property::Object(alice()),
+                    confidence: None,
+                    provenance: PropertyProvenance::default(),
+                }],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            },
+        )
+        .await
+        .expect_err("could correct an entity to a duplicate name");
+    assert!(error.contains::<PropertyIsNotUnique>());
+}
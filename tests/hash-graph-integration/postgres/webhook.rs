@@ -0,0 +1,353 @@
+use std::{
+    future::IntoFuture,
+    sync::{Arc, Mutex},
+};
+
+use authorization::AuthorizationApi;
+use axum::{
+    body::Bytes,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use graph::store::{
+    knowledge::{CreateEntityParams, PatchEntityParams},
+    webhook::{
+        sign_webhook_payload, CreateWebhookParams, Webhook, WebhookDispatcher,
+        WebhookDispatcherConfig, WebhookStore,
+    },
+    EntityStore,
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::{
+        entity::{EntityMetadata, ProvidedEntityEditionProvenance},
+        PropertyMetadataMap,
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use tokio::net::TcpListener;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+const SECRET: &str = "webhook-secret";
+
+#[derive(Debug)]
+struct ReceivedRequest {
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+/// Starts a local HTTP server which records the requests it receives and answers with `status`.
+async fn spawn_receiver(status: StatusCode) -> (String, Arc<Mutex<Vec<ReceivedRequest>>>) {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let router = Router::new().route(
+        "/",
+        post({
+            let received = Arc::clone(&received);
+            move |headers: HeaderMap, body: Bytes| async move {
+                received
+                    .lock()
+                    .expect("lock should not be poisoned")
+                    .push(ReceivedRequest { headers, body });
+                status
+            }
+        }),
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("could not bind listener");
+    let address = listener
+        .local_addr()
+        .expect("could not read listener address");
+    tokio::spawn(axum::serve(listener, router).into_future());
+
+    (format!("http://{address}/"), received)
+}
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [
+                property_type::TEXT_V1,
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+                entity_type::PAGE_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn page_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+async fn create_webhook<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    url: String,
+    entity_type_id: Option<VersionedUrl>,
+) -> Webhook {
+    api.create_webhook(
+        api.account_id,
+        CreateWebhookParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            url,
+            secret: SECRET.to_owned(),
+            entity_type_id,
+        },
+    )
+    .await
+    .expect("could not create webhook")
+}
+
+/// Creates a dispatcher which is allowed to reach the receivers on the loopback interface.
+fn local_dispatcher() -> WebhookDispatcher {
+    WebhookDispatcher::new(WebhookDispatcherConfig {
+        allow_private_destinations: true,
+        ..WebhookDispatcherConfig::default()
+    })
+    .expect("could not create dispatcher")
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_type_id: VersionedUrl,
+    properties: &str,
+) -> EntityMetadata {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: vec![entity_type_id],
+            properties: serde_json::from_str(properties).expect("could not parse entity"),
+            confidence: None,
+            property_metadata: PropertyMetadataMap::default(),
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+#[tokio::test]
+async fn deliver() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let (url, received) = spawn_receiver(StatusCode::OK).await;
+    let webhook = create_webhook(&mut api, url, None).await;
+
+    let alice = create_entity(&mut api, person_type_id(), entity::PERSON_ALICE_V1).await;
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id: alice.record_id.entity_id,
+            expected_edition_ids: Vec::new(),
+            properties: vec![],
+            entity_type_ids: vec![],
+            archived: Some(true),
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        },
+    )
+    .await
+    .expect("could not archive entity");
+
+    let dispatcher = local_dispatcher();
+    assert_eq!(
+        dispatcher
+            .dispatch(&mut api)
+            .await
+            .expect("could not dispatch webhooks"),
+        2
+    );
+    assert_eq!(
+        dispatcher
+            .dispatch(&mut api)
+            .await
+            .expect("could not dispatch webhooks"),
+        0
+    );
+
+    let mut operations = received
+        .lock()
+        .expect("lock should not be poisoned")
+        .iter()
+        .map(|request| {
+            let timestamp = request.headers["X-Hash-Timestamp"]
+                .to_str()
+                .expect("timestamp should be ASCII")
+                .parse::<i64>()
+                .expect("timestamp should be an integer");
+            assert_eq!(
+                request.headers["X-Hash-Signature"],
+                sign_webhook_payload(SECRET, timestamp, &request.body)
+            );
+            assert_eq!(
+                request.headers["X-Hash-Webhook-Id"],
+                webhook.webhook_id.to_string()
+            );
+
+            let payload = serde_json::from_slice::<serde_json::Value>(&request.body)
+                .expect("payload should be JSON");
+            assert_eq!(
+                payload["entity"]["metadata"]["recordId"]["entityId"],
+                alice.record_id.entity_id.to_string()
+            );
+            payload["operation"]
+                .as_str()
+                .expect("operation should be a string")
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+    operations.sort();
+    assert_eq!(operations, ["archive", "create"]);
+
+    let deliveries = api
+        .get_webhook_deliveries(api.account_id, webhook.webhook_id)
+        .await
+        .expect("could not read deliveries");
+    assert_eq!(deliveries.len(), 2);
+    for delivery in deliveries {
+        assert!(delivery.delivered_at.is_some());
+        assert!(delivery.next_attempt_at.is_none());
+        assert_eq!(delivery.attempts.len(), 1);
+        assert_eq!(delivery.attempts[0].status_code, Some(200));
+    }
+}
+
+#[tokio::test]
+async fn retry_failed_delivery() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let (url, received) = spawn_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+    let webhook = create_webhook(&mut api, url, Some(person_type_id())).await;
+
+    create_entity(&mut api, person_type_id(), entity::PERSON_ALICE_V1).await;
+    // Pages are not delivered to the webhook
+    create_entity(&mut api, page_type_id(), entity::PAGE_V1).await;
+
+    let dispatcher = local_dispatcher();
+    assert_eq!(
+        dispatcher
+            .dispatch(&mut api)
+            .await
+            .expect("could not dispatch webhooks"),
+        1
+    );
+    assert_eq!(
+        received.lock().expect("lock should not be poisoned").len(),
+        1
+    );
+
+    // The retry is not due yet
+    assert_eq!(
+        dispatcher
+            .dispatch(&mut api)
+            .await
+            .expect("could not dispatch webhooks"),
+        0
+    );
+
+    let deliveries = api
+        .get_webhook_deliveries(api.account_id, webhook.webhook_id)
+        .await
+        .expect("could not read deliveries");
+    assert_eq!(deliveries.len(), 1);
+    let delivery = &deliveries[0];
+    assert!(delivery.delivered_at.is_none());
+    assert!(delivery.next_attempt_at.is_some());
+    assert_eq!(delivery.attempts.len(), 1);
+    assert_eq!(delivery.attempts[0].status_code, Some(500));
+}
+
+#[tokio::test]
+async fn reject_private_destination() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let (url, received) = spawn_receiver(StatusCode::OK).await;
+    let localhost_url = url.replace("127.0.0.1", "localhost");
+    let webhooks = [
+        create_webhook(&mut api, url, None).await,
+        create_webhook(&mut api, localhost_url, None).await,
+    ];
+
+    create_entity(&mut api, person_type_id(), entity::PERSON_ALICE_V1).await;
+
+    let dispatcher = WebhookDispatcher::new(WebhookDispatcherConfig::default())
+        .expect("could not create dispatcher");
+    assert_eq!(
+        dispatcher
+            .dispatch(&mut api)
+            .await
+            .expect("could not dispatch webhooks"),
+        2
+    );
+    assert!(
+        received
+            .lock()
+            .expect("lock should not be poisoned")
+            .is_empty()
+    );
+
+    for webhook in webhooks {
+        let deliveries = api
+            .get_webhook_deliveries(api.account_id, webhook.webhook_id)
+            .await
+            .expect("could not read deliveries");
+        assert_eq!(deliveries.len(), 1);
+        let attempt = &deliveries[0].attempts[0];
+        assert_eq!(attempt.status_code, None);
+        assert!(
+            attempt
+                .error
+                .as_deref()
+                .is_some_and(|error| error.contains("is not a publicly reachable address")),
+            "{attempt:?}"
+        );
+    }
+}